@@ -146,6 +146,9 @@ macro_rules! fwd_erased {
     (@convert send_media_group, $arg:ident, media : $T:ty) => {
         $arg.into_iter().collect()
     };
+    (@convert send_paid_media, $arg:ident, media : $T:ty) => {
+        $arg.into_iter().collect()
+    };
     (@convert $m:ident, $arg:ident, options : $T:ty) => {
         $arg.into_iter().collect()
     };
@@ -204,6 +207,7 @@ where
         send_animation,
         send_voice,
         send_video_note,
+        send_paid_media,
         send_media_group,
         send_location,
         edit_message_live_location,
@@ -217,6 +221,11 @@ where
         send_chat_action,
         set_message_reaction,
         get_user_profile_photos,
+        set_user_emoji_status,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
         get_file,
         kick_chat_member,
         ban_chat_member,
@@ -230,6 +239,8 @@ where
         export_chat_invite_link,
         create_chat_invite_link,
         edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
         revoke_chat_invite_link,
         set_chat_photo,
         delete_chat_photo,
@@ -263,6 +274,23 @@ where
         get_user_chat_boosts,
         set_my_commands,
         get_business_connection,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
         get_my_commands,
         set_my_name,
         get_my_name,
@@ -277,6 +305,7 @@ where
         delete_my_commands,
         answer_inline_query,
         answer_web_app_query,
+        save_prepared_inline_message,
         edit_message_text,
         edit_message_text_inline,
         edit_message_caption,
@@ -304,10 +333,14 @@ where
         set_sticker_emoji_list,
         set_sticker_keywords,
         set_sticker_mask_position,
+        get_available_gifts,
+        send_gift,
         send_invoice,
         create_invoice_link,
         answer_shipping_query,
         answer_pre_checkout_query,
+        get_star_transactions,
+        refund_star_payment,
         set_passport_data_errors,
         send_game,
         set_game_score,
@@ -414,6 +447,13 @@ trait ErasableRequester<'a> {
         video_note: InputFile,
     ) -> ErasedRequest<'a, SendVideoNote, Self::Err>;
 
+    fn send_paid_media(
+        &self,
+        chat_id: Recipient,
+        star_count: u32,
+        media: Vec<InputPaidMedia>,
+    ) -> ErasedRequest<'a, SendPaidMedia, Self::Err>;
+
     fn send_media_group(
         &self,
         chat_id: Recipient,
@@ -473,7 +513,7 @@ trait ErasableRequester<'a> {
         &self,
         chat_id: Recipient,
         question: String,
-        options: Vec<String>,
+        options: Vec<InputPollOption>,
     ) -> ErasedRequest<'a, SendPoll, Self::Err>;
 
     fn send_dice(&self, chat_id: Recipient) -> ErasedRequest<'a, SendDice, Self::Err>;
@@ -495,6 +535,25 @@ trait ErasableRequester<'a> {
         user_id: UserId,
     ) -> ErasedRequest<'a, GetUserProfilePhotos, Self::Err>;
 
+    fn set_user_emoji_status(
+        &self,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, SetUserEmojiStatus, Self::Err>;
+
+    fn verify_user(&self, user_id: UserId) -> ErasedRequest<'a, VerifyUser, Self::Err>;
+
+    fn verify_chat(&self, chat_id: Recipient) -> ErasedRequest<'a, VerifyChat, Self::Err>;
+
+    fn remove_user_verification(
+        &self,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, RemoveUserVerification, Self::Err>;
+
+    fn remove_chat_verification(
+        &self,
+        chat_id: Recipient,
+    ) -> ErasedRequest<'a, RemoveChatVerification, Self::Err>;
+
     fn get_file(&self, file_id: String) -> ErasedRequest<'a, GetFile, Self::Err>;
 
     fn ban_chat_member(
@@ -569,6 +628,19 @@ trait ErasableRequester<'a> {
         invite_link: String,
     ) -> ErasedRequest<'a, EditChatInviteLink, Self::Err>;
 
+    fn create_chat_subscription_invite_link(
+        &self,
+        chat_id: Recipient,
+        subscription_period: u32,
+        subscription_price: u32,
+    ) -> ErasedRequest<'a, CreateChatSubscriptionInviteLink, Self::Err>;
+
+    fn edit_chat_subscription_invite_link(
+        &self,
+        chat_id: Recipient,
+        invite_link: String,
+    ) -> ErasedRequest<'a, EditChatSubscriptionInviteLink, Self::Err>;
+
     fn revoke_chat_invite_link(
         &self,
         chat_id: Recipient,
@@ -757,6 +829,108 @@ trait ErasableRequester<'a> {
         business_connection_id: BusinessConnectionId,
     ) -> ErasedRequest<'a, GetBusinessConnection, Self::Err>;
 
+    fn read_business_message(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, ReadBusinessMessage, Self::Err>;
+
+    fn delete_business_messages(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        message_ids: Vec<MessageId>,
+    ) -> ErasedRequest<'a, DeleteBusinessMessages, Self::Err>;
+
+    fn set_business_account_name(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        first_name: String,
+    ) -> ErasedRequest<'a, SetBusinessAccountName, Self::Err>;
+
+    fn set_business_account_username(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, SetBusinessAccountUsername, Self::Err>;
+
+    fn set_business_account_bio(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, SetBusinessAccountBio, Self::Err>;
+
+    fn set_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        photo: InputProfilePhoto,
+    ) -> ErasedRequest<'a, SetBusinessAccountProfilePhoto, Self::Err>;
+
+    fn remove_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, RemoveBusinessAccountProfilePhoto, Self::Err>;
+
+    fn set_business_account_gift_settings(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        show_gift_button: bool,
+        accepted_gift_types: AcceptedGiftTypes,
+    ) -> ErasedRequest<'a, SetBusinessAccountGiftSettings, Self::Err>;
+
+    fn get_business_account_star_balance(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, GetBusinessAccountStarBalance, Self::Err>;
+
+    fn transfer_business_account_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        star_count: u32,
+    ) -> ErasedRequest<'a, TransferBusinessAccountStars, Self::Err>;
+
+    fn get_business_account_gifts(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, GetBusinessAccountGifts, Self::Err>;
+
+    fn convert_gift_to_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+    ) -> ErasedRequest<'a, ConvertGiftToStars, Self::Err>;
+
+    fn upgrade_gift(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+    ) -> ErasedRequest<'a, UpgradeGift, Self::Err>;
+
+    fn transfer_gift(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+        new_owner_chat_id: ChatId,
+    ) -> ErasedRequest<'a, TransferGift, Self::Err>;
+
+    fn post_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        content: InputStoryContent,
+        active_period: u32,
+    ) -> ErasedRequest<'a, PostStory, Self::Err>;
+
+    fn edit_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+        content: InputStoryContent,
+    ) -> ErasedRequest<'a, EditStory, Self::Err>;
+
+    fn delete_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+    ) -> ErasedRequest<'a, DeleteStory, Self::Err>;
+
     fn get_my_commands(&self) -> ErasedRequest<'a, GetMyCommands, Self::Err>;
 
     fn set_my_name(&self) -> ErasedRequest<'a, SetMyName, Self::Err>;
@@ -797,6 +971,12 @@ trait ErasableRequester<'a> {
         result: InlineQueryResult,
     ) -> ErasedRequest<'a, AnswerWebAppQuery, Self::Err>;
 
+    fn save_prepared_inline_message(
+        &self,
+        user_id: UserId,
+        result: InlineQueryResult,
+    ) -> ErasedRequest<'a, SavePreparedInlineMessage, Self::Err>;
+
     fn edit_message_text(
         &self,
         chat_id: Recipient,
@@ -953,6 +1133,11 @@ trait ErasableRequester<'a> {
         sticker: String,
     ) -> ErasedRequest<'a, SetStickerMaskPosition, Self::Err>;
 
+    fn get_available_gifts(&self) -> ErasedRequest<'a, GetAvailableGifts, Self::Err>;
+
+    fn send_gift(&self, user_id: UserId, gift_id: String)
+        -> ErasedRequest<'a, SendGift, Self::Err>;
+
     // we can't change telegram API
     #[allow(clippy::too_many_arguments)]
     fn send_invoice(
@@ -961,7 +1146,6 @@ trait ErasableRequester<'a> {
         title: String,
         description: String,
         payload: String,
-        provider_token: String,
         currency: String,
         prices: Vec<LabeledPrice>,
     ) -> ErasedRequest<'a, SendInvoice, Self::Err>;
@@ -972,7 +1156,6 @@ trait ErasableRequester<'a> {
         title: String,
         description: String,
         payload: String,
-        provider_token: String,
         currency: String,
         prices: Vec<LabeledPrice>,
     ) -> ErasedRequest<'a, CreateInvoiceLink, Self::Err>;
@@ -989,6 +1172,14 @@ trait ErasableRequester<'a> {
         ok: bool,
     ) -> ErasedRequest<'a, AnswerPreCheckoutQuery, Self::Err>;
 
+    fn get_star_transactions(&self) -> ErasedRequest<'a, GetStarTransactions, Self::Err>;
+
+    fn refund_star_payment(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: String,
+    ) -> ErasedRequest<'a, RefundStarPayment, Self::Err>;
+
     fn set_passport_data_errors(
         &self,
         user_id: UserId,
@@ -1157,6 +1348,15 @@ where
         Requester::send_video_note(self, chat_id, video_note).erase()
     }
 
+    fn send_paid_media(
+        &self,
+        chat_id: Recipient,
+        star_count: u32,
+        media: Vec<InputPaidMedia>,
+    ) -> ErasedRequest<'a, SendPaidMedia, Self::Err> {
+        Requester::send_paid_media(self, chat_id, star_count, media).erase()
+    }
+
     fn send_media_group(
         &self,
         chat_id: Recipient,
@@ -1234,7 +1434,7 @@ where
         &self,
         chat_id: Recipient,
         question: String,
-        options: Vec<String>,
+        options: Vec<InputPollOption>,
     ) -> ErasedRequest<'a, SendPoll, Self::Err> {
         Requester::send_poll(self, chat_id, question, options).erase()
     }
@@ -1266,6 +1466,35 @@ where
         Requester::get_user_profile_photos(self, user_id).erase()
     }
 
+    fn set_user_emoji_status(
+        &self,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, SetUserEmojiStatus, Self::Err> {
+        Requester::set_user_emoji_status(self, user_id).erase()
+    }
+
+    fn verify_user(&self, user_id: UserId) -> ErasedRequest<'a, VerifyUser, Self::Err> {
+        Requester::verify_user(self, user_id).erase()
+    }
+
+    fn verify_chat(&self, chat_id: Recipient) -> ErasedRequest<'a, VerifyChat, Self::Err> {
+        Requester::verify_chat(self, chat_id).erase()
+    }
+
+    fn remove_user_verification(
+        &self,
+        user_id: UserId,
+    ) -> ErasedRequest<'a, RemoveUserVerification, Self::Err> {
+        Requester::remove_user_verification(self, user_id).erase()
+    }
+
+    fn remove_chat_verification(
+        &self,
+        chat_id: Recipient,
+    ) -> ErasedRequest<'a, RemoveChatVerification, Self::Err> {
+        Requester::remove_chat_verification(self, chat_id).erase()
+    }
+
     fn get_file(&self, file_id: String) -> ErasedRequest<'a, GetFile, Self::Err> {
         Requester::get_file(self, file_id).erase()
     }
@@ -1366,6 +1595,29 @@ where
         Requester::edit_chat_invite_link(self, chat_id, invite_link).erase()
     }
 
+    fn create_chat_subscription_invite_link(
+        &self,
+        chat_id: Recipient,
+        subscription_period: u32,
+        subscription_price: u32,
+    ) -> ErasedRequest<'a, CreateChatSubscriptionInviteLink, Self::Err> {
+        Requester::create_chat_subscription_invite_link(
+            self,
+            chat_id,
+            subscription_period,
+            subscription_price,
+        )
+        .erase()
+    }
+
+    fn edit_chat_subscription_invite_link(
+        &self,
+        chat_id: Recipient,
+        invite_link: String,
+    ) -> ErasedRequest<'a, EditChatSubscriptionInviteLink, Self::Err> {
+        Requester::edit_chat_subscription_invite_link(self, chat_id, invite_link).erase()
+    }
+
     fn revoke_chat_invite_link(
         &self,
         chat_id: Recipient,
@@ -1624,6 +1876,149 @@ where
         Requester::get_business_connection(self, business_connection_id).erase()
     }
 
+    fn read_business_message(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: ChatId,
+        message_id: MessageId,
+    ) -> ErasedRequest<'a, ReadBusinessMessage, Self::Err> {
+        Requester::read_business_message(self, business_connection_id, chat_id, message_id).erase()
+    }
+
+    fn delete_business_messages(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        message_ids: Vec<MessageId>,
+    ) -> ErasedRequest<'a, DeleteBusinessMessages, Self::Err> {
+        Requester::delete_business_messages(self, business_connection_id, message_ids).erase()
+    }
+
+    fn set_business_account_name(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        first_name: String,
+    ) -> ErasedRequest<'a, SetBusinessAccountName, Self::Err> {
+        Requester::set_business_account_name(self, business_connection_id, first_name).erase()
+    }
+
+    fn set_business_account_username(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, SetBusinessAccountUsername, Self::Err> {
+        Requester::set_business_account_username(self, business_connection_id).erase()
+    }
+
+    fn set_business_account_bio(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, SetBusinessAccountBio, Self::Err> {
+        Requester::set_business_account_bio(self, business_connection_id).erase()
+    }
+
+    fn set_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        photo: InputProfilePhoto,
+    ) -> ErasedRequest<'a, SetBusinessAccountProfilePhoto, Self::Err> {
+        Requester::set_business_account_profile_photo(self, business_connection_id, photo).erase()
+    }
+
+    fn remove_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, RemoveBusinessAccountProfilePhoto, Self::Err> {
+        Requester::remove_business_account_profile_photo(self, business_connection_id).erase()
+    }
+
+    fn set_business_account_gift_settings(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        show_gift_button: bool,
+        accepted_gift_types: AcceptedGiftTypes,
+    ) -> ErasedRequest<'a, SetBusinessAccountGiftSettings, Self::Err> {
+        Requester::set_business_account_gift_settings(
+            self,
+            business_connection_id,
+            show_gift_button,
+            accepted_gift_types,
+        )
+        .erase()
+    }
+
+    fn get_business_account_star_balance(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, GetBusinessAccountStarBalance, Self::Err> {
+        Requester::get_business_account_star_balance(self, business_connection_id).erase()
+    }
+
+    fn transfer_business_account_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        star_count: u32,
+    ) -> ErasedRequest<'a, TransferBusinessAccountStars, Self::Err> {
+        Requester::transfer_business_account_stars(self, business_connection_id, star_count).erase()
+    }
+
+    fn get_business_account_gifts(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> ErasedRequest<'a, GetBusinessAccountGifts, Self::Err> {
+        Requester::get_business_account_gifts(self, business_connection_id).erase()
+    }
+
+    fn convert_gift_to_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+    ) -> ErasedRequest<'a, ConvertGiftToStars, Self::Err> {
+        Requester::convert_gift_to_stars(self, business_connection_id, owned_gift_id).erase()
+    }
+
+    fn upgrade_gift(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+    ) -> ErasedRequest<'a, UpgradeGift, Self::Err> {
+        Requester::upgrade_gift(self, business_connection_id, owned_gift_id).erase()
+    }
+
+    fn transfer_gift(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: String,
+        new_owner_chat_id: ChatId,
+    ) -> ErasedRequest<'a, TransferGift, Self::Err> {
+        Requester::transfer_gift(self, business_connection_id, owned_gift_id, new_owner_chat_id)
+            .erase()
+    }
+
+    fn post_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        content: InputStoryContent,
+        active_period: u32,
+    ) -> ErasedRequest<'a, PostStory, Self::Err> {
+        Requester::post_story(self, business_connection_id, content, active_period).erase()
+    }
+
+    fn edit_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+        content: InputStoryContent,
+    ) -> ErasedRequest<'a, EditStory, Self::Err> {
+        Requester::edit_story(self, business_connection_id, story_id, content).erase()
+    }
+
+    fn delete_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+    ) -> ErasedRequest<'a, DeleteStory, Self::Err> {
+        Requester::delete_story(self, business_connection_id, story_id).erase()
+    }
+
     fn get_my_commands(&self) -> ErasedRequest<'a, GetMyCommands, Self::Err> {
         Requester::get_my_commands(self).erase()
     }
@@ -1692,6 +2087,14 @@ where
         Requester::answer_web_app_query(self, web_app_query_id, result).erase()
     }
 
+    fn save_prepared_inline_message(
+        &self,
+        user_id: UserId,
+        result: InlineQueryResult,
+    ) -> ErasedRequest<'a, SavePreparedInlineMessage, Self::Err> {
+        Requester::save_prepared_inline_message(self, user_id, result).erase()
+    }
+
     fn edit_message_text(
         &self,
         chat_id: Recipient,
@@ -1902,27 +2305,29 @@ where
         Requester::set_sticker_mask_position(self, sticker).erase()
     }
 
+    fn get_available_gifts(&self) -> ErasedRequest<'a, GetAvailableGifts, Self::Err> {
+        Requester::get_available_gifts(self).erase()
+    }
+
+    fn send_gift(
+        &self,
+        user_id: UserId,
+        gift_id: String,
+    ) -> ErasedRequest<'a, SendGift, Self::Err> {
+        Requester::send_gift(self, user_id, gift_id).erase()
+    }
+
     fn send_invoice(
         &self,
         chat_id: Recipient,
         title: String,
         description: String,
         payload: String,
-        provider_token: String,
         currency: String,
         prices: Vec<LabeledPrice>,
     ) -> ErasedRequest<'a, SendInvoice, Self::Err> {
-        Requester::send_invoice(
-            self,
-            chat_id,
-            title,
-            description,
-            payload,
-            provider_token,
-            currency,
-            prices,
-        )
-        .erase()
+        Requester::send_invoice(self, chat_id, title, description, payload, currency, prices)
+            .erase()
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1931,20 +2336,10 @@ where
         title: String,
         description: String,
         payload: String,
-        provider_token: String,
         currency: String,
         prices: Vec<LabeledPrice>,
     ) -> ErasedRequest<'a, CreateInvoiceLink, Self::Err> {
-        Requester::create_invoice_link(
-            self,
-            title,
-            description,
-            payload,
-            provider_token,
-            currency,
-            prices,
-        )
-        .erase()
+        Requester::create_invoice_link(self, title, description, payload, currency, prices).erase()
     }
 
     fn answer_shipping_query(
@@ -1963,6 +2358,18 @@ where
         Requester::answer_pre_checkout_query(self, pre_checkout_query_id, ok).erase()
     }
 
+    fn get_star_transactions(&self) -> ErasedRequest<'a, GetStarTransactions, Self::Err> {
+        Requester::get_star_transactions(self).erase()
+    }
+
+    fn refund_star_payment(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: String,
+    ) -> ErasedRequest<'a, RefundStarPayment, Self::Err> {
+        Requester::refund_star_payment(self, user_id, telegram_payment_charge_id).erase()
+    }
+
     fn set_passport_data_errors(
         &self,
         user_id: UserId,
@@ -6,8 +6,9 @@ use crate::{
     payloads::{
         AnswerInlineQuery, AnswerWebAppQuery, CopyMessage, EditMessageCaption,
         EditMessageCaptionInline, EditMessageMedia, EditMessageMediaInline, EditMessageText,
-        EditMessageTextInline, SendAnimation, SendAudio, SendDocument, SendMediaGroup, SendMessage,
-        SendPhoto, SendPoll, SendVideo, SendVoice,
+        EditMessageTextInline, EditStory, PostStory, SavePreparedInlineMessage, SendAnimation,
+        SendAudio, SendDocument, SendGift, SendMediaGroup, SendMessage, SendPaidMedia, SendPhoto,
+        SendPoll, SendVideo, SendVoice,
     },
     prelude::Requester,
     requests::{HasPayload, Output, Request},
@@ -148,9 +149,14 @@ where
     B::CopyMessage: Clone,
     B::AnswerInlineQuery: Clone,
     B::AnswerWebAppQuery: Clone,
+    B::SavePreparedInlineMessage: Clone,
     B::EditMessageMedia: Clone,
     B::EditMessageMediaInline: Clone,
     B::SendMediaGroup: Clone,
+    B::SendPaidMedia: Clone,
+    B::SendGift: Clone,
+    B::PostStory: Clone,
+    B::EditStory: Clone,
 {
     type Err = B::Err;
 
@@ -170,7 +176,12 @@ where
         copy_message,
         answer_inline_query,
         answer_web_app_query,
+        save_prepared_inline_message,
         send_media_group,
+        send_paid_media,
+        send_gift,
+        post_story,
+        edit_story,
         edit_message_media,
         edit_message_media_inline,
         => f, fty
@@ -199,6 +210,11 @@ where
         send_chat_action,
         set_message_reaction,
         get_user_profile_photos,
+        set_user_emoji_status,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
         get_file,
         kick_chat_member,
         ban_chat_member,
@@ -212,6 +228,8 @@ where
         export_chat_invite_link,
         create_chat_invite_link,
         edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
         revoke_chat_invite_link,
         set_chat_photo,
         delete_chat_photo,
@@ -245,6 +263,21 @@ where
         get_user_chat_boosts,
         set_my_commands,
         get_business_connection,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        delete_story,
         get_my_commands,
         set_my_name,
         get_my_name,
@@ -278,10 +311,13 @@ where
         set_sticker_emoji_list,
         set_sticker_keywords,
         set_sticker_mask_position,
+        get_available_gifts,
         send_invoice,
         create_invoice_link,
         answer_shipping_query,
         answer_pre_checkout_query,
+        get_star_transactions,
+        refund_star_payment,
         set_passport_data_errors,
         send_game,
         set_game_score,
@@ -334,6 +370,7 @@ impl_visit_parse_modes! {
     SendDocument => [parse_mode],
     SendAnimation => [parse_mode],
     SendVoice => [parse_mode],
+    SendPaidMedia => [parse_mode],
     EditMessageText => [parse_mode],
     EditMessageTextInline => [parse_mode],
     EditMessageCaption => [parse_mode],
@@ -341,7 +378,10 @@ impl_visit_parse_modes! {
     // FIXME: check if `parse_mode` changes anything if `.caption` is not set
     //        (and if it does, maybe not call visitor if `self.caption.is_none()`)
     CopyMessage => [parse_mode],
-    SendPoll => [explanation_parse_mode],
+    SendPoll => [question_parse_mode, explanation_parse_mode],
+    SendGift => [text_parse_mode],
+    PostStory => [parse_mode],
+    EditStory => [parse_mode],
 }
 
 impl VisitParseModes for AnswerInlineQuery {
@@ -358,6 +398,12 @@ impl VisitParseModes for AnswerWebAppQuery {
     }
 }
 
+impl VisitParseModes for SavePreparedInlineMessage {
+    fn visit_parse_modes(&mut self, mut visitor: impl FnMut(&mut Option<ParseMode>)) {
+        visit_parse_modes_in_inline_query_result(&mut self.result, &mut visitor);
+    }
+}
+
 impl VisitParseModes for SendMediaGroup {
     fn visit_parse_modes(&mut self, mut visitor: impl FnMut(&mut Option<ParseMode>)) {
         self.media
@@ -51,6 +51,7 @@ where
     B::SendAnimation: Clone + Send + Sync + 'static,
     B::SendVoice: Clone + Send + Sync + 'static,
     B::SendVideoNote: Clone + Send + Sync + 'static,
+    B::SendPaidMedia: Clone + Send + Sync + 'static,
     B::SendMediaGroup: Clone + Send + Sync + 'static,
     B::SendLocation: Clone + Send + Sync + 'static,
     B::SendVenue: Clone + Send + Sync + 'static,
@@ -73,6 +74,7 @@ where
         send_animation,
         send_voice,
         send_video_note,
+        send_paid_media,
         send_media_group,
         send_location,
         send_venue,
@@ -101,6 +103,11 @@ where
         send_chat_action,
         set_message_reaction,
         get_user_profile_photos,
+        set_user_emoji_status,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
         get_file,
         kick_chat_member,
         ban_chat_member,
@@ -114,6 +121,8 @@ where
         export_chat_invite_link,
         create_chat_invite_link,
         edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
         revoke_chat_invite_link,
         set_chat_photo,
         delete_chat_photo,
@@ -147,6 +156,23 @@ where
         get_user_chat_boosts,
         set_my_commands,
         get_business_connection,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
         get_my_commands,
         set_my_name,
         get_my_name,
@@ -161,6 +187,7 @@ where
         delete_my_commands,
         answer_inline_query,
         answer_web_app_query,
+        save_prepared_inline_message,
         edit_message_text,
         edit_message_text_inline,
         edit_message_caption,
@@ -187,9 +214,13 @@ where
         set_sticker_emoji_list,
         set_sticker_keywords,
         set_sticker_mask_position,
+        get_available_gifts,
+        send_gift,
         answer_shipping_query,
         create_invoice_link,
         answer_pre_checkout_query,
+        get_star_transactions,
+        refund_star_payment,
         set_passport_data_errors,
         send_game,
         set_game_score,
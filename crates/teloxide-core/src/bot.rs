@@ -13,6 +13,7 @@ mod api;
 mod download;
 
 const TELOXIDE_TOKEN: &str = "TELOXIDE_TOKEN";
+const TELOXIDE_TEST_ENV: &str = "TELOXIDE_TEST_ENV";
 
 /// A requests sender.
 ///
@@ -57,6 +58,8 @@ pub struct Bot {
     token: Arc<str>,
     api_url: Arc<reqwest::Url>,
     client: Client,
+    local: bool,
+    test_env: bool,
 }
 
 /// Constructors
@@ -96,7 +99,7 @@ impl Bot {
                 .expect("Failed to parse default Telegram bot API url"),
         );
 
-        Self { token, api_url, client }
+        Self { token, api_url, client, local: false, test_env: false }
     }
 
     /// Creates a new `Bot` with the `TELOXIDE_TOKEN` & `TELOXIDE_PROXY`
@@ -107,6 +110,9 @@ impl Bot {
     /// [`reqwest::Proxy::all`], if it exists, otherwise returns the default
     /// client.
     ///
+    /// If `TELOXIDE_TEST_ENV` is set to `1` or `true`, the bot works in the
+    /// test environment, see [`Bot::set_test_env`].
+    ///
     /// # Panics
     ///  - If cannot get the `TELOXIDE_TOKEN`  environmental variable.
     ///  - If it cannot create [`reqwest::Client`].
@@ -120,6 +126,9 @@ impl Bot {
     /// Creates a new `Bot` with the `TELOXIDE_TOKEN` environmental variable (a
     /// bot's token) and your [`reqwest::Client`].
     ///
+    /// If `TELOXIDE_TEST_ENV` is set to `1` or `true`, the bot works in the
+    /// test environment, see [`Bot::set_test_env`].
+    ///
     /// # Panics
     /// If cannot get the `TELOXIDE_TOKEN` environmental variable.
     ///
@@ -130,7 +139,10 @@ impl Bot {
     /// [`reqwest::Client`]: https://docs.rs/reqwest/0.10.1/reqwest/struct.Client.html
     /// [issue 223]: https://github.com/teloxide/teloxide/issues/223
     pub fn from_env_with_client(client: Client) -> Self {
-        Self::with_client(get_env(TELOXIDE_TOKEN), client)
+        let test_env = std::env::var(TELOXIDE_TEST_ENV)
+            .is_ok_and(|value| matches!(value.as_str(), "1" | "true"));
+
+        Self::with_client(get_env(TELOXIDE_TOKEN), client).set_test_env(test_env)
     }
 
     /// Sets a custom API URL.
@@ -176,6 +188,64 @@ impl Bot {
         self.api_url = Arc::new(url);
         self
     }
+
+    /// Sets whether the [Telegram bot API server][tbas] set by
+    /// [`Bot::set_api_url`] runs in the local mode (i.e. with the `--local`
+    /// flag).
+    ///
+    /// In the local mode, the server shares the filesystem with the bot, so:
+    ///  - Files are downloaded by [`Download`] methods straight from the disk,
+    ///    since the server returns absolute paths in [`File::path`].
+    ///  - Files created with [`InputFile::file`] are not uploaded, but passed
+    ///    to the server as `file://` URIs.
+    ///  - Files can be uploaded up to [`LOCAL_UPLOAD_SIZE_LIMIT`] instead of
+    ///    [`UPLOAD_SIZE_LIMIT`] (see [`Bot::upload_size_limit`]).
+    ///
+    /// [tbas]: https://github.com/tdlib/telegram-bot-api
+    /// [`Download`]: crate::net::Download
+    /// [`File::path`]: crate::types::File::path
+    /// [`InputFile::file`]: crate::types::InputFile::file
+    /// [`LOCAL_UPLOAD_SIZE_LIMIT`]: crate::net::LOCAL_UPLOAD_SIZE_LIMIT
+    /// [`UPLOAD_SIZE_LIMIT`]: crate::net::UPLOAD_SIZE_LIMIT
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::Bot;
+    ///
+    /// let url = reqwest::Url::parse("http://localhost:8081").unwrap();
+    /// let bot = Bot::new("TOKEN").set_api_url(url).set_local_mode(true);
+    ///
+    /// assert!(bot.is_local_mode());
+    /// assert_eq!(bot.upload_size_limit(), teloxide_core::net::LOCAL_UPLOAD_SIZE_LIMIT);
+    /// ```
+    pub fn set_local_mode(mut self, local: bool) -> Self {
+        self.local = local;
+        self
+    }
+
+    /// Sets whether the bot works in the Telegram [test environment].
+    ///
+    /// The test environment has its own accounts and bots (which are created
+    /// via @BotFather in the test environment), so a token of a bot from the
+    /// production environment won't work there and vice versa. Both requests
+    /// and file downloads use `/test/` URLs, e.g.
+    /// `https://api.telegram.org/bot<token>/test/getMe`.
+    ///
+    /// [test environment]: https://core.telegram.org/bots/webapps#using-bots-in-the-test-environment
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::Bot;
+    ///
+    /// let bot = Bot::new("TOKEN").set_test_env(true);
+    /// assert!(bot.is_test_env());
+    /// ```
+    pub fn set_test_env(mut self, test_env: bool) -> Self {
+        self.test_env = test_env;
+        self
+    }
 }
 
 /// Getters
@@ -197,6 +267,30 @@ impl Bot {
     pub fn api_url(&self) -> reqwest::Url {
         reqwest::Url::clone(&*self.api_url)
     }
+
+    /// Returns `true` if the bot works with a Bot API server in the local
+    /// mode, see [`Bot::set_local_mode`].
+    #[must_use]
+    pub fn is_local_mode(&self) -> bool {
+        self.local
+    }
+
+    /// Returns `true` if the bot works in the test environment, see
+    /// [`Bot::set_test_env`].
+    #[must_use]
+    pub fn is_test_env(&self) -> bool {
+        self.test_env
+    }
+
+    /// Returns the maximum size of a file uploaded by this bot, in bytes.
+    ///
+    /// Uploading a larger [`InputFile::file`] fails before it's sent.
+    ///
+    /// [`InputFile::file`]: crate::types::InputFile::file
+    #[must_use]
+    pub fn upload_size_limit(&self) -> u64 {
+        serde_multipart::upload_size_limit(self.local)
+    }
 }
 
 impl Bot {
@@ -210,6 +304,7 @@ impl Bot {
     {
         let client = self.client.clone();
         let token = Arc::clone(&self.token);
+        let test_env = self.test_env;
         let api_url = Arc::clone(&self.api_url);
 
         let timeout_hint = payload.timeout_hint();
@@ -225,6 +320,7 @@ impl Bot {
                 reqwest::Url::clone(&*api_url),
                 P::NAME,
                 params,
+                test_env,
                 timeout_hint,
             )
             .await
@@ -241,20 +337,22 @@ impl Bot {
     {
         let client = self.client.clone();
         let token = Arc::clone(&self.token);
+        let test_env = self.test_env;
         let api_url = Arc::clone(&self.api_url);
 
         let timeout_hint = payload.timeout_hint();
-        let params = serde_multipart::to_form(payload);
+        let params = serde_multipart::to_form(payload, self.local);
 
         // async move to capture client&token&api_url&params
         async move {
-            let params = params?.await;
+            let params = params?.await?;
             net::request_multipart(
                 &client,
                 token.as_ref(),
                 reqwest::Url::clone(&*api_url),
                 P::NAME,
                 params,
+                test_env,
                 timeout_hint,
             )
             .await
@@ -271,20 +369,22 @@ impl Bot {
     {
         let client = self.client.clone();
         let token = Arc::clone(&self.token);
+        let test_env = self.test_env;
         let api_url = self.api_url.clone();
 
         let timeout_hint = payload.timeout_hint();
-        let params = serde_multipart::to_form_ref(payload);
+        let params = serde_multipart::to_form_ref(payload, self.local);
 
         // async move to capture client&token&api_url&params
         async move {
-            let params = params?.await;
+            let params = params?.await?;
             net::request_multipart(
                 &client,
                 token.as_ref(),
                 reqwest::Url::clone(&*api_url),
                 P::NAME,
                 params,
+                test_env,
                 timeout_hint,
             )
             .await
@@ -5,9 +5,10 @@ use crate::{
     prelude::Requester,
     requests::{JsonRequest, MultipartRequest},
     types::{
-        BotCommand, BusinessConnectionId, ChatId, ChatPermissions, InlineQueryResult, InputFile,
-        InputMedia, InputSticker, LabeledPrice, MessageId, Recipient, Rgb, StickerFormat, ThreadId,
-        UserId,
+        AcceptedGiftTypes, BotCommand, BusinessConnectionId, ChatId, ChatPermissions,
+        InlineQueryResult, InputFile, InputMedia, InputPaidMedia, InputPollOption,
+        InputProfilePhoto, InputSticker, InputStoryContent, LabeledPrice, MessageId, Recipient,
+        Rgb, StickerFormat, StoryId, ThreadId, UserId,
     },
     Bot,
 };
@@ -154,6 +155,19 @@ impl Requester for Bot {
         Self::SendVideoNote::new(self.clone(), payloads::SendVideoNote::new(chat_id, video_note))
     }
 
+    type SendPaidMedia = MultipartRequest<payloads::SendPaidMedia>;
+
+    fn send_paid_media<C, M>(&self, chat_id: C, star_count: u32, media: M) -> Self::SendPaidMedia
+    where
+        C: Into<Recipient>,
+        M: IntoIterator<Item = InputPaidMedia>,
+    {
+        Self::SendPaidMedia::new(
+            self.clone(),
+            payloads::SendPaidMedia::new(chat_id, star_count, media),
+        )
+    }
+
     type SendMediaGroup = MultipartRequest<payloads::SendMediaGroup>;
 
     fn send_media_group<C, M>(&self, chat_id: C, media: M) -> Self::SendMediaGroup
@@ -283,7 +297,7 @@ impl Requester for Bot {
     where
         C: Into<Recipient>,
         Q: Into<String>,
-        O: IntoIterator<Item = String>,
+        O: IntoIterator<Item = InputPollOption>,
     {
         Self::SendPoll::new(self.clone(), payloads::SendPoll::new(chat_id, question, options))
     }
@@ -328,6 +342,48 @@ impl Requester for Bot {
         Self::GetUserProfilePhotos::new(self.clone(), payloads::GetUserProfilePhotos::new(user_id))
     }
 
+    type SetUserEmojiStatus = JsonRequest<payloads::SetUserEmojiStatus>;
+
+    fn set_user_emoji_status(&self, user_id: UserId) -> Self::SetUserEmojiStatus {
+        Self::SetUserEmojiStatus::new(self.clone(), payloads::SetUserEmojiStatus::new(user_id))
+    }
+
+    type VerifyUser = JsonRequest<payloads::VerifyUser>;
+
+    fn verify_user(&self, user_id: UserId) -> Self::VerifyUser {
+        Self::VerifyUser::new(self.clone(), payloads::VerifyUser::new(user_id))
+    }
+
+    type VerifyChat = JsonRequest<payloads::VerifyChat>;
+
+    fn verify_chat<C>(&self, chat_id: C) -> Self::VerifyChat
+    where
+        C: Into<Recipient>,
+    {
+        Self::VerifyChat::new(self.clone(), payloads::VerifyChat::new(chat_id))
+    }
+
+    type RemoveUserVerification = JsonRequest<payloads::RemoveUserVerification>;
+
+    fn remove_user_verification(&self, user_id: UserId) -> Self::RemoveUserVerification {
+        Self::RemoveUserVerification::new(
+            self.clone(),
+            payloads::RemoveUserVerification::new(user_id),
+        )
+    }
+
+    type RemoveChatVerification = JsonRequest<payloads::RemoveChatVerification>;
+
+    fn remove_chat_verification<C>(&self, chat_id: C) -> Self::RemoveChatVerification
+    where
+        C: Into<Recipient>,
+    {
+        Self::RemoveChatVerification::new(
+            self.clone(),
+            payloads::RemoveChatVerification::new(chat_id),
+        )
+    }
+
     type GetFile = JsonRequest<payloads::GetFile>;
 
     fn get_file<F>(&self, file_id: F) -> Self::GetFile
@@ -488,6 +544,44 @@ impl Requester for Bot {
         )
     }
 
+    type CreateChatSubscriptionInviteLink = JsonRequest<payloads::CreateChatSubscriptionInviteLink>;
+
+    fn create_chat_subscription_invite_link<C>(
+        &self,
+        chat_id: C,
+        subscription_period: u32,
+        subscription_price: u32,
+    ) -> Self::CreateChatSubscriptionInviteLink
+    where
+        C: Into<Recipient>,
+    {
+        Self::CreateChatSubscriptionInviteLink::new(
+            self.clone(),
+            payloads::CreateChatSubscriptionInviteLink::new(
+                chat_id,
+                subscription_period,
+                subscription_price,
+            ),
+        )
+    }
+
+    type EditChatSubscriptionInviteLink = JsonRequest<payloads::EditChatSubscriptionInviteLink>;
+
+    fn edit_chat_subscription_invite_link<C, I>(
+        &self,
+        chat_id: C,
+        invite_link: I,
+    ) -> Self::EditChatSubscriptionInviteLink
+    where
+        C: Into<Recipient>,
+        I: Into<String>,
+    {
+        Self::EditChatSubscriptionInviteLink::new(
+            self.clone(),
+            payloads::EditChatSubscriptionInviteLink::new(chat_id, invite_link),
+        )
+    }
+
     type RevokeChatInviteLink = JsonRequest<payloads::RevokeChatInviteLink>;
 
     fn revoke_chat_invite_link<C, I>(
@@ -894,6 +988,252 @@ impl Requester for Bot {
         )
     }
 
+    type ReadBusinessMessage = JsonRequest<payloads::ReadBusinessMessage>;
+
+    fn read_business_message<C>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: C,
+        message_id: MessageId,
+    ) -> Self::ReadBusinessMessage
+    where
+        C: Into<ChatId>,
+    {
+        Self::ReadBusinessMessage::new(
+            self.clone(),
+            payloads::ReadBusinessMessage::new(business_connection_id, chat_id, message_id),
+        )
+    }
+
+    type DeleteBusinessMessages = JsonRequest<payloads::DeleteBusinessMessages>;
+
+    fn delete_business_messages<M>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        message_ids: M,
+    ) -> Self::DeleteBusinessMessages
+    where
+        M: IntoIterator<Item = MessageId>,
+    {
+        Self::DeleteBusinessMessages::new(
+            self.clone(),
+            payloads::DeleteBusinessMessages::new(business_connection_id, message_ids),
+        )
+    }
+
+    type SetBusinessAccountName = JsonRequest<payloads::SetBusinessAccountName>;
+
+    fn set_business_account_name<F>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        first_name: F,
+    ) -> Self::SetBusinessAccountName
+    where
+        F: Into<String>,
+    {
+        Self::SetBusinessAccountName::new(
+            self.clone(),
+            payloads::SetBusinessAccountName::new(business_connection_id, first_name),
+        )
+    }
+
+    type SetBusinessAccountUsername = JsonRequest<payloads::SetBusinessAccountUsername>;
+
+    fn set_business_account_username(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::SetBusinessAccountUsername {
+        Self::SetBusinessAccountUsername::new(
+            self.clone(),
+            payloads::SetBusinessAccountUsername::new(business_connection_id),
+        )
+    }
+
+    type SetBusinessAccountBio = JsonRequest<payloads::SetBusinessAccountBio>;
+
+    fn set_business_account_bio(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::SetBusinessAccountBio {
+        Self::SetBusinessAccountBio::new(
+            self.clone(),
+            payloads::SetBusinessAccountBio::new(business_connection_id),
+        )
+    }
+
+    type SetBusinessAccountProfilePhoto =
+        MultipartRequest<payloads::SetBusinessAccountProfilePhoto>;
+
+    fn set_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        photo: InputProfilePhoto,
+    ) -> Self::SetBusinessAccountProfilePhoto {
+        Self::SetBusinessAccountProfilePhoto::new(
+            self.clone(),
+            payloads::SetBusinessAccountProfilePhoto::new(business_connection_id, photo),
+        )
+    }
+
+    type RemoveBusinessAccountProfilePhoto =
+        JsonRequest<payloads::RemoveBusinessAccountProfilePhoto>;
+
+    fn remove_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::RemoveBusinessAccountProfilePhoto {
+        Self::RemoveBusinessAccountProfilePhoto::new(
+            self.clone(),
+            payloads::RemoveBusinessAccountProfilePhoto::new(business_connection_id),
+        )
+    }
+
+    type SetBusinessAccountGiftSettings = JsonRequest<payloads::SetBusinessAccountGiftSettings>;
+
+    fn set_business_account_gift_settings(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        show_gift_button: bool,
+        accepted_gift_types: AcceptedGiftTypes,
+    ) -> Self::SetBusinessAccountGiftSettings {
+        Self::SetBusinessAccountGiftSettings::new(
+            self.clone(),
+            payloads::SetBusinessAccountGiftSettings::new(
+                business_connection_id,
+                show_gift_button,
+                accepted_gift_types,
+            ),
+        )
+    }
+
+    type GetBusinessAccountStarBalance = JsonRequest<payloads::GetBusinessAccountStarBalance>;
+
+    fn get_business_account_star_balance(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::GetBusinessAccountStarBalance {
+        Self::GetBusinessAccountStarBalance::new(
+            self.clone(),
+            payloads::GetBusinessAccountStarBalance::new(business_connection_id),
+        )
+    }
+
+    type TransferBusinessAccountStars = JsonRequest<payloads::TransferBusinessAccountStars>;
+
+    fn transfer_business_account_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        star_count: u32,
+    ) -> Self::TransferBusinessAccountStars {
+        Self::TransferBusinessAccountStars::new(
+            self.clone(),
+            payloads::TransferBusinessAccountStars::new(business_connection_id, star_count),
+        )
+    }
+
+    type GetBusinessAccountGifts = JsonRequest<payloads::GetBusinessAccountGifts>;
+
+    fn get_business_account_gifts(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::GetBusinessAccountGifts {
+        Self::GetBusinessAccountGifts::new(
+            self.clone(),
+            payloads::GetBusinessAccountGifts::new(business_connection_id),
+        )
+    }
+
+    type ConvertGiftToStars = JsonRequest<payloads::ConvertGiftToStars>;
+
+    fn convert_gift_to_stars<O>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: O,
+    ) -> Self::ConvertGiftToStars
+    where
+        O: Into<String>,
+    {
+        Self::ConvertGiftToStars::new(
+            self.clone(),
+            payloads::ConvertGiftToStars::new(business_connection_id, owned_gift_id),
+        )
+    }
+
+    type UpgradeGift = JsonRequest<payloads::UpgradeGift>;
+
+    fn upgrade_gift<O>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: O,
+    ) -> Self::UpgradeGift
+    where
+        O: Into<String>,
+    {
+        Self::UpgradeGift::new(
+            self.clone(),
+            payloads::UpgradeGift::new(business_connection_id, owned_gift_id),
+        )
+    }
+
+    type TransferGift = JsonRequest<payloads::TransferGift>;
+
+    fn transfer_gift<O, N>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: O,
+        new_owner_chat_id: N,
+    ) -> Self::TransferGift
+    where
+        O: Into<String>,
+        N: Into<ChatId>,
+    {
+        Self::TransferGift::new(
+            self.clone(),
+            payloads::TransferGift::new(business_connection_id, owned_gift_id, new_owner_chat_id),
+        )
+    }
+
+    type PostStory = MultipartRequest<payloads::PostStory>;
+
+    fn post_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        content: InputStoryContent,
+        active_period: u32,
+    ) -> Self::PostStory {
+        Self::PostStory::new(
+            self.clone(),
+            payloads::PostStory::new(business_connection_id, content, active_period),
+        )
+    }
+
+    type EditStory = MultipartRequest<payloads::EditStory>;
+
+    fn edit_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+        content: InputStoryContent,
+    ) -> Self::EditStory {
+        Self::EditStory::new(
+            self.clone(),
+            payloads::EditStory::new(business_connection_id, story_id, content),
+        )
+    }
+
+    type DeleteStory = JsonRequest<payloads::DeleteStory>;
+
+    fn delete_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+    ) -> Self::DeleteStory {
+        Self::DeleteStory::new(
+            self.clone(),
+            payloads::DeleteStory::new(business_connection_id, story_id),
+        )
+    }
+
     type GetMyCommands = JsonRequest<payloads::GetMyCommands>;
 
     fn get_my_commands(&self) -> Self::GetMyCommands {
@@ -1000,6 +1340,19 @@ impl Requester for Bot {
         )
     }
 
+    type SavePreparedInlineMessage = JsonRequest<payloads::SavePreparedInlineMessage>;
+
+    fn save_prepared_inline_message(
+        &self,
+        user_id: UserId,
+        result: InlineQueryResult,
+    ) -> Self::SavePreparedInlineMessage {
+        Self::SavePreparedInlineMessage::new(
+            self.clone(),
+            payloads::SavePreparedInlineMessage::new(user_id, result),
+        )
+    }
+
     type EditMessageText = JsonRequest<payloads::EditMessageText>;
 
     fn edit_message_text<C, T>(
@@ -1366,70 +1719,66 @@ impl Requester for Bot {
         )
     }
 
+    type GetAvailableGifts = JsonRequest<payloads::GetAvailableGifts>;
+
+    fn get_available_gifts(&self) -> Self::GetAvailableGifts {
+        Self::GetAvailableGifts::new(self.clone(), payloads::GetAvailableGifts::new())
+    }
+
+    type SendGift = JsonRequest<payloads::SendGift>;
+
+    fn send_gift<G>(&self, user_id: UserId, gift_id: G) -> Self::SendGift
+    where
+        G: Into<String>,
+    {
+        Self::SendGift::new(self.clone(), payloads::SendGift::new(user_id, gift_id))
+    }
+
     type SendInvoice = JsonRequest<payloads::SendInvoice>;
 
-    fn send_invoice<Ch, T, D, Pa, P, C, Pri>(
+    fn send_invoice<Ch, T, D, Pa, C, P>(
         &self,
         chat_id: Ch,
         title: T,
         description: D,
         payload: Pa,
-        provider_token: P,
         currency: C,
-        prices: Pri,
+        prices: P,
     ) -> Self::SendInvoice
     where
         Ch: Into<Recipient>,
         T: Into<String>,
         D: Into<String>,
         Pa: Into<String>,
-        P: Into<String>,
         C: Into<String>,
-        Pri: IntoIterator<Item = LabeledPrice>,
+        P: IntoIterator<Item = LabeledPrice>,
     {
         Self::SendInvoice::new(
             self.clone(),
-            payloads::SendInvoice::new(
-                chat_id,
-                title,
-                description,
-                payload,
-                provider_token,
-                currency,
-                prices,
-            ),
+            payloads::SendInvoice::new(chat_id, title, description, payload, currency, prices),
         )
     }
 
     type CreateInvoiceLink = JsonRequest<payloads::CreateInvoiceLink>;
 
-    fn create_invoice_link<T, D, Pa, P, C, Pri>(
+    fn create_invoice_link<T, D, Pa, C, P>(
         &self,
         title: T,
         description: D,
         payload: Pa,
-        provider_token: P,
         currency: C,
-        prices: Pri,
+        prices: P,
     ) -> Self::CreateInvoiceLink
     where
         T: Into<String>,
         D: Into<String>,
         Pa: Into<String>,
-        P: Into<String>,
         C: Into<String>,
-        Pri: IntoIterator<Item = LabeledPrice>,
+        P: IntoIterator<Item = LabeledPrice>,
     {
         Self::CreateInvoiceLink::new(
             self.clone(),
-            payloads::CreateInvoiceLink::new(
-                title,
-                description,
-                payload,
-                provider_token,
-                currency,
-                prices,
-            ),
+            payloads::CreateInvoiceLink::new(title, description, payload, currency, prices),
         )
     }
 
@@ -1461,6 +1810,28 @@ impl Requester for Bot {
         )
     }
 
+    type GetStarTransactions = JsonRequest<payloads::GetStarTransactions>;
+
+    fn get_star_transactions(&self) -> Self::GetStarTransactions {
+        Self::GetStarTransactions::new(self.clone(), payloads::GetStarTransactions::new())
+    }
+
+    type RefundStarPayment = JsonRequest<payloads::RefundStarPayment>;
+
+    fn refund_star_payment<T>(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: T,
+    ) -> Self::RefundStarPayment
+    where
+        T: Into<String>,
+    {
+        Self::RefundStarPayment::new(
+            self.clone(),
+            payloads::RefundStarPayment::new(user_id, telegram_payment_charge_id),
+        )
+    }
+
     type SetPassportDataErrors = JsonRequest<payloads::SetPassportDataErrors>;
 
     fn set_passport_data_errors<E>(&self, user_id: UserId, errors: E) -> Self::SetPassportDataErrors
@@ -1,6 +1,11 @@
+use std::path::Path;
+
 use bytes::Bytes;
-use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
+use futures::{
+    future::BoxFuture, stream::BoxStream, FutureExt, StreamExt, TryFutureExt, TryStreamExt,
+};
 use tokio::io::AsyncWrite;
+use tokio_util::codec::{BytesCodec, FramedRead};
 
 use crate::{
     bot::Bot,
@@ -20,28 +25,46 @@ impl Download for Bot {
         path: &str,
         destination: &'dst mut (dyn AsyncWrite + Unpin + Send),
     ) -> Self::Fut<'dst> {
-        net::download_file(
-            &self.client,
-            reqwest::Url::clone(&*self.api_url),
-            &self.token,
-            path,
-            destination,
-        )
-        .boxed()
+        if self.is_local_file(path) {
+            let path = path.to_owned();
+            return async move {
+                let mut file = tokio::fs::File::open(path).await?;
+                tokio::io::copy(&mut file, destination).await?;
+                Ok(())
+            }
+            .boxed();
+        }
+
+        net::download_file_from_url(&self.client, self.file_url(path), destination).boxed()
     }
 
-    type StreamErr = reqwest::Error;
+    type StreamErr = DownloadError;
 
     type Stream = BoxStream<'static, Result<Bytes, Self::StreamErr>>;
 
     fn download_file_stream(&self, path: &str) -> Self::Stream {
-        net::download_file_stream(
-            &self.client,
-            reqwest::Url::clone(&*self.api_url),
-            &self.token,
-            path,
-        )
-        .map(|res| res.map_err(crate::errors::hide_token))
-        .boxed()
+        if self.is_local_file(path) {
+            return tokio::fs::File::open(path.to_owned())
+                .map_ok(|file| FramedRead::new(file, BytesCodec::new()).map_ok(|b| b.freeze()))
+                .try_flatten_stream()
+                .map_err(DownloadError::Io)
+                .boxed();
+        }
+
+        net::download_file_stream_from_url(&self.client, self.file_url(path))
+            .map_err(DownloadError::from)
+            .boxed()
+    }
+}
+
+impl Bot {
+    /// In the local mode, Bot API servers return absolute paths of files,
+    /// which can be read directly.
+    fn is_local_file(&self, path: &str) -> bool {
+        self.local && Path::new(path).is_absolute()
+    }
+
+    fn file_url(&self, path: &str) -> reqwest::Url {
+        net::file_url(reqwest::Url::clone(&*self.api_url), &self.token, path, self.test_env)
     }
 }
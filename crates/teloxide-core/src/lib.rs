@@ -43,6 +43,7 @@
 //! - `erased` — enables [`ErasedRequester`] bot adaptor
 //! - `throttle` — enables [`Throttle`] bot adaptor
 //! - `cache_me` — enables [`CacheMe`] bot adaptor
+//! - `testing` — enables [`MockBot`] for testing bots without network access
 //! - `full` — enables all features except `nightly` and tls-related
 //! - `nightly` — enables nightly-only features, currently:
 //!   - Removes some future boxing using `#![feature(type_alias_impl_trait)]`
@@ -53,6 +54,7 @@
 //! [`ErasedRequester`]: adaptors::ErasedRequester
 //! [`Throttle`]: adaptors::Throttle
 //! [`CacheMe`]: adaptors::CacheMe
+//! [`MockBot`]: testing::MockBot
 //! [`native-tls`]: https://docs.rs/native-tls
 //! [`rustls`]: https://docs.rs/rustls
 
@@ -122,6 +124,8 @@ pub mod net;
 pub mod payloads;
 pub mod prelude;
 pub mod requests;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod types;
 
 // reexported
@@ -572,6 +572,15 @@ macro_rules! requester_forward {
             $body!(send_video_note this (chat_id: C, video_note: InputFile))
         }
     };
+    (@method send_paid_media $body:ident $ty:ident) => {
+        type SendPaidMedia = $ty![SendPaidMedia];
+
+        fn send_paid_media<C, M>(&self, chat_id: C, star_count: u32, media: M) -> Self::SendPaidMedia where C: Into<Recipient>,
+        M: IntoIterator<Item = InputPaidMedia> {
+            let this = self;
+            $body!(send_paid_media this (chat_id: C, star_count: u32, media: M))
+        }
+    };
     (@method send_media_group $body:ident $ty:ident) => {
         type SendMediaGroup = $ty![SendMediaGroup];
 
@@ -646,7 +655,7 @@ macro_rules! requester_forward {
 
         fn send_poll<C, Q, O>(&self, chat_id: C, question: Q, options: O) -> Self::SendPoll where C: Into<Recipient>,
         Q: Into<String>,
-        O: IntoIterator<Item = String> {
+        O: IntoIterator<Item = InputPollOption> {
             let this = self;
             $body!(send_poll this (chat_id: C, question: Q, options: O))
         }
@@ -683,6 +692,46 @@ macro_rules! requester_forward {
             $body!(get_user_profile_photos this (user_id: UserId))
         }
     };
+    (@method set_user_emoji_status $body:ident $ty:ident) => {
+        type SetUserEmojiStatus = $ty![SetUserEmojiStatus];
+
+        fn set_user_emoji_status(&self, user_id: UserId) -> Self::SetUserEmojiStatus {
+            let this = self;
+            $body!(set_user_emoji_status this (user_id: UserId))
+        }
+    };
+    (@method verify_user $body:ident $ty:ident) => {
+        type VerifyUser = $ty![VerifyUser];
+
+        fn verify_user(&self, user_id: UserId) -> Self::VerifyUser {
+            let this = self;
+            $body!(verify_user this (user_id: UserId))
+        }
+    };
+    (@method verify_chat $body:ident $ty:ident) => {
+        type VerifyChat = $ty![VerifyChat];
+
+        fn verify_chat<C>(&self, chat_id: C) -> Self::VerifyChat where C: Into<Recipient> {
+            let this = self;
+            $body!(verify_chat this (chat_id: C))
+        }
+    };
+    (@method remove_user_verification $body:ident $ty:ident) => {
+        type RemoveUserVerification = $ty![RemoveUserVerification];
+
+        fn remove_user_verification(&self, user_id: UserId) -> Self::RemoveUserVerification {
+            let this = self;
+            $body!(remove_user_verification this (user_id: UserId))
+        }
+    };
+    (@method remove_chat_verification $body:ident $ty:ident) => {
+        type RemoveChatVerification = $ty![RemoveChatVerification];
+
+        fn remove_chat_verification<C>(&self, chat_id: C) -> Self::RemoveChatVerification where C: Into<Recipient> {
+            let this = self;
+            $body!(remove_chat_verification this (chat_id: C))
+        }
+    };
     (@method get_file $body:ident $ty:ident) => {
         type GetFile = $ty![GetFile];
 
@@ -791,6 +840,23 @@ macro_rules! requester_forward {
             $body!(edit_chat_invite_link this (chat_id: C, invite_link: I))
         }
     };
+    (@method create_chat_subscription_invite_link $body:ident $ty:ident) => {
+        type CreateChatSubscriptionInviteLink = $ty![CreateChatSubscriptionInviteLink];
+
+        fn create_chat_subscription_invite_link<C>(&self, chat_id: C, subscription_period: u32, subscription_price: u32) -> Self::CreateChatSubscriptionInviteLink where C: Into<Recipient> {
+            let this = self;
+            $body!(create_chat_subscription_invite_link this (chat_id: C, subscription_period: u32, subscription_price: u32))
+        }
+    };
+    (@method edit_chat_subscription_invite_link $body:ident $ty:ident) => {
+        type EditChatSubscriptionInviteLink = $ty![EditChatSubscriptionInviteLink];
+
+        fn edit_chat_subscription_invite_link<C, I>(&self, chat_id: C, invite_link: I) -> Self::EditChatSubscriptionInviteLink where C: Into<Recipient>,
+        I: Into<String> {
+            let this = self;
+            $body!(edit_chat_subscription_invite_link this (chat_id: C, invite_link: I))
+        }
+    };
     (@method revoke_chat_invite_link $body:ident $ty:ident) => {
         type RevokeChatInviteLink = $ty![RevokeChatInviteLink];
 
@@ -1077,6 +1143,143 @@ macro_rules! requester_forward {
             $body!(get_business_connection this (business_connection_id: BusinessConnectionId))
         }
     };
+    (@method read_business_message $body:ident $ty:ident) => {
+        type ReadBusinessMessage = $ty![ReadBusinessMessage];
+
+        fn read_business_message<C>(&self, business_connection_id: BusinessConnectionId, chat_id: C, message_id: MessageId) -> Self::ReadBusinessMessage where C: Into<ChatId> {
+            let this = self;
+            $body!(read_business_message this (business_connection_id: BusinessConnectionId, chat_id: C, message_id: MessageId))
+        }
+    };
+    (@method delete_business_messages $body:ident $ty:ident) => {
+        type DeleteBusinessMessages = $ty![DeleteBusinessMessages];
+
+        fn delete_business_messages<M>(&self, business_connection_id: BusinessConnectionId, message_ids: M) -> Self::DeleteBusinessMessages where M: IntoIterator<Item = MessageId> {
+            let this = self;
+            $body!(delete_business_messages this (business_connection_id: BusinessConnectionId, message_ids: M))
+        }
+    };
+    (@method set_business_account_name $body:ident $ty:ident) => {
+        type SetBusinessAccountName = $ty![SetBusinessAccountName];
+
+        fn set_business_account_name<F>(&self, business_connection_id: BusinessConnectionId, first_name: F) -> Self::SetBusinessAccountName where F: Into<String> {
+            let this = self;
+            $body!(set_business_account_name this (business_connection_id: BusinessConnectionId, first_name: F))
+        }
+    };
+    (@method set_business_account_username $body:ident $ty:ident) => {
+        type SetBusinessAccountUsername = $ty![SetBusinessAccountUsername];
+
+        fn set_business_account_username(&self, business_connection_id: BusinessConnectionId) -> Self::SetBusinessAccountUsername {
+            let this = self;
+            $body!(set_business_account_username this (business_connection_id: BusinessConnectionId))
+        }
+    };
+    (@method set_business_account_bio $body:ident $ty:ident) => {
+        type SetBusinessAccountBio = $ty![SetBusinessAccountBio];
+
+        fn set_business_account_bio(&self, business_connection_id: BusinessConnectionId) -> Self::SetBusinessAccountBio {
+            let this = self;
+            $body!(set_business_account_bio this (business_connection_id: BusinessConnectionId))
+        }
+    };
+    (@method set_business_account_profile_photo $body:ident $ty:ident) => {
+        type SetBusinessAccountProfilePhoto = $ty![SetBusinessAccountProfilePhoto];
+
+        fn set_business_account_profile_photo(&self, business_connection_id: BusinessConnectionId, photo: InputProfilePhoto) -> Self::SetBusinessAccountProfilePhoto {
+            let this = self;
+            $body!(set_business_account_profile_photo this (business_connection_id: BusinessConnectionId, photo: InputProfilePhoto))
+        }
+    };
+    (@method remove_business_account_profile_photo $body:ident $ty:ident) => {
+        type RemoveBusinessAccountProfilePhoto = $ty![RemoveBusinessAccountProfilePhoto];
+
+        fn remove_business_account_profile_photo(&self, business_connection_id: BusinessConnectionId) -> Self::RemoveBusinessAccountProfilePhoto {
+            let this = self;
+            $body!(remove_business_account_profile_photo this (business_connection_id: BusinessConnectionId))
+        }
+    };
+    (@method set_business_account_gift_settings $body:ident $ty:ident) => {
+        type SetBusinessAccountGiftSettings = $ty![SetBusinessAccountGiftSettings];
+
+        fn set_business_account_gift_settings(&self, business_connection_id: BusinessConnectionId, show_gift_button: bool, accepted_gift_types: AcceptedGiftTypes) -> Self::SetBusinessAccountGiftSettings {
+            let this = self;
+            $body!(set_business_account_gift_settings this (business_connection_id: BusinessConnectionId, show_gift_button: bool, accepted_gift_types: AcceptedGiftTypes))
+        }
+    };
+    (@method get_business_account_star_balance $body:ident $ty:ident) => {
+        type GetBusinessAccountStarBalance = $ty![GetBusinessAccountStarBalance];
+
+        fn get_business_account_star_balance(&self, business_connection_id: BusinessConnectionId) -> Self::GetBusinessAccountStarBalance {
+            let this = self;
+            $body!(get_business_account_star_balance this (business_connection_id: BusinessConnectionId))
+        }
+    };
+    (@method transfer_business_account_stars $body:ident $ty:ident) => {
+        type TransferBusinessAccountStars = $ty![TransferBusinessAccountStars];
+
+        fn transfer_business_account_stars(&self, business_connection_id: BusinessConnectionId, star_count: u32) -> Self::TransferBusinessAccountStars {
+            let this = self;
+            $body!(transfer_business_account_stars this (business_connection_id: BusinessConnectionId, star_count: u32))
+        }
+    };
+    (@method get_business_account_gifts $body:ident $ty:ident) => {
+        type GetBusinessAccountGifts = $ty![GetBusinessAccountGifts];
+
+        fn get_business_account_gifts(&self, business_connection_id: BusinessConnectionId) -> Self::GetBusinessAccountGifts {
+            let this = self;
+            $body!(get_business_account_gifts this (business_connection_id: BusinessConnectionId))
+        }
+    };
+    (@method convert_gift_to_stars $body:ident $ty:ident) => {
+        type ConvertGiftToStars = $ty![ConvertGiftToStars];
+
+        fn convert_gift_to_stars<O>(&self, business_connection_id: BusinessConnectionId, owned_gift_id: O) -> Self::ConvertGiftToStars where O: Into<String> {
+            let this = self;
+            $body!(convert_gift_to_stars this (business_connection_id: BusinessConnectionId, owned_gift_id: O))
+        }
+    };
+    (@method upgrade_gift $body:ident $ty:ident) => {
+        type UpgradeGift = $ty![UpgradeGift];
+
+        fn upgrade_gift<O>(&self, business_connection_id: BusinessConnectionId, owned_gift_id: O) -> Self::UpgradeGift where O: Into<String> {
+            let this = self;
+            $body!(upgrade_gift this (business_connection_id: BusinessConnectionId, owned_gift_id: O))
+        }
+    };
+    (@method transfer_gift $body:ident $ty:ident) => {
+        type TransferGift = $ty![TransferGift];
+
+        fn transfer_gift<O, N>(&self, business_connection_id: BusinessConnectionId, owned_gift_id: O, new_owner_chat_id: N) -> Self::TransferGift where O: Into<String>,
+        N: Into<ChatId> {
+            let this = self;
+            $body!(transfer_gift this (business_connection_id: BusinessConnectionId, owned_gift_id: O, new_owner_chat_id: N))
+        }
+    };
+    (@method post_story $body:ident $ty:ident) => {
+        type PostStory = $ty![PostStory];
+
+        fn post_story(&self, business_connection_id: BusinessConnectionId, content: InputStoryContent, active_period: u32) -> Self::PostStory {
+            let this = self;
+            $body!(post_story this (business_connection_id: BusinessConnectionId, content: InputStoryContent, active_period: u32))
+        }
+    };
+    (@method edit_story $body:ident $ty:ident) => {
+        type EditStory = $ty![EditStory];
+
+        fn edit_story(&self, business_connection_id: BusinessConnectionId, story_id: StoryId, content: InputStoryContent) -> Self::EditStory {
+            let this = self;
+            $body!(edit_story this (business_connection_id: BusinessConnectionId, story_id: StoryId, content: InputStoryContent))
+        }
+    };
+    (@method delete_story $body:ident $ty:ident) => {
+        type DeleteStory = $ty![DeleteStory];
+
+        fn delete_story(&self, business_connection_id: BusinessConnectionId, story_id: StoryId) -> Self::DeleteStory {
+            let this = self;
+            $body!(delete_story this (business_connection_id: BusinessConnectionId, story_id: StoryId))
+        }
+    };
     (@method get_my_commands $body:ident $ty:ident) => {
         type GetMyCommands = $ty![GetMyCommands];
 
@@ -1190,6 +1393,14 @@ macro_rules! requester_forward {
             $body!(answer_web_app_query this (web_app_query_id: W, result: InlineQueryResult))
         }
     };
+    (@method save_prepared_inline_message $body:ident $ty:ident) => {
+        type SavePreparedInlineMessage = $ty![SavePreparedInlineMessage];
+
+        fn save_prepared_inline_message(&self, user_id: UserId, result: InlineQueryResult) -> Self::SavePreparedInlineMessage {
+            let this = self;
+            $body!(save_prepared_inline_message this (user_id: UserId, result: InlineQueryResult))
+        }
+    };
     (@method edit_message_text $body:ident $ty:ident) => {
         type EditMessageText = $ty![EditMessageText];
 
@@ -1414,31 +1625,45 @@ macro_rules! requester_forward {
             $body!(set_sticker_mask_position this (sticker: S))
         }
     };
+    (@method get_available_gifts $body:ident $ty:ident) => {
+        type GetAvailableGifts = $ty![GetAvailableGifts];
+
+        fn get_available_gifts(&self, ) -> Self::GetAvailableGifts {
+            let this = self;
+            $body!(get_available_gifts this ())
+        }
+    };
+    (@method send_gift $body:ident $ty:ident) => {
+        type SendGift = $ty![SendGift];
+
+        fn send_gift<G>(&self, user_id: UserId, gift_id: G) -> Self::SendGift where G: Into<String> {
+            let this = self;
+            $body!(send_gift this (user_id: UserId, gift_id: G))
+        }
+    };
     (@method send_invoice $body:ident $ty:ident) => {
         type SendInvoice = $ty![SendInvoice];
 
-        fn send_invoice<Ch, T, D, Pa, P, C, Pri>(&self, chat_id: Ch, title: T, description: D, payload: Pa, provider_token: P, currency: C, prices: Pri) -> Self::SendInvoice where Ch: Into<Recipient>,
+        fn send_invoice<Ch, T, D, Pa, C, P>(&self, chat_id: Ch, title: T, description: D, payload: Pa, currency: C, prices: P) -> Self::SendInvoice where Ch: Into<Recipient>,
         T: Into<String>,
         D: Into<String>,
         Pa: Into<String>,
-        P: Into<String>,
         C: Into<String>,
-        Pri: IntoIterator<Item = LabeledPrice> {
+        P: IntoIterator<Item = LabeledPrice> {
             let this = self;
-            $body!(send_invoice this (chat_id: Ch, title: T, description: D, payload: Pa, provider_token: P, currency: C, prices: Pri))
+            $body!(send_invoice this (chat_id: Ch, title: T, description: D, payload: Pa, currency: C, prices: P))
         }
     };
     (@method create_invoice_link $body:ident $ty:ident) => {
         type CreateInvoiceLink = $ty![CreateInvoiceLink];
 
-        fn create_invoice_link<T, D, Pa, P, C, Pri>(&self, title: T, description: D, payload: Pa, provider_token: P, currency: C, prices: Pri) -> Self::CreateInvoiceLink where T: Into<String>,
+        fn create_invoice_link<T, D, Pa, C, P>(&self, title: T, description: D, payload: Pa, currency: C, prices: P) -> Self::CreateInvoiceLink where T: Into<String>,
         D: Into<String>,
         Pa: Into<String>,
-        P: Into<String>,
         C: Into<String>,
-        Pri: IntoIterator<Item = LabeledPrice> {
+        P: IntoIterator<Item = LabeledPrice> {
             let this = self;
-            $body!(create_invoice_link this (title: T, description: D, payload: Pa, provider_token: P, currency: C, prices: Pri))
+            $body!(create_invoice_link this (title: T, description: D, payload: Pa, currency: C, prices: P))
         }
     };
     (@method answer_shipping_query $body:ident $ty:ident) => {
@@ -1457,6 +1682,22 @@ macro_rules! requester_forward {
             $body!(answer_pre_checkout_query this (pre_checkout_query_id: P, ok: bool))
         }
     };
+    (@method get_star_transactions $body:ident $ty:ident) => {
+        type GetStarTransactions = $ty![GetStarTransactions];
+
+        fn get_star_transactions(&self, ) -> Self::GetStarTransactions {
+            let this = self;
+            $body!(get_star_transactions this ())
+        }
+    };
+    (@method refund_star_payment $body:ident $ty:ident) => {
+        type RefundStarPayment = $ty![RefundStarPayment];
+
+        fn refund_star_payment<T>(&self, user_id: UserId, telegram_payment_charge_id: T) -> Self::RefundStarPayment where T: Into<String> {
+            let this = self;
+            $body!(refund_star_payment this (user_id: UserId, telegram_payment_charge_id: T))
+        }
+    };
     (@method set_passport_data_errors $body:ident $ty:ident) => {
         type SetPassportDataErrors = $ty![SetPassportDataErrors];
 
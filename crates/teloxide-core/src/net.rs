@@ -5,6 +5,7 @@ use std::time::Duration;
 pub use self::download::{download_file, download_file_stream, Download};
 
 pub(crate) use self::{
+    download::{download_file_from_url, download_file_stream_from_url},
     request::{request_json, request_multipart},
     telegram_response::TelegramResponse,
 };
@@ -16,6 +17,15 @@ mod telegram_response;
 /// The default Telegram API URL.
 pub const TELEGRAM_API_URL: &str = "https://api.telegram.org";
 
+/// The maximum size of a file uploaded to the Telegram API, in bytes.
+pub const UPLOAD_SIZE_LIMIT: u64 = 50 * 1024 * 1024;
+
+/// The maximum size of a file uploaded to a [Telegram bot API server][tbas]
+/// running in the local mode, in bytes.
+///
+/// [tbas]: https://github.com/tdlib/telegram-bot-api
+pub const LOCAL_UPLOAD_SIZE_LIMIT: u64 = 2000 * 1024 * 1024;
+
 /// Constructs a network client from the `TELOXIDE_PROXY` environmental
 /// variable.
 ///
@@ -78,16 +88,36 @@ pub fn default_reqwest_settings() -> reqwest::ClientBuilder {
 
 /// Creates URL for making HTTPS requests. See the [Telegram documentation].
 ///
+/// If `test_env` is `true`, the URL points to the [test environment].
+///
 /// [Telegram documentation]: https://core.telegram.org/bots/api#making-requests
-fn method_url(base: reqwest::Url, token: &str, method_name: &str) -> reqwest::Url {
-    base.join(&format!("/bot{token}/{method_name}")).expect("failed to format url")
+/// [test environment]: https://core.telegram.org/bots/webapps#using-bots-in-the-test-environment
+fn method_url(base: reqwest::Url, token: &str, method_name: &str, test_env: bool) -> reqwest::Url {
+    let env = env_segment(test_env);
+    base.join(&format!("/bot{token}/{env}{method_name}")).expect("failed to format url")
 }
 
 /// Creates URL for downloading a file. See the [Telegram documentation].
 ///
+/// If `test_env` is `true`, the URL points to the [test environment].
+///
 /// [Telegram documentation]: https://core.telegram.org/bots/api#file
-fn file_url(base: reqwest::Url, token: &str, file_path: &str) -> reqwest::Url {
-    base.join(&format!("file/bot{token}/{file_path}")).expect("failed to format url")
+/// [test environment]: https://core.telegram.org/bots/webapps#using-bots-in-the-test-environment
+pub(crate) fn file_url(
+    base: reqwest::Url,
+    token: &str,
+    file_path: &str,
+    test_env: bool,
+) -> reqwest::Url {
+    let env = env_segment(test_env);
+    base.join(&format!("file/bot{token}/{env}{file_path}")).expect("failed to format url")
+}
+
+fn env_segment(test_env: bool) -> &'static str {
+    match test_env {
+        true => "test/",
+        false => "",
+    }
 }
 
 #[cfg(test)]
@@ -100,6 +130,7 @@ mod tests {
             reqwest::Url::parse(TELEGRAM_API_URL).unwrap(),
             "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao",
             "methodName",
+            false,
         );
 
         assert_eq!(
@@ -108,12 +139,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn method_url_test_env_test() {
+        let url = method_url(
+            reqwest::Url::parse(TELEGRAM_API_URL).unwrap(),
+            "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao",
+            "methodName",
+            true,
+        );
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.telegram.org/bot535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao/test/methodName"
+        );
+    }
+
     #[test]
     fn file_url_test() {
         let url = file_url(
             reqwest::Url::parse(TELEGRAM_API_URL).unwrap(),
             "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao",
             "AgADAgADyqoxG2g8aEsu_KjjVsGF4-zetw8ABAEAAwIAA20AA_8QAwABFgQ",
+            false,
         );
 
         assert_eq!(
@@ -121,4 +168,19 @@ mod tests {
             "https://api.telegram.org/file/bot535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao/AgADAgADyqoxG2g8aEsu_KjjVsGF4-zetw8ABAEAAwIAA20AA_8QAwABFgQ"
         );
     }
+
+    #[test]
+    fn file_url_test_env_test() {
+        let url = file_url(
+            reqwest::Url::parse(TELEGRAM_API_URL).unwrap(),
+            "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao",
+            "photos/file_0.jpg",
+            true,
+        );
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.telegram.org/file/bot535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao/test/photos/file_0.jpg"
+        );
+    }
 }
@@ -96,7 +96,19 @@ pub fn download_file<'o, D>(
 where
     D: ?Sized + AsyncWrite + Unpin,
 {
-    client.get(file_url(api_url, token, path)).send().then(move |r| async move {
+    download_file_from_url(client, file_url(api_url, token, path, false), dst)
+}
+
+/// Like [`download_file`], but with an already built URL of the file.
+pub(crate) fn download_file_from_url<'o, D>(
+    client: &Client,
+    url: Url,
+    dst: &'o mut D,
+) -> impl Future<Output = Result<(), DownloadError>> + 'o
+where
+    D: ?Sized + AsyncWrite + Unpin,
+{
+    client.get(url).send().then(move |r| async move {
         let mut res = r?.error_for_status()?;
 
         while let Some(chunk) = res.chunk().await? {
@@ -118,7 +130,15 @@ pub fn download_file_stream(
     token: &str,
     path: &str,
 ) -> impl Stream<Item = reqwest::Result<Bytes>> + 'static {
-    client.get(file_url(api_url, token, path)).send().into_stream().flat_map(|res| {
+    download_file_stream_from_url(client, file_url(api_url, token, path, false))
+}
+
+/// Like [`download_file_stream`], but with an already built URL of the file.
+pub(crate) fn download_file_stream_from_url(
+    client: &Client,
+    url: Url,
+) -> impl Stream<Item = reqwest::Result<Bytes>> + 'static {
+    client.get(url).send().into_stream().flat_map(|res| {
         match res.and_then(Response::error_for_status) {
             Ok(res) => Either::Left(unfold(res, |mut res| async {
                 match res.chunk().await {
@@ -16,6 +16,7 @@ pub async fn request_multipart<T>(
     api_url: reqwest::Url,
     method_name: &str,
     params: reqwest::multipart::Form,
+    test_env: bool,
     _timeout_hint: Option<Duration>,
 ) -> ResponseResult<T>
 where
@@ -35,7 +36,7 @@ where
     let method_name = method_name.trim_end_matches("Inline");
 
     let request = client
-        .post(crate::net::method_url(api_url, token, method_name))
+        .post(crate::net::method_url(api_url, token, method_name, test_env))
         .multipart(params)
         .build()?;
 
@@ -55,6 +56,7 @@ pub async fn request_json<T>(
     api_url: reqwest::Url,
     method_name: &str,
     params: Vec<u8>,
+    test_env: bool,
     _timeout_hint: Option<Duration>,
 ) -> ResponseResult<T>
 where
@@ -74,7 +76,7 @@ where
     let method_name = method_name.trim_end_matches("Inline");
 
     let request = client
-        .post(crate::net::method_url(api_url, token, method_name))
+        .post(crate::net::method_url(api_url, token, method_name, test_env))
         .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
         .body(params)
         .build()?;
@@ -27,13 +27,16 @@ mod ban_chat_sender_chat;
 mod close;
 mod close_forum_topic;
 mod close_general_forum_topic;
+mod convert_gift_to_stars;
 mod copy_message;
 mod copy_messages;
 mod create_chat_invite_link;
+mod create_chat_subscription_invite_link;
 mod create_forum_topic;
 mod create_invoice_link;
 mod create_new_sticker_set;
 mod decline_chat_join_request;
+mod delete_business_messages;
 mod delete_chat_photo;
 mod delete_chat_sticker_set;
 mod delete_forum_topic;
@@ -42,8 +45,10 @@ mod delete_messages;
 mod delete_my_commands;
 mod delete_sticker_from_set;
 mod delete_sticker_set;
+mod delete_story;
 mod delete_webhook;
 mod edit_chat_invite_link;
+mod edit_chat_subscription_invite_link;
 mod edit_forum_topic;
 mod edit_general_forum_topic;
 mod edit_message_caption;
@@ -56,9 +61,13 @@ mod edit_message_reply_markup;
 mod edit_message_reply_markup_inline;
 mod edit_message_text;
 mod edit_message_text_inline;
+mod edit_story;
 mod export_chat_invite_link;
 mod forward_message;
 mod forward_messages;
+mod get_available_gifts;
+mod get_business_account_gifts;
+mod get_business_account_star_balance;
 mod get_business_connection;
 mod get_chat;
 mod get_chat_administrators;
@@ -76,6 +85,7 @@ mod get_my_default_administrator_rights;
 mod get_my_description;
 mod get_my_name;
 mod get_my_short_description;
+mod get_star_transactions;
 mod get_sticker_set;
 mod get_updates;
 mod get_user_chat_boosts;
@@ -86,12 +96,19 @@ mod kick_chat_member;
 mod leave_chat;
 mod log_out;
 mod pin_chat_message;
+mod post_story;
 mod promote_chat_member;
+mod read_business_message;
+mod refund_star_payment;
+mod remove_business_account_profile_photo;
+mod remove_chat_verification;
+mod remove_user_verification;
 mod reopen_forum_topic;
 mod reopen_general_forum_topic;
 mod replace_sticker_in_set;
 mod restrict_chat_member;
 mod revoke_chat_invite_link;
+mod save_prepared_inline_message;
 mod send_animation;
 mod send_audio;
 mod send_chat_action;
@@ -99,10 +116,12 @@ mod send_contact;
 mod send_dice;
 mod send_document;
 mod send_game;
+mod send_gift;
 mod send_invoice;
 mod send_location;
 mod send_media_group;
 mod send_message;
+mod send_paid_media;
 mod send_photo;
 mod send_poll;
 mod send_sticker;
@@ -110,6 +129,11 @@ mod send_venue;
 mod send_video;
 mod send_video_note;
 mod send_voice;
+mod set_business_account_bio;
+mod set_business_account_gift_settings;
+mod set_business_account_name;
+mod set_business_account_profile_photo;
+mod set_business_account_username;
 mod set_chat_administrator_custom_title;
 mod set_chat_description;
 mod set_chat_menu_button;
@@ -133,10 +157,13 @@ mod set_sticker_mask_position;
 mod set_sticker_position_in_set;
 mod set_sticker_set_thumbnail;
 mod set_sticker_set_title;
+mod set_user_emoji_status;
 mod set_webhook;
 mod stop_message_live_location;
 mod stop_message_live_location_inline;
 mod stop_poll;
+mod transfer_business_account_stars;
+mod transfer_gift;
 mod unban_chat_member;
 mod unban_chat_sender_chat;
 mod unhide_general_forum_topic;
@@ -144,7 +171,10 @@ mod unpin_all_chat_messages;
 mod unpin_all_forum_topic_messages;
 mod unpin_all_general_forum_topic_messages;
 mod unpin_chat_message;
+mod upgrade_gift;
 mod upload_sticker_file;
+mod verify_chat;
+mod verify_user;
 
 pub use add_sticker_to_set::{AddStickerToSet, AddStickerToSetSetters};
 pub use answer_callback_query::{AnswerCallbackQuery, AnswerCallbackQuerySetters};
@@ -158,13 +188,18 @@ pub use ban_chat_sender_chat::{BanChatSenderChat, BanChatSenderChatSetters};
 pub use close::{Close, CloseSetters};
 pub use close_forum_topic::{CloseForumTopic, CloseForumTopicSetters};
 pub use close_general_forum_topic::{CloseGeneralForumTopic, CloseGeneralForumTopicSetters};
+pub use convert_gift_to_stars::{ConvertGiftToStars, ConvertGiftToStarsSetters};
 pub use copy_message::{CopyMessage, CopyMessageSetters};
 pub use copy_messages::{CopyMessages, CopyMessagesSetters};
 pub use create_chat_invite_link::{CreateChatInviteLink, CreateChatInviteLinkSetters};
+pub use create_chat_subscription_invite_link::{
+    CreateChatSubscriptionInviteLink, CreateChatSubscriptionInviteLinkSetters,
+};
 pub use create_forum_topic::{CreateForumTopic, CreateForumTopicSetters};
 pub use create_invoice_link::{CreateInvoiceLink, CreateInvoiceLinkSetters};
 pub use create_new_sticker_set::{CreateNewStickerSet, CreateNewStickerSetSetters};
 pub use decline_chat_join_request::{DeclineChatJoinRequest, DeclineChatJoinRequestSetters};
+pub use delete_business_messages::{DeleteBusinessMessages, DeleteBusinessMessagesSetters};
 pub use delete_chat_photo::{DeleteChatPhoto, DeleteChatPhotoSetters};
 pub use delete_chat_sticker_set::{DeleteChatStickerSet, DeleteChatStickerSetSetters};
 pub use delete_forum_topic::{DeleteForumTopic, DeleteForumTopicSetters};
@@ -173,8 +208,12 @@ pub use delete_messages::{DeleteMessages, DeleteMessagesSetters};
 pub use delete_my_commands::{DeleteMyCommands, DeleteMyCommandsSetters};
 pub use delete_sticker_from_set::{DeleteStickerFromSet, DeleteStickerFromSetSetters};
 pub use delete_sticker_set::{DeleteStickerSet, DeleteStickerSetSetters};
+pub use delete_story::{DeleteStory, DeleteStorySetters};
 pub use delete_webhook::{DeleteWebhook, DeleteWebhookSetters};
 pub use edit_chat_invite_link::{EditChatInviteLink, EditChatInviteLinkSetters};
+pub use edit_chat_subscription_invite_link::{
+    EditChatSubscriptionInviteLink, EditChatSubscriptionInviteLinkSetters,
+};
 pub use edit_forum_topic::{EditForumTopic, EditForumTopicSetters};
 pub use edit_general_forum_topic::{EditGeneralForumTopic, EditGeneralForumTopicSetters};
 pub use edit_message_caption::{EditMessageCaption, EditMessageCaptionSetters};
@@ -191,9 +230,15 @@ pub use edit_message_reply_markup_inline::{
 };
 pub use edit_message_text::{EditMessageText, EditMessageTextSetters};
 pub use edit_message_text_inline::{EditMessageTextInline, EditMessageTextInlineSetters};
+pub use edit_story::{EditStory, EditStorySetters};
 pub use export_chat_invite_link::{ExportChatInviteLink, ExportChatInviteLinkSetters};
 pub use forward_message::{ForwardMessage, ForwardMessageSetters};
 pub use forward_messages::{ForwardMessages, ForwardMessagesSetters};
+pub use get_available_gifts::{GetAvailableGifts, GetAvailableGiftsSetters};
+pub use get_business_account_gifts::{GetBusinessAccountGifts, GetBusinessAccountGiftsSetters};
+pub use get_business_account_star_balance::{
+    GetBusinessAccountStarBalance, GetBusinessAccountStarBalanceSetters,
+};
 pub use get_business_connection::{GetBusinessConnection, GetBusinessConnectionSetters};
 pub use get_chat::{GetChat, GetChatSetters};
 pub use get_chat_administrators::{GetChatAdministrators, GetChatAdministratorsSetters};
@@ -215,6 +260,7 @@ pub use get_my_default_administrator_rights::{
 pub use get_my_description::{GetMyDescription, GetMyDescriptionSetters};
 pub use get_my_name::{GetMyName, GetMyNameSetters};
 pub use get_my_short_description::{GetMyShortDescription, GetMyShortDescriptionSetters};
+pub use get_star_transactions::{GetStarTransactions, GetStarTransactionsSetters};
 pub use get_sticker_set::{GetStickerSet, GetStickerSetSetters};
 pub use get_updates::{GetUpdates, GetUpdatesSetters};
 pub use get_user_chat_boosts::{GetUserChatBoosts, GetUserChatBoostsSetters};
@@ -225,12 +271,23 @@ pub use kick_chat_member::{KickChatMember, KickChatMemberSetters};
 pub use leave_chat::{LeaveChat, LeaveChatSetters};
 pub use log_out::{LogOut, LogOutSetters};
 pub use pin_chat_message::{PinChatMessage, PinChatMessageSetters};
+pub use post_story::{PostStory, PostStorySetters};
 pub use promote_chat_member::{PromoteChatMember, PromoteChatMemberSetters};
+pub use read_business_message::{ReadBusinessMessage, ReadBusinessMessageSetters};
+pub use refund_star_payment::{RefundStarPayment, RefundStarPaymentSetters};
+pub use remove_business_account_profile_photo::{
+    RemoveBusinessAccountProfilePhoto, RemoveBusinessAccountProfilePhotoSetters,
+};
+pub use remove_chat_verification::{RemoveChatVerification, RemoveChatVerificationSetters};
+pub use remove_user_verification::{RemoveUserVerification, RemoveUserVerificationSetters};
 pub use reopen_forum_topic::{ReopenForumTopic, ReopenForumTopicSetters};
 pub use reopen_general_forum_topic::{ReopenGeneralForumTopic, ReopenGeneralForumTopicSetters};
 pub use replace_sticker_in_set::{ReplaceStickerInSet, ReplaceStickerInSetSetters};
 pub use restrict_chat_member::{RestrictChatMember, RestrictChatMemberSetters};
 pub use revoke_chat_invite_link::{RevokeChatInviteLink, RevokeChatInviteLinkSetters};
+pub use save_prepared_inline_message::{
+    SavePreparedInlineMessage, SavePreparedInlineMessageSetters,
+};
 pub use send_animation::{SendAnimation, SendAnimationSetters};
 pub use send_audio::{SendAudio, SendAudioSetters};
 pub use send_chat_action::{SendChatAction, SendChatActionSetters};
@@ -238,10 +295,12 @@ pub use send_contact::{SendContact, SendContactSetters};
 pub use send_dice::{SendDice, SendDiceSetters};
 pub use send_document::{SendDocument, SendDocumentSetters};
 pub use send_game::{SendGame, SendGameSetters};
+pub use send_gift::{SendGift, SendGiftSetters};
 pub use send_invoice::{SendInvoice, SendInvoiceSetters};
 pub use send_location::{SendLocation, SendLocationSetters};
 pub use send_media_group::{SendMediaGroup, SendMediaGroupSetters};
 pub use send_message::{SendMessage, SendMessageSetters};
+pub use send_paid_media::{SendPaidMedia, SendPaidMediaSetters};
 pub use send_photo::{SendPhoto, SendPhotoSetters};
 pub use send_poll::{SendPoll, SendPollSetters};
 pub use send_sticker::{SendSticker, SendStickerSetters};
@@ -249,6 +308,17 @@ pub use send_venue::{SendVenue, SendVenueSetters};
 pub use send_video::{SendVideo, SendVideoSetters};
 pub use send_video_note::{SendVideoNote, SendVideoNoteSetters};
 pub use send_voice::{SendVoice, SendVoiceSetters};
+pub use set_business_account_bio::{SetBusinessAccountBio, SetBusinessAccountBioSetters};
+pub use set_business_account_gift_settings::{
+    SetBusinessAccountGiftSettings, SetBusinessAccountGiftSettingsSetters,
+};
+pub use set_business_account_name::{SetBusinessAccountName, SetBusinessAccountNameSetters};
+pub use set_business_account_profile_photo::{
+    SetBusinessAccountProfilePhoto, SetBusinessAccountProfilePhotoSetters,
+};
+pub use set_business_account_username::{
+    SetBusinessAccountUsername, SetBusinessAccountUsernameSetters,
+};
 pub use set_chat_administrator_custom_title::{
     SetChatAdministratorCustomTitle, SetChatAdministratorCustomTitleSetters,
 };
@@ -278,12 +348,17 @@ pub use set_sticker_mask_position::{SetStickerMaskPosition, SetStickerMaskPositi
 pub use set_sticker_position_in_set::{SetStickerPositionInSet, SetStickerPositionInSetSetters};
 pub use set_sticker_set_thumbnail::{SetStickerSetThumbnail, SetStickerSetThumbnailSetters};
 pub use set_sticker_set_title::{SetStickerSetTitle, SetStickerSetTitleSetters};
+pub use set_user_emoji_status::{SetUserEmojiStatus, SetUserEmojiStatusSetters};
 pub use set_webhook::{SetWebhook, SetWebhookSetters};
 pub use stop_message_live_location::{StopMessageLiveLocation, StopMessageLiveLocationSetters};
 pub use stop_message_live_location_inline::{
     StopMessageLiveLocationInline, StopMessageLiveLocationInlineSetters,
 };
 pub use stop_poll::{StopPoll, StopPollSetters};
+pub use transfer_business_account_stars::{
+    TransferBusinessAccountStars, TransferBusinessAccountStarsSetters,
+};
+pub use transfer_gift::{TransferGift, TransferGiftSetters};
 pub use unban_chat_member::{UnbanChatMember, UnbanChatMemberSetters};
 pub use unban_chat_sender_chat::{UnbanChatSenderChat, UnbanChatSenderChatSetters};
 pub use unhide_general_forum_topic::{UnhideGeneralForumTopic, UnhideGeneralForumTopicSetters};
@@ -295,7 +370,10 @@ pub use unpin_all_general_forum_topic_messages::{
     UnpinAllGeneralForumTopicMessages, UnpinAllGeneralForumTopicMessagesSetters,
 };
 pub use unpin_chat_message::{UnpinChatMessage, UnpinChatMessageSetters};
+pub use upgrade_gift::{UpgradeGift, UpgradeGiftSetters};
 pub use upload_sticker_file::{UploadStickerFile, UploadStickerFileSetters};
+pub use verify_chat::{VerifyChat, VerifyChatSetters};
+pub use verify_user::{VerifyUser, VerifyUserSetters};
 // END BLOCK payload_modules
 
 /// Generates `mod`s and `pub use`s above.
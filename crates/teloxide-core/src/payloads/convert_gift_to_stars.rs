@@ -0,0 +1,18 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{BusinessConnectionId, True};
+
+impl_payload! {
+    /// Converts a given regular gift to Telegram Stars. Requires the _can\_convert\_gifts\_to\_stars_ business bot right. Returns _True_ on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub ConvertGiftToStars (ConvertGiftToStarsSetters) => True {
+        required {
+            /// Unique identifier of the business connection on behalf of which to convert the gift
+            pub business_connection_id: BusinessConnectionId,
+            /// Unique identifier of the regular gift that should be converted to Telegram Stars
+            pub owned_gift_id: String [into],
+        }
+    }
+}
@@ -15,20 +15,21 @@ impl_payload! {
             pub description: String [into],
             /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to the user, use for your internal processes.
             pub payload: String [into],
-            /// Payments provider token, obtained via [Botfather]
-            ///
-            /// [Botfather]: https://t.me/botfather
-            pub provider_token: String [into],
             /// Three-letter ISO 4217 currency code, see [more on currencies]. Pass `XTR` for payments in [Telegram Stars].
             ///
             /// [more on currencies]: https://core.telegram.org/bots/payments#supported-currencies
             /// [Telegram Stars]: https://t.me/BotNews/90
             pub currency: String [into],
-            /// Price breakdown, a JSON-serialized list of components (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.)
+            /// Price breakdown, a JSON-serialized list of components (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.). Must contain exactly one item for payments in Telegram Stars.
             pub prices: Vec<LabeledPrice> [collect],
         }
         optional {
-            /// The maximum accepted amount for tips in the smallest units of the currency (integer, **not** float/double). For example, for a maximum tip of `US$ 1.45` pass `max_tip_amount = 145`. See the exp parameter in [`currencies.json`], it shows the number of digits past the decimal point for each currency (2 for the majority of currencies). Defaults to 0
+            /// Payments provider token, obtained via [Botfather]. Pass an empty string or omit the parameter for payments in [Telegram Stars].
+            ///
+            /// [Botfather]: https://t.me/botfather
+            /// [Telegram Stars]: https://t.me/BotNews/90
+            pub provider_token: String [into],
+            /// The maximum accepted amount for tips in the smallest units of the currency (integer, **not** float/double). For example, for a maximum tip of `US$ 1.45` pass `max_tip_amount = 145`. See the exp parameter in [`currencies.json`], it shows the number of digits past the decimal point for each currency (2 for the majority of currencies). Defaults to 0. Not supported for payments in Telegram Stars.
             ///
             /// [`currencies.json`]: https://core.telegram.org/bots/payments/currencies.json
             pub max_tip_amount: u32,
@@ -44,19 +45,19 @@ impl_payload! {
             pub photo_width: String [into],
             /// Photo height
             pub photo_height: String [into],
-            /// Pass _True_, if you require the user's full name to complete the order
+            /// Pass _True_, if you require the user's full name to complete the order. Ignored for payments in Telegram Stars.
             pub need_name: bool,
-            /// Pass _True_, if you require the user's phone number to complete the order
+            /// Pass _True_, if you require the user's phone number to complete the order. Ignored for payments in Telegram Stars.
             pub need_phone_number: bool,
-            /// Pass _True_, if you require the user's email address to complete the order
+            /// Pass _True_, if you require the user's email address to complete the order. Ignored for payments in Telegram Stars.
             pub need_email: bool,
-            /// Pass _True_, if you require the user's shipping address to complete the order
+            /// Pass _True_, if you require the user's shipping address to complete the order. Ignored for payments in Telegram Stars.
             pub need_shipping_address: bool,
-            /// Pass _True_, if user's phone number should be sent to provider
+            /// Pass _True_, if user's phone number should be sent to provider. Ignored for payments in Telegram Stars.
             pub send_phone_number_to_provider: bool,
-            /// Pass _True_, if user's email address should be sent to provider
+            /// Pass _True_, if user's email address should be sent to provider. Ignored for payments in Telegram Stars.
             pub send_email_to_provider: bool,
-            /// Pass _True_, if the final price depends on the shipping method
+            /// Pass _True_, if the final price depends on the shipping method. Ignored for payments in Telegram Stars.
             pub is_flexible: bool,
         }
     }
@@ -0,0 +1,18 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{BusinessConnectionId, StoryId, True};
+
+impl_payload! {
+    /// Deletes a story previously posted by the bot on behalf of a managed business account. Requires the _can\_manage\_stories_ business bot right. Returns _True_ on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub DeleteStory (DeleteStorySetters) => True {
+        required {
+            /// Unique identifier of the business connection
+            pub business_connection_id: BusinessConnectionId,
+            /// Unique identifier of the story to delete
+            pub story_id: StoryId,
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use serde::Serialize;
 
-use crate::types::{InlineKeyboardMarkup, MessageEntity, ParseMode, True};
+use crate::types::{BusinessConnectionId, InlineKeyboardMarkup, MessageEntity, ParseMode, True};
 
 impl_payload! {
     /// Use this method to edit captions of messages. On success, _True_ is returned.
@@ -15,6 +15,8 @@ impl_payload! {
             pub inline_message_id: String [into],
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: BusinessConnectionId,
             /// New caption of the message, 0-1024 characters after entities parsing
             pub caption: String [into],
             /// Mode for parsing entities in the message text. See [formatting options] for more details.
@@ -23,6 +25,8 @@ impl_payload! {
             pub parse_mode: ParseMode,
             /// List of special entities that appear in the caption, which can be specified instead of _parse\_mode_
             pub caption_entities: Vec<MessageEntity> [collect],
+            /// Pass _True_, if the caption must be shown above the message media. Supported only for animation, photo and video messages.
+            pub show_caption_above_media: bool,
             /// A JSON-serialized object for an [inline keyboard].
             ///
             /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
@@ -2,7 +2,9 @@
 
 use serde::Serialize;
 
-use crate::types::{InlineKeyboardMarkup, InputMedia, Message, MessageId, Recipient};
+use crate::types::{
+    BusinessConnectionId, InlineKeyboardMarkup, InputMedia, Message, MessageId, Recipient,
+};
 
 impl_payload! {
     /// Use this method to edit animation, audio, document, photo, or video messages. If a message is a part of a message album, then it can be edited only to a photo or a video. Otherwise, message type can be changed arbitrarily. When inline message is edited, new file can't be uploaded. Use previously uploaded file via its file_id or specify a URL. On success, the edited Message is returned.
@@ -20,6 +22,8 @@ impl_payload! {
             pub media: InputMedia,
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: BusinessConnectionId,
             /// A JSON-serialized object for an [inline keyboard].
             ///
             /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
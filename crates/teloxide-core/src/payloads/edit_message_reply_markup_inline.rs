@@ -2,7 +2,7 @@
 
 use serde::Serialize;
 
-use crate::types::{InlineKeyboardMarkup, True};
+use crate::types::{BusinessConnectionId, InlineKeyboardMarkup, True};
 
 impl_payload! {
     /// Use this method to edit only the reply markup of messages. On success, _True_ is returned.
@@ -15,6 +15,8 @@ impl_payload! {
             pub inline_message_id: String [into],
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: BusinessConnectionId,
             /// A JSON-serialized object for an [inline keyboard].
             ///
             /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
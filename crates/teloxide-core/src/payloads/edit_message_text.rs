@@ -3,8 +3,8 @@
 use serde::Serialize;
 
 use crate::types::{
-    InlineKeyboardMarkup, LinkPreviewOptions, Message, MessageEntity, MessageId, ParseMode,
-    Recipient,
+    BusinessConnectionId, InlineKeyboardMarkup, LinkPreviewOptions, Message, MessageEntity,
+    MessageId, ParseMode, Recipient,
 };
 
 impl_payload! {
@@ -25,6 +25,8 @@ impl_payload! {
             pub text: String [into],
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message to be edited was sent
+            pub business_connection_id: BusinessConnectionId,
             /// Mode for parsing entities in the message text. See [formatting options] for more details.
             ///
             /// [formatting options]: https://core.telegram.org/bots/api#formatting-options
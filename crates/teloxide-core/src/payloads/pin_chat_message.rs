@@ -2,7 +2,7 @@
 
 use serde::Serialize;
 
-use crate::types::{MessageId, Recipient, True};
+use crate::types::{BusinessConnectionId, MessageId, Recipient, True};
 
 impl_payload! {
     /// Use this method to pin a message in a group, a supergroup, or a channel. The bot must be an administrator in the chat for this to work and must have the 'can_pin_messages' admin right in the supergroup or 'can_edit_messages' admin right in the channel. Returns _True_ on success.
@@ -16,6 +16,8 @@ impl_payload! {
             pub message_id: MessageId,
         }
         optional {
+            /// Unique identifier of the business connection on behalf of which the message will be pinned
+            pub business_connection_id: BusinessConnectionId,
             /// Pass True, if it is not necessary to send a notification to all chat members about the new pinned message. Notifications are always disabled in channels.
             pub disable_notification: bool,
         }
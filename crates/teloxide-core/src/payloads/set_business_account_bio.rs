@@ -0,0 +1,20 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{BusinessConnectionId, True};
+
+impl_payload! {
+    /// Changes the bio of a managed business account. Requires the _can\_change\_bio_ business bot right. Returns _True_ on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub SetBusinessAccountBio (SetBusinessAccountBioSetters) => True {
+        required {
+            /// Unique identifier of the business connection on behalf of which to change the bio
+            pub business_connection_id: BusinessConnectionId,
+        }
+        optional {
+            /// The new value of the bio for the business account; 0-140 characters
+            pub bio: String [into],
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{AcceptedGiftTypes, BusinessConnectionId, True};
+
+impl_payload! {
+    /// Changes the privacy settings pertaining to incoming gifts in a managed business account. Requires the _can\_change\_gift\_settings_ business bot right. Returns _True_ on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub SetBusinessAccountGiftSettings (SetBusinessAccountGiftSettingsSetters) => True {
+        required {
+            /// Unique identifier of the business connection on behalf of which to change the gift settings
+            pub business_connection_id: BusinessConnectionId,
+            /// Pass _True_, if a button for sending a gift to the user or by the business account must always be shown in the input field
+            pub show_gift_button: bool,
+            /// Types of gifts accepted by the business account
+            pub accepted_gift_types: AcceptedGiftTypes,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{BusinessConnectionId, True};
+
+impl_payload! {
+    /// Changes the first and last name of a managed business account. Requires the _can\_change\_name_ business bot right. Returns _True_ on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub SetBusinessAccountName (SetBusinessAccountNameSetters) => True {
+        required {
+            /// Unique identifier of the business connection on behalf of which to change the name
+            pub business_connection_id: BusinessConnectionId,
+            /// The new value of the first name for the business account; 1-64 characters
+            pub first_name: String [into],
+        }
+        optional {
+            /// The new value of the last name for the business account; 0-64 characters
+            pub last_name: String [into],
+        }
+    }
+}
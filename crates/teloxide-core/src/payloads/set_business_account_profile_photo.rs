@@ -0,0 +1,23 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{BusinessConnectionId, InputProfilePhoto, True};
+
+impl_payload! {
+    @[multipart = photo]
+    /// Changes the profile photo of a managed business account. Requires the _can\_edit\_profile\_photo_ business bot right. Returns _True_ on success.
+    #[derive(Debug, Clone, Serialize)]
+    pub SetBusinessAccountProfilePhoto (SetBusinessAccountProfilePhotoSetters) => True {
+        required {
+            /// Unique identifier of the business connection on behalf of which to change the profile photo
+            pub business_connection_id: BusinessConnectionId,
+            /// The new profile photo to set
+            pub photo: InputProfilePhoto,
+        }
+        optional {
+            /// Pass _True_ to set the public photo, which will be visible even if the main photo is hidden by the business account's privacy settings. An account can have only one public photo.
+            pub is_public: bool,
+        }
+    }
+}
@@ -6,37 +6,48 @@ pub use crate::payloads::{
     AnswerPreCheckoutQuerySetters as _, AnswerShippingQuerySetters as _,
     AnswerWebAppQuerySetters as _, ApproveChatJoinRequestSetters as _, BanChatMemberSetters as _,
     BanChatSenderChatSetters as _, CloseForumTopicSetters as _, CloseGeneralForumTopicSetters as _,
-    CloseSetters as _, CopyMessageSetters as _, CopyMessagesSetters as _,
-    CreateChatInviteLinkSetters as _, CreateForumTopicSetters as _, CreateInvoiceLinkSetters as _,
-    CreateNewStickerSetSetters as _, DeclineChatJoinRequestSetters as _,
+    CloseSetters as _, ConvertGiftToStarsSetters as _, CopyMessageSetters as _,
+    CopyMessagesSetters as _, CreateChatInviteLinkSetters as _,
+    CreateChatSubscriptionInviteLinkSetters as _, CreateForumTopicSetters as _,
+    CreateInvoiceLinkSetters as _, CreateNewStickerSetSetters as _,
+    DeclineChatJoinRequestSetters as _, DeleteBusinessMessagesSetters as _,
     DeleteChatPhotoSetters as _, DeleteChatStickerSetSetters as _, DeleteForumTopicSetters as _,
     DeleteMessageSetters as _, DeleteMessagesSetters as _, DeleteMyCommandsSetters as _,
-    DeleteStickerFromSetSetters as _, DeleteStickerSetSetters as _, DeleteWebhookSetters as _,
-    EditChatInviteLinkSetters as _, EditForumTopicSetters as _, EditGeneralForumTopicSetters as _,
-    EditMessageCaptionInlineSetters as _, EditMessageCaptionSetters as _,
-    EditMessageLiveLocationInlineSetters as _, EditMessageLiveLocationSetters as _,
-    EditMessageMediaInlineSetters as _, EditMessageMediaSetters as _,
-    EditMessageReplyMarkupInlineSetters as _, EditMessageReplyMarkupSetters as _,
-    EditMessageTextInlineSetters as _, EditMessageTextSetters as _,
-    ExportChatInviteLinkSetters as _, ForwardMessageSetters as _, ForwardMessagesSetters as _,
+    DeleteStickerFromSetSetters as _, DeleteStickerSetSetters as _, DeleteStorySetters as _,
+    DeleteWebhookSetters as _, EditChatInviteLinkSetters as _,
+    EditChatSubscriptionInviteLinkSetters as _, EditForumTopicSetters as _,
+    EditGeneralForumTopicSetters as _, EditMessageCaptionInlineSetters as _,
+    EditMessageCaptionSetters as _, EditMessageLiveLocationInlineSetters as _,
+    EditMessageLiveLocationSetters as _, EditMessageMediaInlineSetters as _,
+    EditMessageMediaSetters as _, EditMessageReplyMarkupInlineSetters as _,
+    EditMessageReplyMarkupSetters as _, EditMessageTextInlineSetters as _,
+    EditMessageTextSetters as _, EditStorySetters as _, ExportChatInviteLinkSetters as _,
+    ForwardMessageSetters as _, ForwardMessagesSetters as _, GetAvailableGiftsSetters as _,
+    GetBusinessAccountGiftsSetters as _, GetBusinessAccountStarBalanceSetters as _,
     GetBusinessConnectionSetters as _, GetChatAdministratorsSetters as _,
     GetChatMemberCountSetters as _, GetChatMemberSetters as _, GetChatMembersCountSetters as _,
     GetChatMenuButtonSetters as _, GetChatSetters as _, GetCustomEmojiStickersSetters as _,
     GetFileSetters as _, GetForumTopicIconStickersSetters as _, GetGameHighScoresSetters as _,
     GetMeSetters as _, GetMyCommandsSetters as _, GetMyDefaultAdministratorRightsSetters as _,
     GetMyDescriptionSetters as _, GetMyNameSetters as _, GetMyShortDescriptionSetters as _,
-    GetStickerSetSetters as _, GetUpdatesSetters as _, GetUserChatBoostsSetters as _,
-    GetUserProfilePhotosSetters as _, GetWebhookInfoSetters as _,
+    GetStarTransactionsSetters as _, GetStickerSetSetters as _, GetUpdatesSetters as _,
+    GetUserChatBoostsSetters as _, GetUserProfilePhotosSetters as _, GetWebhookInfoSetters as _,
     HideGeneralForumTopicSetters as _, KickChatMemberSetters as _, LeaveChatSetters as _,
-    LogOutSetters as _, PinChatMessageSetters as _, PromoteChatMemberSetters as _,
-    ReopenForumTopicSetters as _, ReopenGeneralForumTopicSetters as _,
-    ReplaceStickerInSetSetters as _, RestrictChatMemberSetters as _,
-    RevokeChatInviteLinkSetters as _, SendAnimationSetters as _, SendAudioSetters as _,
+    LogOutSetters as _, PinChatMessageSetters as _, PostStorySetters as _,
+    PromoteChatMemberSetters as _, ReadBusinessMessageSetters as _, RefundStarPaymentSetters as _,
+    RemoveBusinessAccountProfilePhotoSetters as _, RemoveChatVerificationSetters as _,
+    RemoveUserVerificationSetters as _, ReopenForumTopicSetters as _,
+    ReopenGeneralForumTopicSetters as _, ReplaceStickerInSetSetters as _,
+    RestrictChatMemberSetters as _, RevokeChatInviteLinkSetters as _,
+    SavePreparedInlineMessageSetters as _, SendAnimationSetters as _, SendAudioSetters as _,
     SendChatActionSetters as _, SendContactSetters as _, SendDiceSetters as _,
-    SendDocumentSetters as _, SendGameSetters as _, SendInvoiceSetters as _,
+    SendDocumentSetters as _, SendGameSetters as _, SendGiftSetters as _, SendInvoiceSetters as _,
     SendLocationSetters as _, SendMediaGroupSetters as _, SendMessageSetters as _,
-    SendPhotoSetters as _, SendPollSetters as _, SendStickerSetters as _, SendVenueSetters as _,
-    SendVideoNoteSetters as _, SendVideoSetters as _, SendVoiceSetters as _,
+    SendPaidMediaSetters as _, SendPhotoSetters as _, SendPollSetters as _,
+    SendStickerSetters as _, SendVenueSetters as _, SendVideoNoteSetters as _,
+    SendVideoSetters as _, SendVoiceSetters as _, SetBusinessAccountBioSetters as _,
+    SetBusinessAccountGiftSettingsSetters as _, SetBusinessAccountNameSetters as _,
+    SetBusinessAccountProfilePhotoSetters as _, SetBusinessAccountUsernameSetters as _,
     SetChatAdministratorCustomTitleSetters as _, SetChatDescriptionSetters as _,
     SetChatMenuButtonSetters as _, SetChatPermissionsSetters as _, SetChatPhotoSetters as _,
     SetChatStickerSetSetters as _, SetChatTitleSetters as _,
@@ -46,10 +57,13 @@ pub use crate::payloads::{
     SetMyNameSetters as _, SetMyShortDescriptionSetters as _, SetPassportDataErrorsSetters as _,
     SetStickerEmojiListSetters as _, SetStickerKeywordsSetters as _,
     SetStickerMaskPositionSetters as _, SetStickerPositionInSetSetters as _,
-    SetStickerSetThumbnailSetters as _, SetStickerSetTitleSetters as _, SetWebhookSetters as _,
+    SetStickerSetThumbnailSetters as _, SetStickerSetTitleSetters as _,
+    SetUserEmojiStatusSetters as _, SetWebhookSetters as _,
     StopMessageLiveLocationInlineSetters as _, StopMessageLiveLocationSetters as _,
-    StopPollSetters as _, UnbanChatMemberSetters as _, UnbanChatSenderChatSetters as _,
+    StopPollSetters as _, TransferBusinessAccountStarsSetters as _, TransferGiftSetters as _,
+    UnbanChatMemberSetters as _, UnbanChatSenderChatSetters as _,
     UnhideGeneralForumTopicSetters as _, UnpinAllChatMessagesSetters as _,
     UnpinAllForumTopicMessagesSetters as _, UnpinAllGeneralForumTopicMessagesSetters as _,
-    UnpinChatMessageSetters as _, UploadStickerFileSetters as _,
+    UnpinChatMessageSetters as _, UpgradeGiftSetters as _, UploadStickerFileSetters as _,
+    VerifyChatSetters as _, VerifyUserSetters as _,
 };
@@ -0,0 +1,18 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{BusinessConnectionId, True};
+
+impl_payload! {
+    /// Transfers Telegram Stars from the business account balance to the bot's balance. Requires the _can\_transfer\_stars_ business bot right. Returns _True_ on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub TransferBusinessAccountStars (TransferBusinessAccountStarsSetters) => True {
+        required {
+            /// Unique identifier of the business connection on behalf of which to transfer the stars
+            pub business_connection_id: BusinessConnectionId,
+            /// Number of Telegram Stars to transfer; 1-10000
+            pub star_count: u32,
+        }
+    }
+}
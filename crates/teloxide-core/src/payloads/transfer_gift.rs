@@ -0,0 +1,24 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{BusinessConnectionId, ChatId, True};
+
+impl_payload! {
+    /// Transfers an owned unique gift to another user. Requires the _can\_transfer\_and\_upgrade\_gifts_ business bot right. Requires _can\_transfer\_stars_ business bot right if the transfer is paid. Returns _True_ on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub TransferGift (TransferGiftSetters) => True {
+        required {
+            /// Unique identifier of the business connection on behalf of which to transfer the gift
+            pub business_connection_id: BusinessConnectionId,
+            /// Unique identifier of the regular gift that should be transferred
+            pub owned_gift_id: String [into],
+            /// Unique identifier of the chat which will own the gift. The chat must be active in the last 24 hours.
+            pub new_owner_chat_id: ChatId [into],
+        }
+        optional {
+            /// The amount of Telegram Stars that will be paid for the transfer from the business account balance. If positive, then the _can\_transfer\_stars_ business bot right is required.
+            pub star_count: u32,
+        }
+    }
+}
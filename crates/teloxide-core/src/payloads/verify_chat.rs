@@ -0,0 +1,22 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{Recipient, True};
+
+impl_payload! {
+    /// Verifies a chat [on behalf of the organization] which is represented by the bot. Returns _True_ on success.
+    ///
+    /// [on behalf of the organization]: https://telegram.org/verify#third-party-verification
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub VerifyChat (VerifyChatSetters) => True {
+        required {
+            /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+            pub chat_id: Recipient [into],
+        }
+        optional {
+            /// Custom description for the verification; 0-70 characters. Must be empty if the organization isn't allowed to provide a custom verification description.
+            pub custom_description: String [into],
+        }
+    }
+}
@@ -293,6 +293,14 @@ pub trait Requester {
     where
         C: Into<Recipient>;
 
+    type SendPaidMedia: Request<Payload = SendPaidMedia, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SendPaidMedia`].
+    fn send_paid_media<C, M>(&self, chat_id: C, star_count: u32, media: M) -> Self::SendPaidMedia
+    where
+        C: Into<Recipient>,
+        M: IntoIterator<Item = InputPaidMedia>;
+
     type SendMediaGroup: Request<Payload = SendMediaGroup, Err = Self::Err>;
 
     /// For Telegram documentation see [`SendMediaGroup`].
@@ -397,7 +405,7 @@ pub trait Requester {
     where
         C: Into<Recipient>,
         Q: Into<String>,
-        O: IntoIterator<Item = String>;
+        O: IntoIterator<Item = InputPollOption>;
 
     type SendDice: Request<Payload = SendDice, Err = Self::Err>;
 
@@ -429,6 +437,35 @@ pub trait Requester {
     /// For Telegram documentation see [`GetUserProfilePhotos`].
     fn get_user_profile_photos(&self, user_id: UserId) -> Self::GetUserProfilePhotos;
 
+    type SetUserEmojiStatus: Request<Payload = SetUserEmojiStatus, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SetUserEmojiStatus`].
+    fn set_user_emoji_status(&self, user_id: UserId) -> Self::SetUserEmojiStatus;
+
+    type VerifyUser: Request<Payload = VerifyUser, Err = Self::Err>;
+
+    /// For Telegram documentation see [`VerifyUser`].
+    fn verify_user(&self, user_id: UserId) -> Self::VerifyUser;
+
+    type VerifyChat: Request<Payload = VerifyChat, Err = Self::Err>;
+
+    /// For Telegram documentation see [`VerifyChat`].
+    fn verify_chat<C>(&self, chat_id: C) -> Self::VerifyChat
+    where
+        C: Into<Recipient>;
+
+    type RemoveUserVerification: Request<Payload = RemoveUserVerification, Err = Self::Err>;
+
+    /// For Telegram documentation see [`RemoveUserVerification`].
+    fn remove_user_verification(&self, user_id: UserId) -> Self::RemoveUserVerification;
+
+    type RemoveChatVerification: Request<Payload = RemoveChatVerification, Err = Self::Err>;
+
+    /// For Telegram documentation see [`RemoveChatVerification`].
+    fn remove_chat_verification<C>(&self, chat_id: C) -> Self::RemoveChatVerification
+    where
+        C: Into<Recipient>;
+
     type GetFile: Request<Payload = GetFile, Err = Self::Err>;
 
     /// For Telegram documentation see [`GetFile`].
@@ -545,6 +582,36 @@ pub trait Requester {
         C: Into<Recipient>,
         I: Into<String>;
 
+    type CreateChatSubscriptionInviteLink: Request<
+        Payload = CreateChatSubscriptionInviteLink,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`CreateChatSubscriptionInviteLink`].
+    fn create_chat_subscription_invite_link<C>(
+        &self,
+        chat_id: C,
+        subscription_period: u32,
+        subscription_price: u32,
+    ) -> Self::CreateChatSubscriptionInviteLink
+    where
+        C: Into<Recipient>;
+
+    type EditChatSubscriptionInviteLink: Request<
+        Payload = EditChatSubscriptionInviteLink,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`EditChatSubscriptionInviteLink`].
+    fn edit_chat_subscription_invite_link<C, I>(
+        &self,
+        chat_id: C,
+        invite_link: I,
+    ) -> Self::EditChatSubscriptionInviteLink
+    where
+        C: Into<Recipient>,
+        I: Into<String>;
+
     type RevokeChatInviteLink: Request<Payload = RevokeChatInviteLink, Err = Self::Err>;
 
     /// For Telegram documentation see [`RevokeChatInviteLink`].
@@ -839,6 +906,187 @@ pub trait Requester {
         business_connection_id: BusinessConnectionId,
     ) -> Self::GetBusinessConnection;
 
+    type ReadBusinessMessage: Request<Payload = ReadBusinessMessage, Err = Self::Err>;
+
+    /// For Telegram documentation see [`ReadBusinessMessage`].
+    fn read_business_message<C>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        chat_id: C,
+        message_id: MessageId,
+    ) -> Self::ReadBusinessMessage
+    where
+        C: Into<ChatId>;
+
+    type DeleteBusinessMessages: Request<Payload = DeleteBusinessMessages, Err = Self::Err>;
+
+    /// For Telegram documentation see [`DeleteBusinessMessages`].
+    fn delete_business_messages<M>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        message_ids: M,
+    ) -> Self::DeleteBusinessMessages
+    where
+        M: IntoIterator<Item = MessageId>;
+
+    type SetBusinessAccountName: Request<Payload = SetBusinessAccountName, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SetBusinessAccountName`].
+    fn set_business_account_name<F>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        first_name: F,
+    ) -> Self::SetBusinessAccountName
+    where
+        F: Into<String>;
+
+    type SetBusinessAccountUsername: Request<Payload = SetBusinessAccountUsername, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SetBusinessAccountUsername`].
+    fn set_business_account_username(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::SetBusinessAccountUsername;
+
+    type SetBusinessAccountBio: Request<Payload = SetBusinessAccountBio, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SetBusinessAccountBio`].
+    fn set_business_account_bio(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::SetBusinessAccountBio;
+
+    type SetBusinessAccountProfilePhoto: Request<
+        Payload = SetBusinessAccountProfilePhoto,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`SetBusinessAccountProfilePhoto`].
+    fn set_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        photo: InputProfilePhoto,
+    ) -> Self::SetBusinessAccountProfilePhoto;
+
+    type RemoveBusinessAccountProfilePhoto: Request<
+        Payload = RemoveBusinessAccountProfilePhoto,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`RemoveBusinessAccountProfilePhoto`].
+    fn remove_business_account_profile_photo(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::RemoveBusinessAccountProfilePhoto;
+
+    type SetBusinessAccountGiftSettings: Request<
+        Payload = SetBusinessAccountGiftSettings,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`SetBusinessAccountGiftSettings`].
+    fn set_business_account_gift_settings(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        show_gift_button: bool,
+        accepted_gift_types: AcceptedGiftTypes,
+    ) -> Self::SetBusinessAccountGiftSettings;
+
+    type GetBusinessAccountStarBalance: Request<
+        Payload = GetBusinessAccountStarBalance,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`GetBusinessAccountStarBalance`].
+    fn get_business_account_star_balance(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::GetBusinessAccountStarBalance;
+
+    type TransferBusinessAccountStars: Request<
+        Payload = TransferBusinessAccountStars,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`TransferBusinessAccountStars`].
+    fn transfer_business_account_stars(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        star_count: u32,
+    ) -> Self::TransferBusinessAccountStars;
+
+    type GetBusinessAccountGifts: Request<Payload = GetBusinessAccountGifts, Err = Self::Err>;
+
+    /// For Telegram documentation see [`GetBusinessAccountGifts`].
+    fn get_business_account_gifts(
+        &self,
+        business_connection_id: BusinessConnectionId,
+    ) -> Self::GetBusinessAccountGifts;
+
+    type ConvertGiftToStars: Request<Payload = ConvertGiftToStars, Err = Self::Err>;
+
+    /// For Telegram documentation see [`ConvertGiftToStars`].
+    fn convert_gift_to_stars<O>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: O,
+    ) -> Self::ConvertGiftToStars
+    where
+        O: Into<String>;
+
+    type UpgradeGift: Request<Payload = UpgradeGift, Err = Self::Err>;
+
+    /// For Telegram documentation see [`UpgradeGift`].
+    fn upgrade_gift<O>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: O,
+    ) -> Self::UpgradeGift
+    where
+        O: Into<String>;
+
+    type TransferGift: Request<Payload = TransferGift, Err = Self::Err>;
+
+    /// For Telegram documentation see [`TransferGift`].
+    fn transfer_gift<O, N>(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        owned_gift_id: O,
+        new_owner_chat_id: N,
+    ) -> Self::TransferGift
+    where
+        O: Into<String>,
+        N: Into<ChatId>;
+
+    type PostStory: Request<Payload = PostStory, Err = Self::Err>;
+
+    /// For Telegram documentation see [`PostStory`].
+    fn post_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        content: InputStoryContent,
+        active_period: u32,
+    ) -> Self::PostStory;
+
+    type EditStory: Request<Payload = EditStory, Err = Self::Err>;
+
+    /// For Telegram documentation see [`EditStory`].
+    fn edit_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+        content: InputStoryContent,
+    ) -> Self::EditStory;
+
+    type DeleteStory: Request<Payload = DeleteStory, Err = Self::Err>;
+
+    /// For Telegram documentation see [`DeleteStory`].
+    fn delete_story(
+        &self,
+        business_connection_id: BusinessConnectionId,
+        story_id: StoryId,
+    ) -> Self::DeleteStory;
+
     type GetMyCommands: Request<Payload = GetMyCommands, Err = Self::Err>;
 
     /// For Telegram documentation see [`GetMyCommands`].
@@ -924,6 +1172,15 @@ pub trait Requester {
     where
         W: Into<String>;
 
+    type SavePreparedInlineMessage: Request<Payload = SavePreparedInlineMessage, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SavePreparedInlineMessage`].
+    fn save_prepared_inline_message(
+        &self,
+        user_id: UserId,
+        result: InlineQueryResult,
+    ) -> Self::SavePreparedInlineMessage;
+
     type EditMessageText: Request<Payload = EditMessageText, Err = Self::Err>;
 
     /// For Telegram documentation see [`EditMessageText`].
@@ -1191,47 +1448,55 @@ pub trait Requester {
     where
         S: Into<String>;
 
+    type GetAvailableGifts: Request<Payload = GetAvailableGifts, Err = Self::Err>;
+
+    /// For Telegram documentation see [`GetAvailableGifts`].
+    fn get_available_gifts(&self) -> Self::GetAvailableGifts;
+
+    type SendGift: Request<Payload = SendGift, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SendGift`].
+    fn send_gift<G>(&self, user_id: UserId, gift_id: G) -> Self::SendGift
+    where
+        G: Into<String>;
+
     type SendInvoice: Request<Payload = SendInvoice, Err = Self::Err>;
 
     /// For Telegram documentation see [`SendInvoice`].
-    fn send_invoice<Ch, T, D, Pa, P, C, Pri>(
+    fn send_invoice<Ch, T, D, Pa, C, P>(
         &self,
         chat_id: Ch,
         title: T,
         description: D,
         payload: Pa,
-        provider_token: P,
         currency: C,
-        prices: Pri,
+        prices: P,
     ) -> Self::SendInvoice
     where
         Ch: Into<Recipient>,
         T: Into<String>,
         D: Into<String>,
         Pa: Into<String>,
-        P: Into<String>,
         C: Into<String>,
-        Pri: IntoIterator<Item = LabeledPrice>;
+        P: IntoIterator<Item = LabeledPrice>;
 
     type CreateInvoiceLink: Request<Payload = CreateInvoiceLink, Err = Self::Err>;
 
     /// For Telegram documentation see [`CreateInvoiceLink`].
-    fn create_invoice_link<T, D, Pa, P, C, Pri>(
+    fn create_invoice_link<T, D, Pa, C, P>(
         &self,
         title: T,
         description: D,
         payload: Pa,
-        provider_token: P,
         currency: C,
-        prices: Pri,
+        prices: P,
     ) -> Self::CreateInvoiceLink
     where
         T: Into<String>,
         D: Into<String>,
         Pa: Into<String>,
-        P: Into<String>,
         C: Into<String>,
-        Pri: IntoIterator<Item = LabeledPrice>;
+        P: IntoIterator<Item = LabeledPrice>;
 
     type AnswerShippingQuery: Request<Payload = AnswerShippingQuery, Err = Self::Err>;
 
@@ -1251,6 +1516,22 @@ pub trait Requester {
     where
         P: Into<String>;
 
+    type GetStarTransactions: Request<Payload = GetStarTransactions, Err = Self::Err>;
+
+    /// For Telegram documentation see [`GetStarTransactions`].
+    fn get_star_transactions(&self) -> Self::GetStarTransactions;
+
+    type RefundStarPayment: Request<Payload = RefundStarPayment, Err = Self::Err>;
+
+    /// For Telegram documentation see [`RefundStarPayment`].
+    fn refund_star_payment<T>(
+        &self,
+        user_id: UserId,
+        telegram_payment_charge_id: T,
+    ) -> Self::RefundStarPayment
+    where
+        T: Into<String>;
+
     type SetPassportDataErrors: Request<Payload = SetPassportDataErrors, Err = Self::Err>;
 
     /// For Telegram documentation see [`SetPassportDataErrors`].
@@ -1336,6 +1617,7 @@ macro_rules! forward_all {
             send_animation,
             send_voice,
             send_video_note,
+            send_paid_media,
             send_media_group,
             send_location,
             edit_message_live_location,
@@ -1349,6 +1631,11 @@ macro_rules! forward_all {
             send_chat_action,
             set_message_reaction,
             get_user_profile_photos,
+            set_user_emoji_status,
+            verify_user,
+            verify_chat,
+            remove_user_verification,
+            remove_chat_verification,
             get_file,
             kick_chat_member,
             ban_chat_member,
@@ -1362,6 +1649,8 @@ macro_rules! forward_all {
             export_chat_invite_link,
             create_chat_invite_link,
             edit_chat_invite_link,
+            create_chat_subscription_invite_link,
+            edit_chat_subscription_invite_link,
             revoke_chat_invite_link,
             set_chat_photo,
             delete_chat_photo,
@@ -1395,6 +1684,23 @@ macro_rules! forward_all {
             get_user_chat_boosts,
             set_my_commands,
             get_business_connection,
+            read_business_message,
+            delete_business_messages,
+            set_business_account_name,
+            set_business_account_username,
+            set_business_account_bio,
+            set_business_account_profile_photo,
+            remove_business_account_profile_photo,
+            set_business_account_gift_settings,
+            get_business_account_star_balance,
+            transfer_business_account_stars,
+            get_business_account_gifts,
+            convert_gift_to_stars,
+            upgrade_gift,
+            transfer_gift,
+            post_story,
+            edit_story,
+            delete_story,
             get_my_commands,
             set_my_name,
             get_my_name,
@@ -1409,6 +1715,7 @@ macro_rules! forward_all {
             delete_my_commands,
             answer_inline_query,
             answer_web_app_query,
+            save_prepared_inline_message,
             edit_message_text,
             edit_message_text_inline,
             edit_message_caption,
@@ -1436,10 +1743,14 @@ macro_rules! forward_all {
             set_sticker_emoji_list,
             set_sticker_keywords,
             set_sticker_mask_position,
+            get_available_gifts,
+            send_gift,
             send_invoice,
             create_invoice_link,
             answer_shipping_query,
             answer_pre_checkout_query,
+            get_star_transactions,
+            refund_star_payment,
             set_passport_data_errors,
             send_game,
             set_game_score,
@@ -68,7 +68,7 @@ pub trait RequesterExt: Requester {
     ///  - [`send_document`]
     ///  - [`send_animation`]
     ///  - [`send_voice`]
-    ///  - [`send_poll`]
+    ///  - [`send_poll`] (both the question and the explanation)
     ///  - [`edit_message_text`] (and [`edit_message_text_inline`])
     ///  - [`edit_message_caption`] (and [`edit_message_caption_inline`])
     ///
@@ -18,34 +18,51 @@ use std::future::Future;
 use reqwest::multipart::Form;
 use serde::Serialize;
 
-use crate::requests::MultipartPayload;
+use crate::{
+    net::{LOCAL_UPLOAD_SIZE_LIMIT, UPLOAD_SIZE_LIMIT},
+    requests::MultipartPayload,
+    types::with_local_mode,
+};
 use error::Error;
 use serializers::MultipartSerializer;
 
 /// Serializes given value into [`Form`] **taking all input files out**.
 ///
+/// If `local` is `true`, files on the disk are referenced by their paths
+/// instead of being uploaded, as supported by Bot API servers in the local
+/// mode.
+///
+/// The returned future fails with an I/O error if any file is larger than
+/// [`upload_size_limit`].
+///
 /// [`Form`]:  reqwest::multipart::Form
-pub(crate) fn to_form<T>(val: &mut T) -> Result<impl Future<Output = Form>, Error>
+pub(crate) fn to_form<T>(
+    val: &mut T,
+    local: bool,
+) -> Result<impl Future<Output = Result<Form, Error>>, Error>
 where
     T: Serialize + MultipartPayload,
 {
-    let mut form = val.serialize(MultipartSerializer::new())?;
+    let mut form = with_local_mode(local, || val.serialize(MultipartSerializer::new()))?;
 
     let mut vec = Vec::with_capacity(1);
     val.move_files(&mut |f| vec.push(f));
     let iter = vec.into_iter();
+    let size_limit = upload_size_limit(local);
 
     let fut = async move {
         for file in iter {
-            if file.needs_attach() {
+            if file.needs_attach(local) {
                 let id = file.id().to_owned();
-                if let Some(part) = file.into_part() {
-                    form = form.part(id, part.await);
+                if let Some(part) = file.into_part(size_limit) {
+                    form = form.part(id, part.await?);
                 }
+            } else {
+                file.check_local_size(size_limit).await?;
             }
         }
 
-        form
+        Ok(form)
     };
 
     Ok(fut)
@@ -53,44 +70,60 @@ where
 
 /// Serializes given value into [`Form`].
 ///
+/// See [`to_form`] for the meaning of `local`.
+///
 /// [`Form`]:  reqwest::multipart::Form
-pub(crate) fn to_form_ref<T: ?Sized>(val: &T) -> Result<impl Future<Output = Form>, Error>
+pub(crate) fn to_form_ref<T: ?Sized>(
+    val: &T,
+    local: bool,
+) -> Result<impl Future<Output = Result<Form, Error>>, Error>
 where
     T: Serialize + MultipartPayload,
 {
-    let mut form = val.serialize(MultipartSerializer::new())?;
+    let mut form = with_local_mode(local, || val.serialize(MultipartSerializer::new()))?;
     let mut vec = Vec::with_capacity(1);
     val.copy_files(&mut |f| vec.push(f));
 
     let iter = vec.into_iter();
+    let size_limit = upload_size_limit(local);
 
     let fut = async move {
         for file in iter {
-            if file.needs_attach() {
+            if file.needs_attach(local) {
                 let id = file.id().to_owned();
-                if let Some(part) = file.into_part() {
-                    form = form.part(id, part.await);
+                if let Some(part) = file.into_part(size_limit) {
+                    form = form.part(id, part.await?);
                 }
+            } else {
+                file.check_local_size(size_limit).await?;
             }
         }
 
-        form
+        Ok(form)
     };
 
     Ok(fut)
 }
 
+pub(crate) fn upload_size_limit(local: bool) -> u64 {
+    match local {
+        true => LOCAL_UPLOAD_SIZE_LIMIT,
+        false => UPLOAD_SIZE_LIMIT,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tokio::fs::File;
 
-    use super::to_form_ref;
+    use super::{to_form_ref, with_local_mode};
     use crate::{
         payloads::{self, setters::*},
         types::{
-            ChatId, InputFile, InputMedia, InputMediaAnimation, InputMediaAudio,
-            InputMediaDocument, InputMediaPhoto, InputMediaVideo, InputSticker, MessageEntity,
-            MessageEntityKind, ParseMode, StickerFormat, UserId,
+            BusinessConnectionId, ChatId, InputFile, InputMedia, InputMediaAnimation,
+            InputMediaAudio, InputMediaDocument, InputMediaPhoto, InputMediaVideo, InputSticker,
+            InputStoryContentPhoto, MessageEntity, MessageEntityKind, ParseMode, ReactionType,
+            StickerFormat, StoryArea, StoryAreaPosition, StoryAreaTypeSuggestedReaction, UserId,
         },
     };
 
@@ -101,67 +134,76 @@ mod tests {
             &payloads::SendPhoto::new(ChatId(0), InputFile::file_id("0")).caption_entities([
                 MessageEntity { kind: MessageEntityKind::Url, offset: 0, length: 0 },
             ]),
+            false,
         )
         .unwrap()
-        .await;
+        .await
+        .unwrap();
     }
 
     #[tokio::test]
     async fn test_send_media_group() {
         const CAPTION: &str = "caption";
 
-        to_form_ref(&payloads::SendMediaGroup::new(
-            ChatId(0),
-            [
-                InputMedia::Photo(
-                    InputMediaPhoto::new(InputFile::file("../../media/teloxide-core-logo.png"))
-                        .caption(CAPTION)
-                        .parse_mode(ParseMode::MarkdownV2)
-                        .caption_entities(entities()),
-                ),
-                InputMedia::Video(
-                    InputMediaVideo::new(InputFile::file_id("17")).supports_streaming(true),
-                ),
-                InputMedia::Animation(
-                    InputMediaAnimation::new(InputFile::read(
-                        File::open("../../media/example.gif").await.unwrap(),
-                    ))
-                    .thumbnail(InputFile::read(
-                        File::open("../../media/teloxide-core-logo.png").await.unwrap(),
-                    ))
-                    .duration(17),
-                ),
-                InputMedia::Audio(
-                    InputMediaAudio::new(InputFile::url("https://example.com".parse().unwrap()))
+        to_form_ref(
+            &payloads::SendMediaGroup::new(
+                ChatId(0),
+                [
+                    InputMedia::Photo(
+                        InputMediaPhoto::new(InputFile::file("../../media/teloxide-core-logo.png"))
+                            .caption(CAPTION)
+                            .parse_mode(ParseMode::MarkdownV2)
+                            .caption_entities(entities()),
+                    ),
+                    InputMedia::Video(
+                        InputMediaVideo::new(InputFile::file_id("17")).supports_streaming(true),
+                    ),
+                    InputMedia::Animation(
+                        InputMediaAnimation::new(InputFile::read(
+                            File::open("../../media/example.gif").await.unwrap(),
+                        ))
+                        .thumbnail(InputFile::read(
+                            File::open("../../media/teloxide-core-logo.png").await.unwrap(),
+                        ))
+                        .duration(17),
+                    ),
+                    InputMedia::Audio(
+                        InputMediaAudio::new(InputFile::url(
+                            "https://example.com".parse().unwrap(),
+                        ))
                         .performer("a"),
-                ),
-                InputMedia::Document(InputMediaDocument::new(InputFile::memory(
-                    &b"Hello world!"[..],
-                ))),
-            ],
-        ))
+                    ),
+                    InputMedia::Document(InputMediaDocument::new(InputFile::memory(
+                        &b"Hello world!"[..],
+                    ))),
+                ],
+            ),
+            false,
+        )
         .unwrap()
-        .await;
+        .await
+        .unwrap();
     }
 
     #[tokio::test]
     async fn test_add_sticker_to_set() {
-        to_form_ref(&payloads::AddStickerToSet::new(
-            UserId(0),
-            "name",
-            InputSticker {
-                sticker: InputFile::file(
-                    "../../media/
-                teloxide-core-logo.png",
-                ),
-                emoji_list: vec!["✈️⚙️".to_owned()],
-                keywords: vec![],
-                mask_position: None,
-                format: StickerFormat::Static,
-            },
-        ))
+        to_form_ref(
+            &payloads::AddStickerToSet::new(
+                UserId(0),
+                "name",
+                InputSticker {
+                    sticker: InputFile::file("../../media/teloxide-core-logo.png"),
+                    emoji_list: vec!["✈️⚙️".to_owned()],
+                    keywords: vec![],
+                    mask_position: None,
+                    format: StickerFormat::Static,
+                },
+            ),
+            false,
+        )
         .unwrap()
-        .await;
+        .await
+        .unwrap();
     }
 
     #[tokio::test]
@@ -175,9 +217,60 @@ mod tests {
             .thumbnail(InputFile::read(
                 File::open("../../media/teloxide-core-logo.png").await.unwrap(),
             )),
+            false,
         )
         .unwrap()
-        .await;
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_post_story() {
+        to_form_ref(
+            &payloads::PostStory::new(
+                BusinessConnectionId("id".to_owned()),
+                InputStoryContentPhoto::new(InputFile::file("../../media/teloxide-core-logo.png"))
+                    .into(),
+                86400,
+            )
+            .caption_entities(entities())
+            .areas([StoryArea::new(
+                StoryAreaPosition {
+                    x_percentage: 50.0,
+                    y_percentage: 50.0,
+                    width_percentage: 10.0,
+                    height_percentage: 10.0,
+                    rotation_angle: 0.0,
+                    corner_radius_percentage: 0.0,
+                },
+                StoryAreaTypeSuggestedReaction {
+                    reaction_type: ReactionType::Emoji { emoji: "👍".to_owned() },
+                    is_dark: false,
+                    is_flipped: true,
+                },
+            )]),
+            false,
+        )
+        .unwrap()
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn test_local_mode() {
+        let file = InputFile::file("/tmp/teloxide/photo.png");
+
+        assert!(file.needs_attach(false));
+        assert!(!file.needs_attach(true));
+        assert_eq!(
+            with_local_mode(true, || serde_json::to_string(&file)).unwrap(),
+            r#""file:///tmp/teloxide/photo.png""#
+        );
+
+        // The mode is restored even if serialization panics
+        let res = std::panic::catch_unwind(|| with_local_mode(true, || panic!("oops")));
+        assert!(res.is_err());
+        assert!(serde_json::to_string(&file).unwrap().starts_with(r#""attach://"#));
     }
 
     fn entities() -> impl Iterator<Item = MessageEntity> {
@@ -0,0 +1,18 @@
+//! Utilities for testing bots without making requests to Telegram.
+//!
+//! The main type here is [`MockBot`], a [`Requester`] which never touches the
+//! network. Instead it records every request made through it and answers with
+//! responses scripted by the test. Synthetic updates to test handlers with can
+//! be built with [`UpdateBuilder`].
+//!
+//! [`Requester`]: crate::requests::Requester
+
+pub use self::{
+    mock_bot::{MockBot, MockRequest, MockSend, RecordedRequest},
+    update_builder::{
+        channel_chat, group_chat, private_chat, supergroup_chat, text_message, user, UpdateBuilder,
+    },
+};
+
+mod mock_bot;
+mod update_builder;
@@ -0,0 +1,534 @@
+use std::{
+    any::{Any, TypeId},
+    collections::{HashMap, VecDeque},
+    fmt,
+    future::IntoFuture,
+    sync::{Arc, Mutex},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+use url::Url;
+
+use crate::{
+    errors::{ApiError, RequestError},
+    payloads::GetMe,
+    requests::{HasPayload, Payload, Request, Requester, ResponseResult},
+    types::*,
+    Bot,
+};
+
+/// A [`Requester`] which records requests instead of sending them.
+///
+/// Every request sent through a `MockBot` is recorded and can later be
+/// inspected with [`requests`] or [`requests_of`]. Responses are scripted per
+/// method with [`respond`] and [`respond_once`].
+///
+/// If no response was scripted for a method, methods returning [`True`]
+/// succeed, while all other methods fail with [`ApiError::Unknown`].
+/// [`GetMe`] is answered with the [`Me`] passed to [`with_me`] (or a
+/// placeholder bot user, if `MockBot` was created with [`new`]), so `MockBot`
+/// can be passed to a dispatcher as is.
+///
+/// All clones of a `MockBot` share recorded requests and scripted responses.
+///
+/// ## Examples
+///
+/// ```
+/// # async {
+/// use teloxide_core::{
+///     payloads::{SendMessage, SendMessageSetters},
+///     prelude::*,
+///     testing::MockBot,
+///     types::ChatId,
+/// };
+///
+/// let bot = MockBot::new();
+/// bot.respond_once::<SendMessage>(Err(teloxide_core::ApiError::BotBlocked.into()));
+///
+/// let res = bot.send_message(ChatId(1), "hi").disable_notification(true).await;
+/// assert!(res.is_err());
+///
+/// let sent = bot.requests_of::<SendMessage>();
+/// assert_eq!(sent.len(), 1);
+/// assert_eq!(sent[0].text, "hi");
+/// assert_eq!(sent[0].disable_notification, Some(true));
+/// # };
+/// ```
+///
+/// [`requests`]: MockBot::requests
+/// [`requests_of`]: MockBot::requests_of
+/// [`respond`]: MockBot::respond
+/// [`respond_once`]: MockBot::respond_once
+/// [`with_me`]: MockBot::with_me
+/// [`new`]: MockBot::new
+#[derive(Clone)]
+pub struct MockBot {
+    /// Used only to construct payloads, never sends anything.
+    factory: Bot,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    requests: Vec<RecordedRequest>,
+
+    /// `TypeId` of a payload => `Arc<Mutex<Responder<P>>>`.
+    responders: HashMap<TypeId, Box<dyn Any + Send>>,
+
+    /// `TypeId` of a payload => queue of `ResponseResult<P::Output>`.
+    queued: HashMap<TypeId, VecDeque<Box<dyn Any + Send>>>,
+}
+
+type Responder<P> = Box<dyn FnMut(&P) -> ResponseResult<<P as Payload>::Output> + Send>;
+
+impl MockBot {
+    /// Creates a new `MockBot` with a placeholder bot user.
+    pub fn new() -> Self {
+        Self::with_me(Me {
+            user: User {
+                id: UserId(1),
+                is_bot: true,
+                first_name: "Mock".to_owned(),
+                last_name: None,
+                username: Some("mock_bot".to_owned()),
+                language_code: None,
+                is_premium: false,
+                added_to_attachment_menu: false,
+            },
+            can_join_groups: true,
+            can_read_all_group_messages: false,
+            supports_inline_queries: false,
+            can_connect_to_business: false,
+        })
+    }
+
+    /// Creates a new `MockBot` which answers [`GetMe`] requests with `me`.
+    pub fn with_me(me: Me) -> Self {
+        let this =
+            Self { factory: Bot::new("0:MOCK"), state: Arc::new(Mutex::new(State::default())) };
+        this.respond::<GetMe, _>(move |_| Ok(me.clone()));
+        this
+    }
+
+    /// Sets a function which answers all requests with payload `P`.
+    ///
+    /// This replaces a previously set function for `P`, if any. Responses
+    /// queued with [`respond_once`] take priority over this function.
+    ///
+    /// Concurrent requests with payload `P` call `f` one at a time, so `f`
+    /// must not send requests with payload `P` through this bot itself.
+    ///
+    /// [`respond_once`]: MockBot::respond_once
+    pub fn respond<P, F>(&self, f: F)
+    where
+        P: Payload + 'static,
+        P::Output: 'static,
+        F: FnMut(&P) -> ResponseResult<P::Output> + Send + 'static,
+    {
+        let responder: Responder<P> = Box::new(f);
+        self.state()
+            .responders
+            .insert(TypeId::of::<P>(), Box::new(Arc::new(Mutex::new(responder))));
+    }
+
+    /// Queues a response for the next request with payload `P`.
+    ///
+    /// Queued responses are used in the order they were added, one per
+    /// request.
+    pub fn respond_once<P>(&self, response: ResponseResult<P::Output>)
+    where
+        P: Payload + 'static,
+        P::Output: Send + 'static,
+    {
+        self.state().queued.entry(TypeId::of::<P>()).or_default().push_back(Box::new(response));
+    }
+
+    /// Returns all requests sent through this bot (and its clones), in the
+    /// order they were sent.
+    #[must_use]
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state().requests.clone()
+    }
+
+    /// Returns payloads of all sent requests of type `P`, in the order they
+    /// were sent.
+    #[must_use]
+    pub fn requests_of<P>(&self) -> Vec<P>
+    where
+        P: Payload + Clone + 'static,
+    {
+        self.state()
+            .requests
+            .iter()
+            .filter_map(RecordedRequest::downcast_ref::<P>)
+            .cloned()
+            .collect()
+    }
+
+    /// Forgets all recorded requests.
+    pub fn clear_requests(&self) {
+        self.state().requests.clear();
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        // A panic in a test while holding the lock shouldn't hide recorded requests
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn execute<P>(&self, payload: P) -> ResponseResult<P::Output>
+    where
+        P: Payload + Serialize + Send + Sync + 'static,
+        P::Output: DeserializeOwned + 'static,
+    {
+        let id = TypeId::of::<P>();
+        let json = serde_json::to_value(&payload)
+            // this `expect` should be ok since we don't write request those may trigger error here
+            .expect("serialization of request to be infallible");
+        let payload = Arc::new(payload);
+
+        let mut state = self.state();
+        state.requests.push(RecordedRequest {
+            method: P::NAME,
+            json,
+            payload: Arc::clone(&payload) as _,
+        });
+
+        if let Some(response) = state.queued.get_mut(&id).and_then(VecDeque::pop_front) {
+            return *response.downcast().expect("queued response has a wrong type");
+        }
+
+        if let Some(responder) = state.responders.get(&id) {
+            let responder = Arc::clone(
+                responder
+                    .downcast_ref::<Arc<Mutex<Responder<P>>>>()
+                    .expect("responder has a wrong type"),
+            );
+
+            // Don't hold the lock while calling user code, the responder may want to
+            // use this bot
+            drop(state);
+
+            let mut responder = responder.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            return responder(&payload);
+        }
+
+        // Methods which return `True` are successful by default
+        serde_json::from_value(serde_json::Value::Bool(true)).map_err(|_| {
+            RequestError::Api(ApiError::Unknown(format!(
+                "no response was set up for `{}` in `MockBot`",
+                P::NAME
+            )))
+        })
+    }
+}
+
+impl Default for MockBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for MockBot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockBot").field("requests", &self.state().requests).finish_non_exhaustive()
+    }
+}
+
+/// A request recorded by [`MockBot`].
+#[derive(Clone)]
+pub struct RecordedRequest {
+    method: &'static str,
+    json: serde_json::Value,
+    payload: Arc<dyn Any + Send + Sync>,
+}
+
+impl RecordedRequest {
+    /// Returns the name of the Telegram method, e.g. `SendMessage`.
+    #[must_use]
+    pub fn method(&self) -> &'static str {
+        self.method
+    }
+
+    /// Returns the payload serialized as JSON, the same way it would be sent
+    /// to Telegram.
+    ///
+    /// Files are represented by `attach://` strings, see [`InputFile`].
+    #[must_use]
+    pub fn json(&self) -> &serde_json::Value {
+        &self.json
+    }
+
+    /// Returns the payload, if it has type `P`.
+    #[must_use]
+    pub fn downcast_ref<P>(&self) -> Option<&P>
+    where
+        P: Payload + 'static,
+    {
+        self.payload.downcast_ref()
+    }
+
+    /// Returns `true` if this request has payload `P`.
+    #[must_use]
+    pub fn is<P>(&self) -> bool
+    where
+        P: Payload + 'static,
+    {
+        self.payload.is::<P>()
+    }
+}
+
+impl fmt::Debug for RecordedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordedRequest")
+            .field("method", &self.method)
+            .field("json", &self.json)
+            .finish()
+    }
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        MockRequest<crate::payloads::$T>
+    };
+}
+
+macro_rules! fwd_mock {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        MockRequest {
+            bot: $this.clone(),
+            payload: $this.factory.$m($($arg),*).payload_ref().clone(),
+        }
+    };
+}
+
+impl Requester for MockBot {
+    type Err = RequestError;
+
+    requester_forward! {
+        get_me,
+        log_out,
+        close,
+        get_updates,
+        set_webhook,
+        delete_webhook,
+        get_webhook_info,
+        forward_message,
+        forward_messages,
+        copy_message,
+        copy_messages,
+        send_message,
+        send_photo,
+        send_audio,
+        send_document,
+        send_video,
+        send_animation,
+        send_voice,
+        send_video_note,
+        send_paid_media,
+        send_media_group,
+        send_location,
+        edit_message_live_location,
+        edit_message_live_location_inline,
+        stop_message_live_location,
+        stop_message_live_location_inline,
+        send_venue,
+        send_contact,
+        send_poll,
+        send_dice,
+        send_chat_action,
+        set_message_reaction,
+        get_user_profile_photos,
+        set_user_emoji_status,
+        verify_user,
+        verify_chat,
+        remove_user_verification,
+        remove_chat_verification,
+        get_file,
+        kick_chat_member,
+        ban_chat_member,
+        unban_chat_member,
+        restrict_chat_member,
+        promote_chat_member,
+        set_chat_administrator_custom_title,
+        ban_chat_sender_chat,
+        unban_chat_sender_chat,
+        set_chat_permissions,
+        export_chat_invite_link,
+        create_chat_invite_link,
+        edit_chat_invite_link,
+        create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link,
+        revoke_chat_invite_link,
+        set_chat_photo,
+        delete_chat_photo,
+        set_chat_title,
+        set_chat_description,
+        pin_chat_message,
+        unpin_chat_message,
+        unpin_all_chat_messages,
+        leave_chat,
+        get_chat,
+        get_chat_administrators,
+        get_chat_members_count,
+        get_chat_member_count,
+        get_chat_member,
+        set_chat_sticker_set,
+        delete_chat_sticker_set,
+        get_forum_topic_icon_stickers,
+        create_forum_topic,
+        edit_forum_topic,
+        close_forum_topic,
+        reopen_forum_topic,
+        delete_forum_topic,
+        unpin_all_forum_topic_messages,
+        edit_general_forum_topic,
+        close_general_forum_topic,
+        reopen_general_forum_topic,
+        hide_general_forum_topic,
+        unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        answer_callback_query,
+        get_user_chat_boosts,
+        set_my_commands,
+        get_business_connection,
+        read_business_message,
+        delete_business_messages,
+        set_business_account_name,
+        set_business_account_username,
+        set_business_account_bio,
+        set_business_account_profile_photo,
+        remove_business_account_profile_photo,
+        set_business_account_gift_settings,
+        get_business_account_star_balance,
+        transfer_business_account_stars,
+        get_business_account_gifts,
+        convert_gift_to_stars,
+        upgrade_gift,
+        transfer_gift,
+        post_story,
+        edit_story,
+        delete_story,
+        get_my_commands,
+        set_my_name,
+        get_my_name,
+        set_my_description,
+        get_my_description,
+        set_my_short_description,
+        get_my_short_description,
+        set_chat_menu_button,
+        get_chat_menu_button,
+        set_my_default_administrator_rights,
+        get_my_default_administrator_rights,
+        delete_my_commands,
+        answer_inline_query,
+        answer_web_app_query,
+        save_prepared_inline_message,
+        edit_message_text,
+        edit_message_text_inline,
+        edit_message_caption,
+        edit_message_caption_inline,
+        edit_message_media,
+        edit_message_media_inline,
+        edit_message_reply_markup,
+        edit_message_reply_markup_inline,
+        stop_poll,
+        delete_message,
+        delete_messages,
+        send_sticker,
+        get_sticker_set,
+        get_custom_emoji_stickers,
+        upload_sticker_file,
+        create_new_sticker_set,
+        add_sticker_to_set,
+        set_sticker_position_in_set,
+        delete_sticker_from_set,
+        replace_sticker_in_set,
+        set_sticker_set_thumbnail,
+        set_custom_emoji_sticker_set_thumbnail,
+        set_sticker_set_title,
+        delete_sticker_set,
+        set_sticker_emoji_list,
+        set_sticker_keywords,
+        set_sticker_mask_position,
+        get_available_gifts,
+        send_gift,
+        send_invoice,
+        create_invoice_link,
+        answer_shipping_query,
+        answer_pre_checkout_query,
+        get_star_transactions,
+        refund_star_payment,
+        set_passport_data_errors,
+        send_game,
+        set_game_score,
+        set_game_score_inline,
+        get_game_high_scores,
+        approve_chat_join_request,
+        decline_chat_join_request
+        => fwd_mock, fty
+    }
+}
+
+/// A request made through [`MockBot`].
+#[must_use = "Requests are lazy and do nothing unless sent"]
+#[derive(Clone)]
+pub struct MockRequest<P> {
+    bot: MockBot,
+    payload: P,
+}
+
+impl<P> HasPayload for MockRequest<P>
+where
+    P: Payload,
+{
+    type Payload = P;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        &mut self.payload
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        &self.payload
+    }
+}
+
+impl<P> Request for MockRequest<P>
+where
+    P: Payload + Serialize + Clone + Send + Sync + 'static,
+    P::Output: DeserializeOwned + Send + 'static,
+{
+    type Err = RequestError;
+    type Send = MockSend<P>;
+    type SendRef = MockSend<P>;
+
+    fn send(self) -> Self::Send {
+        MockSend::new(self)
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        MockSend::new(self.clone())
+    }
+}
+
+impl<P> IntoFuture for MockRequest<P>
+where
+    P: Payload + Serialize + Clone + Send + Sync + 'static,
+    P::Output: DeserializeOwned + Send + 'static,
+{
+    type Output = ResponseResult<P::Output>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+req_future! {
+    def: |it: MockRequest<U>| {
+        async move { it.bot.execute(it.payload) }
+    }
+    pub MockSend<U> (inner0) -> ResponseResult<U::Output>
+    where
+        U: Payload + Serialize + Send + Sync + 'static,
+        U::Output: DeserializeOwned + Send + 'static,
+}
@@ -0,0 +1,749 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use chrono::{DateTime, Utc};
+
+use crate::types::{
+    BusinessBotRights, BusinessConnection, BusinessConnectionId, BusinessMessagesDeleted,
+    CallbackQuery, Chat, ChatBoost, ChatBoostRemoved, ChatBoostSource, ChatBoostSourcePremium,
+    ChatBoostUpdated, ChatFullInfo, ChatId, ChatJoinRequest, ChatKind, ChatMember, ChatMemberKind,
+    ChatMemberUpdated, ChatPrivate, ChatPublic, ChosenInlineResult, CountryCode, InlineQuery,
+    MaybeAnonymousUser, MaybeInaccessibleMessage, MediaKind, MediaText, Message, MessageCommon,
+    MessageId, MessageKind, MessageReactionCountUpdated, MessageReactionUpdated, OrderInfo,
+    PaidMediaPurchased, Poll, PollAnswer, PollOption, PollType, PreCheckoutQuery,
+    PublicChatChannel, PublicChatGroup, PublicChatKind, PublicChatSupergroup, ReactionCount,
+    ReactionType, ShippingAddress, ShippingQuery, Update, UpdateId, UpdateKind, User, UserId,
+};
+
+/// Id of the next update built by [`UpdateBuilder`], so that all updates are
+/// distinct by default.
+static NEXT_UPDATE_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Date used for everything created by this module.
+fn date() -> DateTime<Utc> {
+    DateTime::from_timestamp(1_569_518_829, 0).unwrap()
+}
+
+/// A builder of synthetic [`Update`]s.
+///
+/// Each constructor creates an update of the corresponding [`UpdateKind`] with
+/// sane defaults for all the fields which are not passed explicitly. Users,
+/// chats and messages for the constructors can be made with [`user`],
+/// [`private_chat`], [`group_chat`], [`supergroup_chat`], [`channel_chat`] and
+/// [`text_message`].
+///
+/// Unless [`id`] is called, every built update gets a new unique id.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     testing::{private_chat, user, UpdateBuilder},
+///     types::UpdateKind,
+/// };
+///
+/// let user = user(42, "Alice");
+/// let update = UpdateBuilder::text_message(private_chat(&user), user, "/start").build();
+///
+/// let UpdateKind::Message(message) = update.kind else { unreachable!() };
+/// assert_eq!(message.text(), Some("/start"));
+/// ```
+///
+/// [`user`]: crate::testing::user
+/// [`private_chat`]: crate::testing::private_chat
+/// [`group_chat`]: crate::testing::group_chat
+/// [`supergroup_chat`]: crate::testing::supergroup_chat
+/// [`channel_chat`]: crate::testing::channel_chat
+/// [`text_message`]: crate::testing::text_message
+/// [`id`]: UpdateBuilder::id
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct UpdateBuilder {
+    id: Option<UpdateId>,
+    kind: UpdateKind,
+}
+
+/// Constructors
+impl UpdateBuilder {
+    /// Creates a builder of an update of an arbitrary kind.
+    pub fn new(kind: UpdateKind) -> Self {
+        Self { id: None, kind }
+    }
+
+    /// Creates a builder of a [`UpdateKind::Message`] update.
+    pub fn message(message: Message) -> Self {
+        Self::new(UpdateKind::Message(message))
+    }
+
+    /// Creates a builder of a [`UpdateKind::Message`] update with a text
+    /// message sent by `from` to `chat`.
+    pub fn text_message<T>(chat: Chat, from: User, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::message(text_message(chat, Some(from), text))
+    }
+
+    /// Creates a builder of a [`UpdateKind::ChannelPost`] update with a text
+    /// message posted to `chat`.
+    pub fn channel_post<T>(chat: Chat, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut message = text_message(chat, None, text);
+        message.sender_chat = Some(message.chat.clone());
+
+        Self::new(UpdateKind::ChannelPost(message))
+    }
+
+    /// Creates a builder of a [`UpdateKind::BusinessConnection`] update.
+    pub fn business_connection<I>(id: I, user: User) -> Self
+    where
+        I: Into<String>,
+    {
+        Self::new(UpdateKind::BusinessConnection(BusinessConnection {
+            id: BusinessConnectionId(id.into()),
+            user_chat_id: user.id,
+            user,
+            date: date(),
+            rights: Some(BusinessBotRights { can_reply: true, ..Default::default() }),
+            is_enabled: true,
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::BusinessMessage`] update with a
+    /// text message sent by `from` to `chat` via the business connection `id`.
+    pub fn business_message<I, T>(id: I, chat: Chat, from: User, text: T) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+    {
+        let mut message = text_message(chat, Some(from), text);
+        if let MessageKind::Common(common) = &mut message.kind {
+            common.business_connection_id = Some(BusinessConnectionId(id.into()));
+        }
+
+        Self::new(UpdateKind::BusinessMessage(message))
+    }
+
+    /// Creates a builder of a [`UpdateKind::DeletedBusinessMessages`] update.
+    pub fn deleted_business_messages<I, M>(id: I, chat: Chat, message_ids: M) -> Self
+    where
+        I: Into<String>,
+        M: IntoIterator<Item = MessageId>,
+    {
+        Self::new(UpdateKind::DeletedBusinessMessages(BusinessMessagesDeleted {
+            business_connection_id: BusinessConnectionId(id.into()),
+            chat,
+            message_ids: message_ids.into_iter().collect(),
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::MessageReaction`] update, in which
+    /// `user` sets `new_reaction` on the message `message_id`.
+    pub fn message_reaction<R>(
+        chat: Chat,
+        user: User,
+        message_id: MessageId,
+        new_reaction: R,
+    ) -> Self
+    where
+        R: IntoIterator<Item = ReactionType>,
+    {
+        Self::new(UpdateKind::MessageReaction(MessageReactionUpdated {
+            chat,
+            message_id,
+            actor: MaybeAnonymousUser::User(user),
+            date: date(),
+            old_reaction: Vec::new(),
+            new_reaction: new_reaction.into_iter().collect(),
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::MessageReactionCount`] update.
+    pub fn message_reaction_count<R>(chat: Chat, message_id: MessageId, reactions: R) -> Self
+    where
+        R: IntoIterator<Item = ReactionCount>,
+    {
+        Self::new(UpdateKind::MessageReactionCount(MessageReactionCountUpdated {
+            chat,
+            message_id,
+            date: date(),
+            reactions: reactions.into_iter().collect(),
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::InlineQuery`] update.
+    pub fn inline_query<Q>(from: User, query: Q) -> Self
+    where
+        Q: Into<String>,
+    {
+        Self::new(UpdateKind::InlineQuery(InlineQuery {
+            id: next_id_string(),
+            from,
+            location: None,
+            query: query.into(),
+            offset: String::new(),
+            chat_type: None,
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::ChosenInlineResult`] update.
+    pub fn chosen_inline_result<R, Q>(from: User, result_id: R, query: Q) -> Self
+    where
+        R: Into<String>,
+        Q: Into<String>,
+    {
+        Self::new(UpdateKind::ChosenInlineResult(ChosenInlineResult {
+            result_id: result_id.into(),
+            from,
+            location: None,
+            inline_message_id: None,
+            query: query.into(),
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::CallbackQuery`] update, in which
+    /// `from` presses a button with callback `data` attached to `message`.
+    pub fn callback_query<D>(from: User, data: D, message: Option<Message>) -> Self
+    where
+        D: Into<String>,
+    {
+        Self::new(UpdateKind::CallbackQuery(CallbackQuery {
+            id: next_id_string(),
+            from,
+            message: message.map(MaybeInaccessibleMessage::Regular),
+            inline_message_id: None,
+            chat_instance: String::from("0"),
+            data: Some(data.into()),
+            game_short_name: None,
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::ShippingQuery`] update with a
+    /// placeholder shipping address.
+    pub fn shipping_query<P>(from: User, invoice_payload: P) -> Self
+    where
+        P: Into<String>,
+    {
+        Self::new(UpdateKind::ShippingQuery(ShippingQuery {
+            id: next_id_string(),
+            from,
+            invoice_payload: invoice_payload.into(),
+            shipping_address: ShippingAddress {
+                country_code: CountryCode::US,
+                state: String::from("New York"),
+                city: String::from("New York"),
+                street_line1: String::from("Broadway"),
+                street_line2: String::new(),
+                post_code: String::from("10001"),
+            },
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::PreCheckoutQuery`] update.
+    pub fn pre_checkout_query<C, P>(
+        from: User,
+        currency: C,
+        total_amount: u32,
+        invoice_payload: P,
+    ) -> Self
+    where
+        C: Into<String>,
+        P: Into<String>,
+    {
+        Self::new(UpdateKind::PreCheckoutQuery(PreCheckoutQuery {
+            id: next_id_string(),
+            from,
+            currency: currency.into(),
+            total_amount,
+            invoice_payload: invoice_payload.into(),
+            shipping_option_id: None,
+            order_info: OrderInfo::default(),
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::PurchasedPaidMedia`] update, in
+    /// which `from` bought paid media sent with `paid_media_payload`.
+    pub fn purchased_paid_media<P>(from: User, paid_media_payload: P) -> Self
+    where
+        P: Into<String>,
+    {
+        Self::new(UpdateKind::PurchasedPaidMedia(PaidMediaPurchased {
+            from,
+            paid_media_payload: paid_media_payload.into(),
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::Poll`] update with a regular
+    /// anonymous poll without votes.
+    pub fn poll<Q, O>(question: Q, options: O) -> Self
+    where
+        Q: Into<String>,
+        O: IntoIterator,
+        O::Item: Into<String>,
+    {
+        Self::new(UpdateKind::Poll(Poll {
+            id: next_id_string(),
+            question: question.into(),
+            question_entities: None,
+            options: options
+                .into_iter()
+                .map(|text| PollOption { text: text.into(), text_entities: None, voter_count: 0 })
+                .collect(),
+            is_closed: false,
+            total_voter_count: 0,
+            is_anonymous: true,
+            poll_type: PollType::Regular,
+            allows_multiple_answers: false,
+            correct_option_id: None,
+            explanation: None,
+            explanation_entities: None,
+            open_period: None,
+            close_date: None,
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::PollAnswer`] update.
+    pub fn poll_answer<P, O>(poll_id: P, voter: User, option_ids: O) -> Self
+    where
+        P: Into<String>,
+        O: IntoIterator<Item = u8>,
+    {
+        Self::new(UpdateKind::PollAnswer(PollAnswer {
+            poll_id: poll_id.into(),
+            voter: MaybeAnonymousUser::User(voter),
+            option_ids: option_ids.into_iter().collect(),
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::MyChatMember`] update, in which
+    /// `from` changes the status of the bot (`me`) in `chat`.
+    pub fn my_chat_member_updated(
+        chat: Chat,
+        from: User,
+        me: User,
+        old: ChatMemberKind,
+        new: ChatMemberKind,
+    ) -> Self {
+        Self::new(UpdateKind::MyChatMember(chat_member_updated(chat, from, me, old, new)))
+    }
+
+    /// Creates a builder of a [`UpdateKind::ChatMember`] update, in which
+    /// `from` changes the status of `member` in `chat`.
+    pub fn chat_member_updated(
+        chat: Chat,
+        from: User,
+        member: User,
+        old: ChatMemberKind,
+        new: ChatMemberKind,
+    ) -> Self {
+        Self::new(UpdateKind::ChatMember(chat_member_updated(chat, from, member, old, new)))
+    }
+
+    /// Creates a builder of a [`UpdateKind::ChatJoinRequest`] update.
+    pub fn chat_join_request(chat: Chat, from: User) -> Self {
+        Self::new(UpdateKind::ChatJoinRequest(ChatJoinRequest {
+            chat,
+            user_chat_id: ChatId(from.id.0 as i64),
+            from,
+            date: date(),
+            bio: None,
+            invite_link: None,
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::ChatBoost`] update, in which
+    /// `user` boosts `chat` with Telegram Premium.
+    pub fn chat_boost(chat: Chat, user: User) -> Self {
+        Self::new(UpdateKind::ChatBoost(ChatBoostUpdated {
+            chat,
+            boost: ChatBoost {
+                boost_id: next_id_string(),
+                add_date: date(),
+                expiration_date: date() + chrono::Duration::days(30),
+                source: ChatBoostSource::Premium(ChatBoostSourcePremium { user }),
+            },
+        }))
+    }
+
+    /// Creates a builder of a [`UpdateKind::RemovedChatBoost`] update, in
+    /// which a Telegram Premium boost of `user` is removed from `chat`.
+    pub fn removed_chat_boost(chat: Chat, user: User) -> Self {
+        Self::new(UpdateKind::RemovedChatBoost(ChatBoostRemoved {
+            chat,
+            boost_id: next_id_string(),
+            remove_date: date(),
+            source: ChatBoostSource::Premium(ChatBoostSourcePremium { user }),
+        }))
+    }
+}
+
+/// Modifiers
+impl UpdateBuilder {
+    /// Sets the id of the update.
+    pub fn id(self, id: UpdateId) -> Self {
+        Self { id: Some(id), ..self }
+    }
+
+    /// Turns a message update into the corresponding "edited" update.
+    ///
+    /// That is [`UpdateKind::Message`] becomes [`UpdateKind::EditedMessage`],
+    /// [`UpdateKind::ChannelPost`] becomes [`UpdateKind::EditedChannelPost`]
+    /// and [`UpdateKind::BusinessMessage`] becomes
+    /// [`UpdateKind::EditedBusinessMessage`]. The edit date of the message is
+    /// set too.
+    ///
+    /// ## Panics
+    ///
+    /// If the update is not one of the kinds listed above.
+    pub fn edited(self) -> Self {
+        let kind = match self.kind {
+            UpdateKind::Message(m) => UpdateKind::EditedMessage(edit(m)),
+            UpdateKind::ChannelPost(m) => UpdateKind::EditedChannelPost(edit(m)),
+            UpdateKind::BusinessMessage(m) => UpdateKind::EditedBusinessMessage(edit(m)),
+            kind => panic!("Can't make an edited update out of {kind:?}"),
+        };
+
+        Self { kind, ..self }
+    }
+
+    /// Builds the update.
+    #[must_use]
+    pub fn build(self) -> Update {
+        let id =
+            self.id.unwrap_or_else(|| UpdateId(NEXT_UPDATE_ID.fetch_add(1, Ordering::Relaxed)));
+
+        Update { id, kind: self.kind }
+    }
+}
+
+impl From<UpdateBuilder> for Update {
+    fn from(builder: UpdateBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Creates a non-bot user with the given id and first name.
+#[must_use]
+pub fn user<N>(id: u64, first_name: N) -> User
+where
+    N: Into<String>,
+{
+    User {
+        id: UserId(id),
+        is_bot: false,
+        first_name: first_name.into(),
+        last_name: None,
+        username: None,
+        language_code: Some(String::from("en")),
+        is_premium: false,
+        added_to_attachment_menu: false,
+    }
+}
+
+/// Creates a private chat with `user`.
+#[must_use]
+pub fn private_chat(user: &User) -> Chat {
+    chat(
+        ChatId(user.id.0 as i64),
+        ChatKind::Private(ChatPrivate {
+            username: user.username.clone(),
+            first_name: Some(user.first_name.clone()),
+            last_name: user.last_name.clone(),
+            bio: None,
+            has_private_forwards: None,
+            has_restricted_voice_and_video_messages: None,
+            personal_chat: None,
+            birthdate: None,
+            business_intro: None,
+            business_location: None,
+            business_opening_hours: None,
+        }),
+    )
+}
+
+/// Creates a group chat.
+#[must_use]
+pub fn group_chat<T>(id: ChatId, title: T) -> Chat
+where
+    T: Into<String>,
+{
+    public_chat(id, title, PublicChatKind::Group(PublicChatGroup { permissions: None }))
+}
+
+/// Creates a supergroup chat.
+#[must_use]
+pub fn supergroup_chat<T>(id: ChatId, title: T) -> Chat
+where
+    T: Into<String>,
+{
+    public_chat(
+        id,
+        title,
+        PublicChatKind::Supergroup(PublicChatSupergroup {
+            username: None,
+            active_usernames: None,
+            is_forum: false,
+            sticker_set_name: None,
+            can_set_sticker_set: None,
+            custom_emoji_sticker_set_name: None,
+            permissions: None,
+            slow_mode_delay: None,
+            unrestrict_boost_count: None,
+            linked_chat_id: None,
+            location: None,
+            join_to_send_messages: None,
+            join_by_request: None,
+        }),
+    )
+}
+
+/// Creates a channel chat.
+#[must_use]
+pub fn channel_chat<T>(id: ChatId, title: T) -> Chat
+where
+    T: Into<String>,
+{
+    public_chat(
+        id,
+        title,
+        PublicChatKind::Channel(PublicChatChannel { username: None, linked_chat_id: None }),
+    )
+}
+
+/// Creates a text message sent to `chat`.
+///
+/// `from` is `None` for messages sent on behalf of a chat (e.g. channel
+/// posts).
+#[must_use]
+pub fn text_message<T>(chat: Chat, from: Option<User>, text: T) -> Message
+where
+    T: Into<String>,
+{
+    Message {
+        id: MessageId(next_id() as i32),
+        thread_id: None,
+        from,
+        sender_chat: None,
+        date: date(),
+        chat,
+        is_topic_message: false,
+        via_bot: None,
+        sender_business_bot: None,
+        kind: MessageKind::Common(MessageCommon {
+            author_signature: None,
+            forward_origin: None,
+            reply_to_message: None,
+            external_reply: None,
+            quote: None,
+            reply_to_story: None,
+            sender_boost_count: None,
+            edit_date: None,
+            media_kind: MediaKind::Text(MediaText {
+                text: text.into(),
+                entities: Vec::new(),
+                link_preview_options: None,
+            }),
+            reply_markup: None,
+            is_automatic_forward: false,
+            has_protected_content: false,
+            is_from_offline: false,
+            business_connection_id: None,
+            effect_id: None,
+        }),
+    }
+}
+
+fn chat(id: ChatId, kind: ChatKind) -> Chat {
+    Chat {
+        id,
+        kind,
+        photo: None,
+        available_reactions: None,
+        pinned_message: None,
+        message_auto_delete_time: None,
+        has_hidden_members: false,
+        has_aggressive_anti_spam_enabled: false,
+        chat_full_info: ChatFullInfo::default(),
+    }
+}
+
+fn public_chat<T>(id: ChatId, title: T, kind: PublicChatKind) -> Chat
+where
+    T: Into<String>,
+{
+    chat(
+        id,
+        ChatKind::Public(Box::new(ChatPublic {
+            title: Some(title.into()),
+            kind,
+            description: None,
+            invite_link: None,
+            has_protected_content: None,
+        })),
+    )
+}
+
+fn chat_member_updated(
+    chat: Chat,
+    from: User,
+    member: User,
+    old: ChatMemberKind,
+    new: ChatMemberKind,
+) -> ChatMemberUpdated {
+    ChatMemberUpdated {
+        chat,
+        from,
+        date: date(),
+        old_chat_member: ChatMember { user: member.clone(), kind: old },
+        new_chat_member: ChatMember { user: member, kind: new },
+        invite_link: None,
+        via_chat_folder_invite_link: false,
+    }
+}
+
+fn edit(mut message: Message) -> Message {
+    if let MessageKind::Common(common) = &mut message.kind {
+        common.edit_date = Some(date());
+    }
+
+    message
+}
+
+/// Ids for messages, queries, etc. Uniqueness is all that matters here.
+fn next_id() -> u32 {
+    static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+fn next_id_string() -> String {
+    next_id().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        testing::{
+            channel_chat, group_chat, private_chat, supergroup_chat, text_message, user,
+            UpdateBuilder,
+        },
+        types::{
+            ChatId, ChatMemberKind, MessageId, ReactionCount, ReactionType, UpdateId, UpdateKind,
+        },
+    };
+
+    #[test]
+    fn all_kinds() {
+        let alice = user(1, "Alice");
+        let bot = user(2, "Bot");
+        let group = group_chat(ChatId(-1), "Group");
+        let supergroup = supergroup_chat(ChatId(-1001), "Supergroup");
+        let channel = channel_chat(ChatId(-1002), "Channel");
+        let message = text_message(group.clone(), Some(alice.clone()), "hi");
+        let heart = || ReactionType::Emoji { emoji: "❤".to_owned() };
+
+        let updates = [
+            UpdateBuilder::text_message(private_chat(&alice), alice.clone(), "/start"),
+            UpdateBuilder::text_message(group.clone(), alice.clone(), "hi").edited(),
+            UpdateBuilder::channel_post(channel.clone(), "post"),
+            UpdateBuilder::channel_post(channel.clone(), "post").edited(),
+            UpdateBuilder::business_connection("conn", alice.clone()),
+            UpdateBuilder::business_message("conn", private_chat(&alice), alice.clone(), "hi"),
+            UpdateBuilder::business_message("conn", private_chat(&alice), alice.clone(), "hi")
+                .edited(),
+            UpdateBuilder::deleted_business_messages(
+                "conn",
+                private_chat(&alice),
+                [MessageId(1), MessageId(2)],
+            ),
+            UpdateBuilder::message_reaction(group.clone(), alice.clone(), message.id, [heart()]),
+            UpdateBuilder::message_reaction_count(
+                channel.clone(),
+                MessageId(1),
+                [ReactionCount { r#type: heart(), total_count: 3 }],
+            ),
+            UpdateBuilder::inline_query(alice.clone(), "query"),
+            UpdateBuilder::chosen_inline_result(alice.clone(), "result", "query"),
+            UpdateBuilder::callback_query(alice.clone(), "data", Some(message.clone())),
+            UpdateBuilder::shipping_query(alice.clone(), "payload"),
+            UpdateBuilder::pre_checkout_query(alice.clone(), "USD", 100, "payload"),
+            UpdateBuilder::purchased_paid_media(alice.clone(), "payload"),
+            UpdateBuilder::poll("question", ["a", "b"]),
+            UpdateBuilder::poll_answer("poll", alice.clone(), [0]),
+            UpdateBuilder::my_chat_member_updated(
+                supergroup.clone(),
+                alice.clone(),
+                bot.clone(),
+                ChatMemberKind::Left,
+                ChatMemberKind::Member,
+            ),
+            UpdateBuilder::chat_member_updated(
+                supergroup.clone(),
+                alice.clone(),
+                bot,
+                ChatMemberKind::Member,
+                ChatMemberKind::Left,
+            ),
+            UpdateBuilder::chat_join_request(supergroup.clone(), alice.clone()),
+            UpdateBuilder::chat_boost(channel.clone(), alice.clone()),
+            UpdateBuilder::removed_chat_boost(channel, alice),
+        ];
+
+        let updates = updates.map(UpdateBuilder::build);
+
+        // Every kind (except for `UpdateKind::Error`) is covered exactly once, in the
+        // order of the `updates` array. The match has no wildcard arm, so that a new
+        // kind without a builder fails to compile.
+        for (i, update) in updates.iter().enumerate() {
+            let index = match update.kind {
+                UpdateKind::Message(_) => 0,
+                UpdateKind::EditedMessage(_) => 1,
+                UpdateKind::ChannelPost(_) => 2,
+                UpdateKind::EditedChannelPost(_) => 3,
+                UpdateKind::BusinessConnection(_) => 4,
+                UpdateKind::BusinessMessage(_) => 5,
+                UpdateKind::EditedBusinessMessage(_) => 6,
+                UpdateKind::DeletedBusinessMessages(_) => 7,
+                UpdateKind::MessageReaction(_) => 8,
+                UpdateKind::MessageReactionCount(_) => 9,
+                UpdateKind::InlineQuery(_) => 10,
+                UpdateKind::ChosenInlineResult(_) => 11,
+                UpdateKind::CallbackQuery(_) => 12,
+                UpdateKind::ShippingQuery(_) => 13,
+                UpdateKind::PreCheckoutQuery(_) => 14,
+                UpdateKind::PurchasedPaidMedia(_) => 15,
+                UpdateKind::Poll(_) => 16,
+                UpdateKind::PollAnswer(_) => 17,
+                UpdateKind::MyChatMember(_) => 18,
+                UpdateKind::ChatMember(_) => 19,
+                UpdateKind::ChatJoinRequest(_) => 20,
+                UpdateKind::ChatBoost(_) => 21,
+                UpdateKind::RemovedChatBoost(_) => 22,
+                UpdateKind::Error(_) => panic!("builders must not produce errors"),
+            };
+            assert_eq!(index, i, "{update:?}");
+        }
+
+        for update in &updates {
+            if !matches!(update.kind, UpdateKind::Poll(_)) {
+                assert!(update.from().is_some() || update.chat().is_some(), "{update:?}");
+            }
+        }
+
+        let UpdateKind::Message(message) = &updates[0].kind else { unreachable!() };
+        assert_eq!(message.text(), Some("/start"));
+        assert_eq!(updates[0].chat().unwrap().id, ChatId(1));
+    }
+
+    #[test]
+    fn ids() {
+        let alice = user(1, "Alice");
+        let first = UpdateBuilder::inline_query(alice.clone(), "").build();
+        let second = UpdateBuilder::inline_query(alice.clone(), "").build();
+        assert_ne!(first.id, second.id);
+
+        let update = UpdateBuilder::inline_query(alice, "").id(UpdateId(17)).build();
+        assert_eq!(update.id, UpdateId(17));
+    }
+}
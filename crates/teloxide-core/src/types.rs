@@ -1,14 +1,18 @@
 //! Telegram API types.
 
+pub use accepted_gift_types::*;
 pub use allowed_update::*;
 pub use animation::*;
 pub use audio::*;
+pub use background_fill::*;
+pub use background_type::*;
 pub use birthdate::*;
 pub use bot_command::*;
 pub use bot_command_scope::*;
 pub use bot_description::*;
 pub use bot_name::*;
 pub use bot_short_description::*;
+pub use business_bot_rights::*;
 pub use business_connection::*;
 pub use business_connection_id::*;
 pub use business_intro::*;
@@ -21,6 +25,7 @@ pub use callback_query::*;
 pub use chat::*;
 pub use chat_action::*;
 pub use chat_administrator_rights::*;
+pub use chat_background::*;
 pub use chat_boost::*;
 pub use chat_boost_added::*;
 pub use chat_boost_removed::*;
@@ -41,6 +46,7 @@ pub use contact::*;
 pub use dice::*;
 pub use dice_emoji::*;
 pub use document::*;
+pub use effect_id::*;
 pub use encrypted_credentials::*;
 pub use encrypted_passport_element::*;
 pub use external_reply_info::*;
@@ -55,6 +61,8 @@ pub use game::*;
 pub use game_high_score::*;
 pub use general_forum_topic_hidden::*;
 pub use general_forum_topic_unhidden::*;
+pub use gift::*;
+pub use gifts::*;
 pub use giveaway::*;
 pub use giveaway_completed::*;
 pub use giveaway_created::*;
@@ -88,7 +96,11 @@ pub use inline_query_results_button::*;
 pub use input_file::*;
 pub use input_media::*;
 pub use input_message_content::*;
+pub use input_paid_media::*;
+pub use input_poll_option::*;
+pub use input_profile_photo::*;
 pub use input_sticker::*;
+pub use input_story_content::*;
 pub use invoice::*;
 pub use keyboard_button::*;
 pub use keyboard_button_poll_type::*;
@@ -97,6 +109,7 @@ pub use keyboard_button_request_users::*;
 pub use label_price::*;
 pub use link_preview_options::*;
 pub use location::*;
+pub use location_address::*;
 pub use login_url::*;
 pub use mask_position::*;
 pub use maybe_anonymous_user::*;
@@ -111,6 +124,11 @@ pub use message_origin::*;
 pub use message_reaction_count_updated::*;
 pub use message_reaction_updated::*;
 pub use order_info::*;
+pub use owned_gift::*;
+pub use owned_gifts::*;
+pub use paid_media::*;
+pub use paid_media_info::*;
+pub use paid_media_purchased::*;
 pub use parse_mode::*;
 pub use passport_data::*;
 pub use passport_element_error::*;
@@ -120,29 +138,39 @@ pub use poll::*;
 pub use poll_answer::*;
 pub use poll_type::*;
 pub use pre_checkout_query::*;
+pub use prepared_inline_message::*;
 pub use proximity_alert_triggered::*;
 pub use reaction_type::*;
+pub use refunded_payment::*;
 pub use reply_keyboard_markup::*;
 pub use reply_keyboard_remove::*;
 pub use reply_markup::*;
 pub use reply_parameters::*;
 pub use request_id::*;
 pub use response_parameters::*;
+pub use revenue_withdrawal_state::*;
 pub use rgb::*;
 pub use sent_web_app_message::*;
 pub use shared_user::*;
 pub use shipping_address::*;
 pub use shipping_option::*;
 pub use shipping_query::*;
+pub use star_amount::*;
+pub use star_transaction::*;
+pub use star_transactions::*;
 pub use sticker::*;
 pub use sticker_set::*;
 pub use story::*;
+pub use story_area::*;
+pub use story_area_type::*;
 pub use story_id::*;
 pub use successful_payment::*;
 pub use switch_inline_query_chosen_chat::*;
 pub use target_message::*;
 pub use text_quote::*;
 pub use thread_id::*;
+pub use transaction_partner::*;
+pub use unique_gift::*;
 pub use unit_false::*;
 pub use unit_true::*;
 pub use update::*;
@@ -163,15 +191,19 @@ pub use web_app_info::*;
 pub use webhook_info::*;
 pub use write_access_allowed::*;
 
+mod accepted_gift_types;
 mod allowed_update;
 mod animation;
 mod audio;
+mod background_fill;
+mod background_type;
 mod birthdate;
 mod bot_command;
 mod bot_command_scope;
 mod bot_description;
 mod bot_name;
 mod bot_short_description;
+mod business_bot_rights;
 mod business_connection;
 mod business_connection_id;
 mod business_intro;
@@ -184,6 +216,7 @@ mod callback_query;
 mod chat;
 mod chat_action;
 mod chat_administrator_rights;
+mod chat_background;
 mod chat_boost;
 mod chat_boost_removed;
 mod chat_boost_source;
@@ -203,6 +236,7 @@ mod contact;
 mod dice;
 mod dice_emoji;
 mod document;
+mod effect_id;
 mod external_reply_info;
 mod file;
 mod force_reply;
@@ -215,6 +249,8 @@ mod game;
 mod game_high_score;
 mod general_forum_topic_hidden;
 mod general_forum_topic_unhidden;
+mod gift;
+mod gifts;
 mod giveaway;
 mod giveaway_completed;
 mod giveaway_created;
@@ -226,7 +262,11 @@ mod inline_query_results_button;
 mod input_file;
 mod input_media;
 mod input_message_content;
+mod input_paid_media;
+mod input_poll_option;
+mod input_profile_photo;
 mod input_sticker;
+mod input_story_content;
 mod invoice;
 mod keyboard_button;
 mod keyboard_button_poll_type;
@@ -235,6 +275,7 @@ mod keyboard_button_request_users;
 mod label_price;
 mod link_preview_options;
 mod location;
+mod location_address;
 mod login_url;
 mod mask_position;
 mod maybe_anonymous_user;
@@ -249,34 +290,49 @@ mod message_origin;
 mod message_reaction_count_updated;
 mod message_reaction_updated;
 mod order_info;
+mod owned_gift;
+mod owned_gifts;
+mod paid_media;
+mod paid_media_info;
+mod paid_media_purchased;
 mod parse_mode;
 mod photo_size;
 mod poll;
 mod poll_answer;
 mod poll_type;
 mod pre_checkout_query;
+mod prepared_inline_message;
 mod proximity_alert_triggered;
 mod reaction_type;
+mod refunded_payment;
 mod reply_keyboard_markup;
 mod reply_keyboard_remove;
 mod reply_markup;
 mod reply_parameters;
 mod request_id;
 mod response_parameters;
+mod revenue_withdrawal_state;
 mod rgb;
 mod sent_web_app_message;
 mod shared_user;
 mod shipping_address;
 mod shipping_option;
 mod shipping_query;
+mod star_amount;
+mod star_transaction;
+mod star_transactions;
 mod sticker;
 mod sticker_set;
 mod story;
+mod story_area;
+mod story_area_type;
 mod successful_payment;
 mod switch_inline_query_chosen_chat;
 mod target_message;
 mod text_quote;
 mod thread_id;
+mod transaction_partner;
+mod unique_gift;
 mod unit_false;
 mod unit_true;
 mod update;
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Rgb;
+
+/// This object describes the way a background is filled based on the selected
+/// colors.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundfill).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "type")]
+pub enum BackgroundFill {
+    Solid(BackgroundFillSolid),
+    Gradient(BackgroundFillGradient),
+    FreeformGradient(BackgroundFillFreeformGradient),
+}
+
+/// The background is filled using the selected color.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundfillsolid).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundFillSolid {
+    /// The color of the background fill.
+    pub color: Rgb,
+}
+
+/// The background is a gradient fill.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundfillgradient).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundFillGradient {
+    /// Top color of the gradient.
+    pub top_color: Rgb,
+
+    /// Bottom color of the gradient.
+    pub bottom_color: Rgb,
+
+    /// Clockwise rotation angle of the background fill in degrees; 0-359.
+    pub rotation_angle: u16,
+}
+
+/// The background is a freeform gradient that rotates after every message in
+/// the chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundfillfreeformgradient).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundFillFreeformGradient {
+    /// A list of the 3 or 4 base colors that are used to generate the freeform
+    /// gradient.
+    pub colors: Vec<Rgb>,
+}
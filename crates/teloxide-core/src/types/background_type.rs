@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{BackgroundFill, Document};
+
+/// This object describes the type of a background.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundtype).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "type")]
+pub enum BackgroundType {
+    Fill(BackgroundTypeFill),
+    Wallpaper(BackgroundTypeWallpaper),
+    Pattern(BackgroundTypePattern),
+    ChatTheme(BackgroundTypeChatTheme),
+}
+
+/// The background is automatically filled based on the selected colors.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundtypefill).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundTypeFill {
+    /// The background fill.
+    pub fill: BackgroundFill,
+
+    /// Dimming of the background in dark themes, as a percentage; 0-100.
+    pub dark_theme_dimming: u8,
+}
+
+/// The background is a wallpaper in the JPEG format.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundtypewallpaper).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundTypeWallpaper {
+    /// Document with the wallpaper.
+    pub document: Document,
+
+    /// Dimming of the background in dark themes, as a percentage; 0-100.
+    pub dark_theme_dimming: u8,
+
+    /// `true`, if the wallpaper is downscaled to fit in a 450x450 square and
+    /// then box-blurred with radius 12.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_blurred: bool,
+
+    /// `true`, if the background moves slightly when the device is tilted.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_moving: bool,
+}
+
+/// The background is a PNG or TGV (gzipped subset of SVG with MIME type
+/// “application/x-tgwallpattern”) pattern to be combined with the background
+/// fill chosen by the user.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundtypepattern).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundTypePattern {
+    /// Document with the pattern.
+    pub document: Document,
+
+    /// The background fill that is combined with the pattern.
+    pub fill: BackgroundFill,
+
+    /// Intensity of the pattern when it is shown above the filled background;
+    /// 0-100.
+    pub intensity: u8,
+
+    /// `true`, if the background fill must be applied only to the pattern
+    /// itself. All other pixels are black in this case. For dark themes only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_inverted: bool,
+
+    /// `true`, if the background moves slightly when the device is tilted.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_moving: bool,
+}
+
+/// The background is taken directly from a built-in chat theme.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundtypechattheme).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundTypeChatTheme {
+    /// Name of the chat theme, which is usually an emoji.
+    pub theme_name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BackgroundFillSolid, Rgb};
+
+    #[test]
+    fn deserialize_fill() {
+        let data = r#"{
+            "type": "fill",
+            "fill": {
+                "type": "solid",
+                "color": 16777215
+            },
+            "dark_theme_dimming": 50
+        }"#;
+        let expected = BackgroundType::Fill(BackgroundTypeFill {
+            fill: BackgroundFill::Solid(BackgroundFillSolid { color: Rgb::from_u32(0xFFFFFF) }),
+            dark_theme_dimming: 50,
+        });
+
+        assert_eq!(serde_json::from_str::<BackgroundType>(data).unwrap(), expected);
+    }
+
+    #[test]
+    fn deserialize_chat_theme() {
+        let data = r#"{"type": "chat_theme", "theme_name": "🌷"}"#;
+        let expected =
+            BackgroundType::ChatTheme(BackgroundTypeChatTheme { theme_name: "🌷".to_owned() });
+
+        assert_eq!(serde_json::from_str::<BackgroundType>(data).unwrap(), expected);
+    }
+}
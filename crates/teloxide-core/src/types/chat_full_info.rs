@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::types::AcceptedGiftTypes;
+
 // TODO: in the TBA7.3 the Chat will be splitted into Chat and ChatInfo
 // Currently it's just a container for the some fields of the Chat struct
 #[serde_with::skip_serializing_none]
@@ -41,6 +43,24 @@ pub struct ChatFullInfo {
     /// [`GetChat`]: crate::payloads::GetChat
     #[serde(default)]
     pub has_visible_history: bool,
+    /// The maximum number of reactions that can be set on a message in the
+    /// chat. Returned only in [`GetChat`].
+    ///
+    /// [`GetChat`]: crate::payloads::GetChat
+    pub max_reaction_count: Option<u8>,
+    /// True, if paid media messages can be sent or forwarded to the channel
+    /// chat. The field is available only for channel chats. Returned only in
+    /// [`GetChat`].
+    ///
+    /// [`GetChat`]: crate::payloads::GetChat
+    #[serde(default)]
+    pub can_send_paid_media: bool,
+    /// Information about types of gifts that are accepted by the chat or by
+    /// the corresponding user for private chats. Returned only in
+    /// [`GetChat`].
+    ///
+    /// [`GetChat`]: crate::payloads::GetChat
+    pub accepted_gift_types: Option<AcceptedGiftTypes>,
 }
 
 #[cfg(test)]
@@ -62,5 +82,31 @@ mod tests {
                 ..ChatFullInfo::default()
             }
         );
+        assert_eq!(
+            serde_json::from_str::<ChatFullInfo>(
+                r#"{
+                    "max_reaction_count": 11,
+                    "can_send_paid_media": true,
+                    "accepted_gift_types": {
+                        "unlimited_gifts": true,
+                        "limited_gifts": true,
+                        "unique_gifts": false,
+                        "premium_subscription": false
+                    }
+                }"#
+            )
+            .unwrap(),
+            ChatFullInfo {
+                max_reaction_count: Some(11),
+                can_send_paid_media: true,
+                accepted_gift_types: Some(AcceptedGiftTypes {
+                    unlimited_gifts: true,
+                    limited_gifts: true,
+                    unique_gifts: false,
+                    premium_subscription: false,
+                }),
+                ..ChatFullInfo::default()
+            }
+        );
     }
 }
@@ -29,4 +29,11 @@ pub struct ChatInviteLink {
     pub member_limit: Option<u32>,
     /// Number of pending join requests created using this link
     pub pending_join_request_count: Option<u32>,
+    /// The number of seconds the subscription will be active for before the
+    /// next payment
+    pub subscription_period: Option<u32>,
+    /// The amount of Telegram Stars a user must pay initially and after each
+    /// subsequent subscription period to be a member of the chat using the
+    /// link
+    pub subscription_price: Option<u32>,
 }
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// Unique identifier of a message effect.
+#[derive(Default, Clone, Debug, derive_more::Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EffectId(pub String);
+
+#[cfg(test)]
+mod tests {
+    use crate::types::EffectId;
+
+    #[test]
+    fn effect_id_deser() {
+        let json = r#""5104841245755180586""#;
+        let effect_id: EffectId = serde_json::from_str(json).unwrap();
+        assert_eq!(effect_id, EffectId(String::from("5104841245755180586")));
+    }
+
+    #[test]
+    fn effect_id_ser() {
+        let effect_id = EffectId(String::from("5104841245755180586"));
+        let json = serde_json::to_string(&effect_id).unwrap();
+        assert_eq!(json, r#""5104841245755180586""#);
+    }
+}
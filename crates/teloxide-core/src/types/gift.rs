@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Sticker;
+
+/// This object represents a gift that can be sent by the bot.
+///
+/// [The official docs](https://core.telegram.org/bots/api#gift).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Gift {
+    /// Unique identifier of the gift.
+    pub id: String,
+
+    /// The sticker that represents the gift.
+    pub sticker: Sticker,
+
+    /// The number of Telegram Stars that must be paid to send the sticker.
+    pub star_count: u32,
+
+    /// The number of Telegram Stars that must be paid to upgrade the gift to
+    /// a unique one.
+    pub upgrade_star_count: Option<u32>,
+
+    /// The total number of the gifts of this type that can be sent; for
+    /// limited gifts only.
+    pub total_count: Option<u32>,
+
+    /// The number of remaining gifts of this type that can be sent; for
+    /// limited gifts only.
+    pub remaining_count: Option<u32>,
+}
+
+impl Gift {
+    /// Returns `true` if only a limited number of gifts of this type can be
+    /// sent.
+    #[must_use]
+    pub fn is_limited(&self) -> bool {
+        self.total_count.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"
+        {
+            "id": "5170145012310081615",
+            "sticker": {
+                "width": 512,
+                "height": 512,
+                "emoji": "💝",
+                "is_animated": true,
+                "is_video": false,
+                "type": "regular",
+                "file_id": "CAACAgIAAxUAAWcZLtjbnzOm1b9skuybz9yb5aF6AAKHAgACjTpJS5AAAcPPfLIgyDYE",
+                "file_unique_id": "AgADhwIAAo06SUs",
+                "file_size": 16639
+            },
+            "star_count": 15,
+            "total_count": 1000,
+            "remaining_count": 26
+        }
+        "#;
+        let gift: Gift = serde_json::from_str(data).unwrap();
+
+        assert_eq!(gift.id, "5170145012310081615");
+        assert_eq!(gift.star_count, 15);
+        assert_eq!(gift.upgrade_star_count, None);
+        assert_eq!(gift.remaining_count, Some(26));
+        assert!(gift.is_limited());
+    }
+}
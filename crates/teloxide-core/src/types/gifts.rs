@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Gift;
+
+/// This object represents a list of gifts.
+///
+/// [The official docs](https://core.telegram.org/bots/api#gifts).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Gifts {
+    /// The list of gifts.
+    pub gifts: Vec<Gift>,
+}
@@ -1,7 +1,7 @@
 use bytes::{Bytes, BytesMut};
 use futures::{
     future::{ready, Either},
-    stream,
+    stream, Stream, StreamExt,
 };
 use once_cell::sync::OnceCell;
 use rc_box::ArcBox;
@@ -15,11 +15,19 @@ use tokio::{
 use tokio_util::codec::{Decoder, FramedRead};
 
 use std::{
-    borrow::Cow, convert::Infallible, fmt, future::Future, io, iter, mem, path::PathBuf, pin::Pin,
-    sync::Arc, task,
+    borrow::Cow,
+    cell::Cell,
+    convert::Infallible,
+    fmt,
+    future::Future,
+    io, iter, mem,
+    path::{self, PathBuf},
+    pin::Pin,
+    sync::Arc,
+    task,
 };
 
-use crate::types::InputSticker;
+use crate::types::{InputProfilePhoto, InputSticker, InputStoryContent};
 
 /// This object represents the contents of a file to be uploaded.
 ///
@@ -83,6 +91,13 @@ impl InputFile {
     }
 
     /// Creates an `InputFile` from a file path.
+    ///
+    /// If the bot works with a [Telegram bot API server][tbas] in the local
+    /// mode (see [`Bot::set_local_mode`]), the file is not uploaded: the
+    /// server reads it by the path, which must thus be accessible to it.
+    ///
+    /// [tbas]: https://github.com/tdlib/telegram-bot-api
+    /// [`Bot::set_local_mode`]: crate::Bot::set_local_mode
     pub fn file(path: impl Into<PathBuf>) -> Self {
         Self::new(File(path.into()))
     }
@@ -122,8 +137,20 @@ impl InputFile {
 
     /// Returns `true` if this file needs an attachment i.e. it's not a file_id
     /// or url that can be serialized without any additional multipart parts.
-    pub(crate) fn needs_attach(&self) -> bool {
-        !matches!(self.inner, Url(_) | FileId(_))
+    ///
+    /// `local` is whether the files are sent to a Bot API server in the local
+    /// mode, see [`with_local_mode`].
+    pub(crate) fn needs_attach(&self, local: bool) -> bool {
+        !matches!(self.inner, Url(_) | FileId(_)) && self.local_uri(local).is_none()
+    }
+
+    /// Returns a `file://` URI of this file if it's a file on the disk, which
+    /// can be passed as is to a Bot API server in the local mode.
+    fn local_uri(&self, local: bool) -> Option<url::Url> {
+        match &self.inner {
+            File(path) if local => url::Url::from_file_path(path::absolute(path).ok()?).ok(),
+            _ => None,
+        }
     }
 
     /// Takes this file out.
@@ -139,6 +166,10 @@ impl InputFile {
     /// `multipart/form-data`, or the value if it may be uploaded in any way (ie
     /// it's an URL or file id).
     fn attach_or_value(&self) -> String {
+        if let Some(uri) = self.local_uri(LOCAL_MODE.get()) {
+            return uri.into();
+        }
+
         match &self.inner {
             Url(url) => url.as_str().to_owned(),
             FileId(file_id) => file_id.clone(),
@@ -190,10 +221,42 @@ impl Serialize for InputFile {
     }
 }
 
+thread_local! {
+    /// Whether files are being serialized for a Bot API server in the local
+    /// mode, see [`with_local_mode`].
+    static LOCAL_MODE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, which serializes files, telling them whether they are sent to a
+/// Bot API server in the local mode.
+///
+/// `Serialize` implementations can't get any context, hence the thread-local.
+pub(crate) fn with_local_mode<R>(local: bool, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous mode even if `f` panics.
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            LOCAL_MODE.set(self.0);
+        }
+    }
+
+    let _restore = Restore(LOCAL_MODE.replace(local));
+    f()
+}
+
 // internal api
 
 impl InputFile {
-    pub(crate) fn into_part(mut self) -> Option<impl Future<Output = Part>> {
+    /// Returns a part to be attached to a form, failing with an I/O error if
+    /// the file is larger than `size_limit`.
+    ///
+    /// The size of files created by [`InputFile::read`] is only known once
+    /// they are read, so the body of their part fails instead.
+    pub(crate) fn into_part(
+        mut self,
+        size_limit: u64,
+    ) -> Option<impl Future<Output = io::Result<Part>>> {
         let filename = self.take_or_guess_filename();
 
         match self.inner {
@@ -201,34 +264,77 @@ impl InputFile {
             Url(_) | FileId(_) => None,
 
             File(path_to_file) => {
-                let fut = async {
-                    let body = match tokio::fs::File::open(path_to_file).await {
-                        Ok(file) => {
-                            let file = FramedRead::new(file, BytesDecoder);
-
-                            Body::wrap_stream(file)
-                        }
-                        Err(err) => {
-                            // explicit type needed for `Bytes: From<?T>` in `wrap_stream`
-                            let err = Err::<Bytes, _>(err);
-                            Body::wrap_stream(stream::iter([err]))
-                        }
-                    };
+                let fut = async move {
+                    let file = open_file(path_to_file, size_limit).await?;
+                    let body = Body::wrap_stream(FramedRead::new(file, BytesDecoder));
 
-                    Part::stream(body).file_name(filename)
+                    Ok(Part::stream(body).file_name(filename))
                 };
 
                 Some(Either::Left(fut))
             }
             Bytes(data) => {
-                let stream = Part::stream(data).file_name(filename);
-                Some(Either::Right(Either::Left(ready(stream))))
+                let part = check_size("In-memory file", data.len() as u64, size_limit)
+                    .map(|()| Part::stream(data).file_name(filename));
+                Some(Either::Right(Either::Left(ready(part))))
             }
-            Read(read) => Some(Either::Right(Either::Right(read.into_part(filename)))),
+            Read(read) => Some(Either::Right(Either::Right(read.into_part(filename, size_limit)))),
+        }
+    }
+
+    /// Fails with an I/O error if this is a file on the disk larger than
+    /// `size_limit`.
+    ///
+    /// This is used for files that a Bot API server in the local mode reads by
+    /// their paths, so they never become parts, see
+    /// [`InputFile::needs_attach`].
+    pub(crate) async fn check_local_size(&self, size_limit: u64) -> io::Result<()> {
+        match &self.inner {
+            File(path) => {
+                let size = tokio::fs::metadata(path).await?.len();
+                check_size(path.display(), size, size_limit)
+            }
+            _ => Ok(()),
         }
     }
 }
 
+async fn open_file(path: PathBuf, size_limit: u64) -> io::Result<tokio::fs::File> {
+    let file = tokio::fs::File::open(&path).await?;
+
+    let size = file.metadata().await?.len();
+    check_size(path.display(), size, size_limit)?;
+
+    Ok(file)
+}
+
+fn check_size(name: impl fmt::Display, size: u64, size_limit: u64) -> io::Result<()> {
+    if size > size_limit {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{name} is {size} bytes, which exceeds the limit of {size_limit} bytes"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Fails the stream with an I/O error as soon as it yields more than
+/// `size_limit` bytes in total.
+fn limit_size<S>(stream: S, size_limit: u64) -> impl Stream<Item = io::Result<Bytes>>
+where
+    S: Stream<Item = io::Result<Bytes>>,
+{
+    let mut size = 0;
+    stream.map(move |chunk| {
+        let chunk = chunk?;
+        size += chunk.len() as u64;
+        check_size("Read file", size, size_limit)?;
+
+        Ok(chunk)
+    })
+}
+
 /// Adaptor for `AsyncRead` that allows clonning and converting to
 /// `multipart/form-data`
 #[derive(Clone)]
@@ -246,7 +352,11 @@ impl Read {
         Self { inner: it, buf: Arc::default(), notify: Arc::new(tx), wait: rx }
     }
 
-    pub(crate) async fn into_part(mut self, filename: Cow<'static, str>) -> Part {
+    pub(crate) async fn into_part(
+        mut self,
+        filename: Cow<'static, str>,
+        size_limit: u64,
+    ) -> io::Result<Part> {
         if !self.inner.is_taken() {
             let res = ArcBox::<TakeCell<dyn AsyncRead + Send + Unpin>>::try_from(self.inner);
             match res {
@@ -255,8 +365,8 @@ impl Read {
                 Ok(arc_box) => {
                     let fr = FramedRead::new(ExclusiveArcAsyncRead(arc_box), BytesDecoder);
 
-                    let body = Body::wrap_stream(fr);
-                    return Part::stream(body).file_name(filename);
+                    let body = Body::wrap_stream(limit_size(fr, size_limit));
+                    return Ok(Part::stream(body).file_name(filename));
                 }
                 // move the arc back into `self`
                 Err(i) => self.inner = i,
@@ -265,12 +375,12 @@ impl Read {
 
         // Slow path: either wait until someone will read the whole `dyn AsyncRead` into
         // a buffer, or be the one who reads
-        let body = self.into_shared_body().await;
+        let body = self.into_shared_body(size_limit).await?;
 
-        Part::stream(body).file_name(filename)
+        Ok(Part::stream(body).file_name(filename))
     }
 
-    async fn into_shared_body(mut self) -> Body {
+    async fn into_shared_body(mut self, size_limit: u64) -> io::Result<Body> {
         match self.inner.take() {
             // Read `dyn AsyncRead` into a buffer
             Some(mut read_ref) => {
@@ -329,7 +439,10 @@ impl Read {
         // unwrap: `OnceCell` is initialized in the match above before sending
         // notification, so at this point it's already initialized.
         match buf.get().unwrap() {
-            Ok(_) => {
+            Ok(chunks) => {
+                let size = chunks.iter().map(|chunk| chunk.len() as u64).sum();
+                check_size("Read file", size, size_limit)?;
+
                 // We can't use `.iter()` here, because the iterator must capture `buf`
                 let mut i = 0;
                 let iter = iter::from_fn(move || match buf.get().unwrap() {
@@ -343,12 +456,12 @@ impl Read {
                     Err(_) => unreachable!(),
                 });
 
-                Body::wrap_stream(stream::iter(iter))
+                Ok(Body::wrap_stream(stream::iter(iter)))
             }
 
             Err(err) => {
                 let err = Err::<Bytes, _>(Arc::clone(err));
-                Body::wrap_stream(stream::iter(iter::once(err)))
+                Ok(Body::wrap_stream(stream::iter(iter::once(err))))
             }
         }
     }
@@ -385,7 +498,7 @@ impl Decoder for BytesDecoder {
 
 /// An internal trait that is used in expansion of `impl_payload!` used to work
 /// with input-file-like things (`InputFile` itself, `Option<InputFile>`,
-/// `InputSticker`)
+/// `InputSticker`, `InputProfilePhoto`, `InputStoryContent`)
 pub(crate) trait InputFileLike {
     fn copy_into(&self, into: &mut dyn FnMut(InputFile));
 
@@ -425,3 +538,57 @@ impl InputFileLike for InputSticker {
         self.sticker.move_into(into)
     }
 }
+
+impl InputFileLike for InputProfilePhoto {
+    fn copy_into(&self, into: &mut dyn FnMut(InputFile)) {
+        self.file().copy_into(into)
+    }
+
+    fn move_into(&mut self, into: &mut dyn FnMut(InputFile)) {
+        self.file_mut().move_into(into)
+    }
+}
+
+impl InputFileLike for InputStoryContent {
+    fn copy_into(&self, into: &mut dyn FnMut(InputFile)) {
+        self.file().copy_into(into)
+    }
+
+    fn move_into(&mut self, into: &mut dyn FnMut(InputFile)) {
+        self.file_mut().move_into(into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn size_limit() {
+        let path = std::env::temp_dir().join(format!("teloxide_{}", uuid::Uuid::new_v4()));
+        tokio::fs::write(&path, b"1234").await.unwrap();
+
+        let file = InputFile::file(&path);
+        assert!(file.clone().into_part(4).unwrap().await.is_ok());
+        assert!(file.clone().into_part(3).unwrap().await.is_err());
+        assert!(file.check_local_size(4).await.is_ok());
+        assert!(file.check_local_size(3).await.is_err());
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        let memory = InputFile::memory(&b"1234"[..]);
+        assert!(memory.clone().into_part(4).unwrap().await.is_ok());
+        assert!(memory.into_part(3).unwrap().await.is_err());
+
+        // Copies of the same reader are read into a shared buffer first
+        let read = InputFile::read(&b"1234"[..]);
+        let copy = read.clone();
+        assert!(read.into_part(3).unwrap().await.is_err());
+        assert!(copy.into_part(4).unwrap().await.is_ok());
+
+        // The only copy of a reader is streamed as is
+        let chunks = [Ok(Bytes::from_static(b"12")), Ok(Bytes::from_static(b"34"))];
+        let res: Vec<_> = limit_size(stream::iter(chunks), 3).collect().await;
+        assert!(res[0].is_ok());
+        assert!(res[1].is_err());
+    }
+}
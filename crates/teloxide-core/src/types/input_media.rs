@@ -42,6 +42,10 @@ pub struct InputMediaPhoto {
     /// specified instead of `parse_mode`.
     pub caption_entities: Option<Vec<MessageEntity>>,
 
+    /// Pass `true`, if the caption must be shown above the message media.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_caption_above_media: bool,
+
     /// Pass `true` if the photo needs to be covered with a spoiler animation.
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub has_spoiler: bool,
@@ -49,7 +53,14 @@ pub struct InputMediaPhoto {
 
 impl InputMediaPhoto {
     pub const fn new(media: InputFile) -> Self {
-        Self { media, caption: None, parse_mode: None, caption_entities: None, has_spoiler: false }
+        Self {
+            media,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            has_spoiler: false,
+        }
     }
 
     pub fn media(mut self, val: InputFile) -> Self {
@@ -78,6 +89,14 @@ impl InputMediaPhoto {
         self
     }
 
+    /// Sets [`show_caption_above_media`] to `true`.
+    ///
+    /// [`show_caption_above_media`]: InputMediaPhoto::show_caption_above_media
+    pub fn show_caption_above_media(mut self) -> Self {
+        self.show_caption_above_media = true;
+        self
+    }
+
     /// Sets [`has_spoiler`] to `true`.
     ///
     /// [`has_spoiler`]: InputMediaPhoto::has_spoiler
@@ -118,6 +137,10 @@ pub struct InputMediaVideo {
     /// specified instead of `parse_mode`.
     pub caption_entities: Option<Vec<MessageEntity>>,
 
+    /// Pass `true`, if the caption must be shown above the message media.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_caption_above_media: bool,
+
     /// Video width.
     pub width: Option<u16>,
 
@@ -147,6 +170,7 @@ impl InputMediaVideo {
             height: None,
             duration: None,
             supports_streaming: None,
+            show_caption_above_media: false,
             has_spoiler: false,
         }
     }
@@ -202,6 +226,14 @@ impl InputMediaVideo {
         self
     }
 
+    /// Sets [`show_caption_above_media`] to `true`.
+    ///
+    /// [`show_caption_above_media`]: InputMediaVideo::show_caption_above_media
+    pub fn show_caption_above_media(mut self) -> Self {
+        self.show_caption_above_media = true;
+        self
+    }
+
     /// Sets [`has_spoiler`] to `true`.
     ///
     /// [`has_spoiler`]: InputMediaVideo::has_spoiler
@@ -243,6 +275,10 @@ pub struct InputMediaAnimation {
     /// specified instead of `parse_mode`.
     pub caption_entities: Option<Vec<MessageEntity>>,
 
+    /// Pass `true`, if the caption must be shown above the message media.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_caption_above_media: bool,
+
     /// Animation width.
     pub width: Option<u16>,
 
@@ -269,6 +305,7 @@ impl InputMediaAnimation {
             height: None,
             duration: None,
             caption_entities: None,
+            show_caption_above_media: false,
             has_spoiler: false,
         }
     }
@@ -319,6 +356,14 @@ impl InputMediaAnimation {
         self
     }
 
+    /// Sets [`show_caption_above_media`] to `true`.
+    ///
+    /// [`show_caption_above_media`]: InputMediaAnimation::show_caption_above_media
+    pub fn show_caption_above_media(mut self) -> Self {
+        self.show_caption_above_media = true;
+        self
+    }
+
     /// Sets [`has_spoiler`] to `true`.
     ///
     /// [`has_spoiler`]: InputMediaAnimation::has_spoiler
@@ -573,6 +618,7 @@ mod tests {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            show_caption_above_media: false,
             has_spoiler: false,
         });
 
@@ -593,6 +639,7 @@ mod tests {
             duration: None,
             supports_streaming: None,
             caption_entities: None,
+            show_caption_above_media: false,
             has_spoiler: false,
         });
 
@@ -612,6 +659,7 @@ mod tests {
             height: None,
             duration: None,
             caption_entities: None,
+            show_caption_above_media: false,
             has_spoiler: false,
         });
 
@@ -619,6 +667,19 @@ mod tests {
         assert_eq!(expected_json, actual_json);
     }
 
+    #[test]
+    fn photo_show_caption_above_media_serialize() {
+        let expected_json = r#"{"type":"photo","media":"123456","caption":"above","show_caption_above_media":true}"#;
+        let photo = InputMedia::Photo(
+            InputMediaPhoto::new(InputFile::file_id("123456"))
+                .caption("above")
+                .show_caption_above_media(),
+        );
+
+        let actual_json = serde_json::to_string(&photo).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+
     #[test]
     fn audio_serialize() {
         let expected_json = r#"{"type":"audio","media":"123456"}"#;
@@ -324,10 +324,12 @@ pub struct InputMessageContentInvoice {
     /// the user, use for your internal processes.
     pub payload: String,
 
-    /// Payment provider token, obtained via [@Botfather]
+    /// Payment provider token, obtained via [@Botfather]. Pass `None` for
+    /// payments in [Telegram Stars].
     ///
     /// [@Botfather]: https://t.me/Botfather
-    pub provider_token: String,
+    /// [Telegram Stars]: https://t.me/BotNews/90
+    pub provider_token: Option<String>,
 
     /// Three-letter ISO 4217 currency code, see [more on currencies]. Pass
     /// `XTR` for payments in [Telegram Stars].
@@ -337,7 +339,8 @@ pub struct InputMessageContentInvoice {
     pub currency: String,
 
     /// Price breakdown, list of components (e.g. product price, tax, discount,
-    /// delivery cost, delivery tax, bonus, etc.)
+    /// delivery cost, delivery tax, bonus, etc.). Must contain exactly one
+    /// item for payments in Telegram Stars.
     pub prices: Vec<LabeledPrice>,
 
     /// ----The maximum accepted amount for tips in the smallest units of the
@@ -398,11 +401,10 @@ pub struct InputMessageContentInvoice {
 }
 
 impl InputMessageContentInvoice {
-    pub fn new<T, D, PA, PT, C, PR>(
+    pub fn new<T, D, PA, C, PR>(
         title: T,
         description: D,
         payload: PA,
-        provider_token: PT,
         currency: C,
         prices: PR,
     ) -> Self
@@ -410,14 +412,12 @@ impl InputMessageContentInvoice {
         T: Into<String>,
         D: Into<String>,
         PA: Into<String>,
-        PT: Into<String>,
         C: Into<String>,
         PR: IntoIterator<Item = LabeledPrice>,
     {
         let title = title.into();
         let description = description.into();
         let payload = payload.into();
-        let provider_token = provider_token.into();
         let currency = currency.into();
         let prices = prices.into_iter().collect();
 
@@ -425,7 +425,7 @@ impl InputMessageContentInvoice {
             title,
             description,
             payload,
-            provider_token,
+            provider_token: None,
             currency,
             prices,
             max_tip_amount: None,
@@ -473,7 +473,7 @@ impl InputMessageContentInvoice {
     where
         T: Into<String>,
     {
-        self.provider_token = val.into();
+        self.provider_token = Some(val.into());
         self
     }
 
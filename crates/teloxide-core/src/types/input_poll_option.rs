@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{MessageEntity, ParseMode};
+
+/// This object contains information about one answer option in a poll to be
+/// sent.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputpolloption).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct InputPollOption {
+    /// Option text, 1-100 characters.
+    pub text: String,
+
+    /// Mode for parsing entities in the text. See [formatting options] for
+    /// more details. Currently, only custom emoji entities are allowed.
+    ///
+    /// [formatting options]: https://core.telegram.org/bots/api#formatting-options
+    pub text_parse_mode: Option<ParseMode>,
+
+    /// A JSON-serialized list of special entities that appear in the poll
+    /// option text. It can be specified instead of `text_parse_mode`.
+    pub text_entities: Option<Vec<MessageEntity>>,
+}
+
+impl InputPollOption {
+    pub fn new<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { text: text.into(), text_parse_mode: None, text_entities: None }
+    }
+
+    /// Setter for the `text_parse_mode` field
+    #[must_use]
+    pub fn text_parse_mode(mut self, val: ParseMode) -> Self {
+        self.text_parse_mode = Some(val);
+        self
+    }
+
+    /// Setter for the `text_entities` field
+    #[must_use]
+    pub fn text_entities<E>(mut self, val: E) -> Self
+    where
+        E: IntoIterator<Item = MessageEntity>,
+    {
+        self.text_entities = Some(val.into_iter().collect());
+        self
+    }
+}
+
+impl From<String> for InputPollOption {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&str> for InputPollOption {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let option = InputPollOption::new("Option");
+        assert_eq!(serde_json::to_string(&option).unwrap(), r#"{"text":"Option"}"#);
+
+        let option = InputPollOption::new("*Option*").text_parse_mode(ParseMode::MarkdownV2);
+        assert_eq!(
+            serde_json::to_string(&option).unwrap(),
+            r#"{"text":"*Option*","text_parse_mode":"MarkdownV2"}"#
+        );
+    }
+}
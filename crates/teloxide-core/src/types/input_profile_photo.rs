@@ -0,0 +1,103 @@
+use serde::Serialize;
+
+use crate::types::InputFile;
+
+/// This object describes a profile photo to set.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputprofilephoto).
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum InputProfilePhoto {
+    Static(InputProfilePhotoStatic),
+    Animated(InputProfilePhotoAnimated),
+}
+
+/// A static profile photo in the .JPG format.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputprofilephotostatic).
+#[derive(Clone, Debug, Serialize)]
+pub struct InputProfilePhotoStatic {
+    /// The static profile photo. Profile photos can't be reused and can only
+    /// be uploaded as a new file.
+    pub photo: InputFile,
+}
+
+/// An animated profile photo in the MPEG4 format.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputprofilephotoanimated).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct InputProfilePhotoAnimated {
+    /// The animated profile photo. Profile photos can't be reused and can only
+    /// be uploaded as a new file.
+    pub animation: InputFile,
+
+    /// Timestamp in seconds of the frame that will be used as the static
+    /// profile photo. Defaults to 0.0.
+    pub main_frame_timestamp: Option<f64>,
+}
+
+impl InputProfilePhotoStatic {
+    pub const fn new(photo: InputFile) -> Self {
+        Self { photo }
+    }
+}
+
+impl InputProfilePhotoAnimated {
+    pub const fn new(animation: InputFile) -> Self {
+        Self { animation, main_frame_timestamp: None }
+    }
+
+    pub const fn main_frame_timestamp(mut self, val: f64) -> Self {
+        self.main_frame_timestamp = Some(val);
+        self
+    }
+}
+
+impl From<InputProfilePhotoStatic> for InputProfilePhoto {
+    fn from(photo: InputProfilePhotoStatic) -> Self {
+        Self::Static(photo)
+    }
+}
+
+impl From<InputProfilePhotoAnimated> for InputProfilePhoto {
+    fn from(animation: InputProfilePhotoAnimated) -> Self {
+        Self::Animated(animation)
+    }
+}
+
+impl InputProfilePhoto {
+    /// Returns the file of this profile photo.
+    pub(crate) fn file(&self) -> &InputFile {
+        match self {
+            Self::Static(InputProfilePhotoStatic { photo }) => photo,
+            Self::Animated(InputProfilePhotoAnimated { animation, .. }) => animation,
+        }
+    }
+
+    /// Returns the file of this profile photo.
+    pub(crate) fn file_mut(&mut self) -> &mut InputFile {
+        match self {
+            Self::Static(InputProfilePhotoStatic { photo }) => photo,
+            Self::Animated(InputProfilePhotoAnimated { animation, .. }) => animation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animated_serialize() {
+        let expected_json =
+            r#"{"type":"animated","animation":"123456","main_frame_timestamp":1.5}"#;
+        let photo: InputProfilePhoto = InputProfilePhotoAnimated::new(InputFile::file_id("123456"))
+            .main_frame_timestamp(1.5)
+            .into();
+
+        let actual_json = serde_json::to_string(&photo).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+}
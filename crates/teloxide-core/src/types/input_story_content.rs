@@ -0,0 +1,127 @@
+use serde::Serialize;
+
+use crate::types::InputFile;
+
+/// This object describes the content of a story to post.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputstorycontent).
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum InputStoryContent {
+    Photo(InputStoryContentPhoto),
+    Video(InputStoryContentVideo),
+}
+
+/// Describes a photo to post as a story.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputstorycontentphoto).
+#[derive(Clone, Debug, Serialize)]
+pub struct InputStoryContentPhoto {
+    /// The photo to post as a story. The photo must be of the size 1080x1920
+    /// and must not exceed 10 MB. The photo can't be reused and can only be
+    /// uploaded as a new file.
+    pub photo: InputFile,
+}
+
+/// Describes a video to post as a story.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inputstorycontentvideo).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct InputStoryContentVideo {
+    /// The video to post as a story. The video must be of the size 720x1280,
+    /// streamable, encoded with H.265 codec, with key frames added each
+    /// second in the MPEG4 format, and must not exceed 30 MB. The video can't
+    /// be reused and can only be uploaded as a new file.
+    pub video: InputFile,
+
+    /// Precise duration of the video in seconds; 0-60.
+    pub duration: Option<f64>,
+
+    /// Timestamp in seconds of the frame that will be used as the static cover
+    /// for the story. Defaults to 0.0.
+    pub cover_frame_timestamp: Option<f64>,
+
+    /// Pass `true` if the video has no sound.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub is_animation: bool,
+}
+
+impl InputStoryContentPhoto {
+    pub const fn new(photo: InputFile) -> Self {
+        Self { photo }
+    }
+}
+
+impl InputStoryContentVideo {
+    pub const fn new(video: InputFile) -> Self {
+        Self { video, duration: None, cover_frame_timestamp: None, is_animation: false }
+    }
+
+    pub const fn duration(mut self, val: f64) -> Self {
+        self.duration = Some(val);
+        self
+    }
+
+    pub const fn cover_frame_timestamp(mut self, val: f64) -> Self {
+        self.cover_frame_timestamp = Some(val);
+        self
+    }
+
+    /// Sets [`is_animation`] to `true`.
+    ///
+    /// [`is_animation`]: InputStoryContentVideo::is_animation
+    pub const fn is_animation(mut self) -> Self {
+        self.is_animation = true;
+        self
+    }
+}
+
+impl From<InputStoryContentPhoto> for InputStoryContent {
+    fn from(photo: InputStoryContentPhoto) -> Self {
+        Self::Photo(photo)
+    }
+}
+
+impl From<InputStoryContentVideo> for InputStoryContent {
+    fn from(video: InputStoryContentVideo) -> Self {
+        Self::Video(video)
+    }
+}
+
+impl InputStoryContent {
+    /// Returns the file of this story content.
+    pub(crate) fn file(&self) -> &InputFile {
+        match self {
+            Self::Photo(InputStoryContentPhoto { photo }) => photo,
+            Self::Video(InputStoryContentVideo { video, .. }) => video,
+        }
+    }
+
+    /// Returns the file of this story content.
+    pub(crate) fn file_mut(&mut self) -> &mut InputFile {
+        match self {
+            Self::Photo(InputStoryContentPhoto { photo }) => photo,
+            Self::Video(InputStoryContentVideo { video, .. }) => video,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn video_serialize() {
+        let expected_json =
+            r#"{"type":"video","video":"123456","duration":15.5,"is_animation":true}"#;
+        let content: InputStoryContent = InputStoryContentVideo::new(InputFile::file_id("123456"))
+            .duration(15.5)
+            .is_animation()
+            .into();
+
+        let actual_json = serde_json::to_string(&content).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+}
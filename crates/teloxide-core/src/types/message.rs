@@ -5,15 +5,16 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::types::{
-    Animation, Audio, BareChatId, BusinessConnectionId, Chat, ChatBoostAdded, ChatId, ChatShared,
-    Contact, Dice, Document, ExternalReplyInfo, ForumTopicClosed, ForumTopicCreated,
-    ForumTopicEdited, ForumTopicReopened, Game, GeneralForumTopicHidden, GeneralForumTopicUnhidden,
-    Giveaway, GiveawayCompleted, GiveawayCreated, GiveawayWinners, InlineKeyboardMarkup, Invoice,
-    LinkPreviewOptions, Location, MaybeInaccessibleMessage, MessageAutoDeleteTimerChanged,
-    MessageEntity, MessageEntityRef, MessageId, MessageOrigin, PassportData, PhotoSize, Poll,
-    ProximityAlertTriggered, Sticker, Story, SuccessfulPayment, TextQuote, ThreadId, True, User,
-    UsersShared, Venue, Video, VideoChatEnded, VideoChatParticipantsInvited, VideoChatScheduled,
-    VideoChatStarted, VideoNote, Voice, WebAppData, WriteAccessAllowed,
+    Animation, Audio, BareChatId, BusinessConnectionId, Chat, ChatBackground, ChatBoostAdded,
+    ChatId, ChatShared, Contact, Dice, Document, EffectId, ExternalReplyInfo, ForumTopicClosed,
+    ForumTopicCreated, ForumTopicEdited, ForumTopicReopened, Game, GeneralForumTopicHidden,
+    GeneralForumTopicUnhidden, Giveaway, GiveawayCompleted, GiveawayCreated, GiveawayWinners,
+    InlineKeyboardMarkup, Invoice, LinkPreviewOptions, Location, MaybeInaccessibleMessage,
+    MessageAutoDeleteTimerChanged, MessageEntity, MessageEntityRef, MessageId, MessageOrigin,
+    PaidMediaInfo, PassportData, PhotoSize, Poll, ProximityAlertTriggered, RefundedPayment,
+    Sticker, Story, SuccessfulPayment, TextQuote, ThreadId, True, User, UsersShared, Venue, Video,
+    VideoChatEnded, VideoChatParticipantsInvited, VideoChatScheduled, VideoChatStarted, VideoNote,
+    Voice, WebAppData, WriteAccessAllowed,
 };
 
 /// This object represents a message.
@@ -84,12 +85,14 @@ pub enum MessageKind {
     UsersShared(MessageUsersShared),
     Invoice(MessageInvoice),
     SuccessfulPayment(MessageSuccessfulPayment),
+    RefundedPayment(MessageRefundedPayment),
     ConnectedWebsite(MessageConnectedWebsite),
     WriteAccessAllowed(MessageWriteAccessAllowed),
     PassportData(MessagePassportData),
     Dice(MessageDice),
     ProximityAlertTriggered(MessageProximityAlertTriggered),
     ChatBoostAdded(MessageChatBoostAdded),
+    ChatBackgroundSet(MessageChatBackgroundSet),
     ForumTopicCreated(MessageForumTopicCreated),
     ForumTopicEdited(MessageForumTopicEdited),
     ForumTopicClosed(MessageForumTopicClosed),
@@ -170,6 +173,9 @@ pub struct MessageCommon {
     /// corresponding business account that is independent from any potential
     /// bot chat which might share the same identifier.
     pub business_connection_id: Option<BusinessConnectionId>,
+
+    /// Unique identifier of the message effect added to the message.
+    pub effect_id: Option<EffectId>,
 }
 
 #[serde_with::skip_serializing_none]
@@ -317,6 +323,16 @@ pub struct MessageSuccessfulPayment {
     pub successful_payment: SuccessfulPayment,
 }
 
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageRefundedPayment {
+    /// Message is a service message about a refunded payment, information
+    /// about the payment. [More about payments »].
+    ///
+    /// [More about payments »]: https://core.telegram.org/bots/api#payments
+    pub refunded_payment: RefundedPayment,
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageConnectedWebsite {
@@ -352,6 +368,7 @@ pub enum MediaKind {
     Game(MediaGame),
     Venue(MediaVenue),
     Location(MediaLocation),
+    PaidMedia(MediaPaidMedia),
     Photo(MediaPhoto),
     Poll(MediaPoll),
     Sticker(MediaSticker),
@@ -379,6 +396,10 @@ pub struct MediaAnimation {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub caption_entities: Vec<MessageEntity>,
 
+    /// `true`, if the caption must be shown above the message media.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_caption_above_media: bool,
+
     /// `true`, if the message media is covered by a spoiler animation.
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub has_media_spoiler: bool,
@@ -447,6 +468,25 @@ pub struct MediaLocation {
     pub location: Location,
 }
 
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MediaPaidMedia {
+    /// Message contains paid media; information about the paid media.
+    pub paid_media: PaidMediaInfo,
+
+    /// Caption for the paid media, 0-1024 characters.
+    pub caption: Option<String>,
+
+    /// For messages with a caption, special entities like usernames, URLs,
+    /// bot commands, etc. that appear in the caption.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub caption_entities: Vec<MessageEntity>,
+
+    /// `true`, if the caption must be shown above the message media.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_caption_above_media: bool,
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MediaPhoto {
@@ -461,6 +501,10 @@ pub struct MediaPhoto {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub caption_entities: Vec<MessageEntity>,
 
+    /// `true`, if the caption must be shown above the message media.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_caption_above_media: bool,
+
     /// `true`, if the message media is covered by a spoiler animation.
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub has_media_spoiler: bool,
@@ -522,6 +566,10 @@ pub struct MediaVideo {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub caption_entities: Vec<MessageEntity>,
 
+    /// `true`, if the caption must be shown above the message media.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_caption_above_media: bool,
+
     /// `true`, if the message media is covered by a spoiler animation.
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub has_media_spoiler: bool,
@@ -584,6 +632,13 @@ pub struct MessageChatBoostAdded {
     pub boost_added: ChatBoostAdded,
 }
 
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageChatBackgroundSet {
+    /// Service message: chat background set.
+    pub chat_background_set: ChatBackground,
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageWriteAccessAllowed {
@@ -714,25 +769,26 @@ mod getters {
     use std::ops::Deref;
 
     use crate::types::{
-        self, message::MessageKind::*, Chat, ChatId, ChatMigration, LinkPreviewOptions,
+        self, message::MessageKind::*, Chat, ChatId, ChatMigration, EffectId, LinkPreviewOptions,
         MaybeInaccessibleMessage, MediaAnimation, MediaAudio, MediaContact, MediaDocument,
-        MediaGame, MediaKind, MediaLocation, MediaPhoto, MediaPoll, MediaSticker, MediaStory,
-        MediaText, MediaVenue, MediaVideo, MediaVideoNote, MediaVoice, Message,
+        MediaGame, MediaKind, MediaLocation, MediaPaidMedia, MediaPhoto, MediaPoll, MediaSticker,
+        MediaStory, MediaText, MediaVenue, MediaVideo, MediaVideoNote, MediaVoice, Message,
         MessageChannelChatCreated, MessageChatShared, MessageCommon, MessageConnectedWebsite,
         MessageDeleteChatPhoto, MessageDice, MessageEntity, MessageGroupChatCreated, MessageId,
         MessageInvoice, MessageLeftChatMember, MessageNewChatMembers, MessageNewChatPhoto,
         MessageNewChatTitle, MessageOrigin, MessagePassportData, MessagePinned,
-        MessageProximityAlertTriggered, MessageSuccessfulPayment, MessageSupergroupChatCreated,
-        MessageUsersShared, MessageVideoChatParticipantsInvited, PhotoSize, Story, TextQuote, User,
+        MessageProximityAlertTriggered, MessageRefundedPayment, MessageSuccessfulPayment,
+        MessageSupergroupChatCreated, MessageUsersShared, MessageVideoChatParticipantsInvited,
+        PhotoSize, Story, TextQuote, User,
     };
 
     use super::{
-        MessageChatBoostAdded, MessageForumTopicClosed, MessageForumTopicCreated,
-        MessageForumTopicEdited, MessageForumTopicReopened, MessageGeneralForumTopicHidden,
-        MessageGeneralForumTopicUnhidden, MessageGiveaway, MessageGiveawayCompleted,
-        MessageGiveawayCreated, MessageGiveawayWinners, MessageMessageAutoDeleteTimerChanged,
-        MessageVideoChatEnded, MessageVideoChatScheduled, MessageVideoChatStarted,
-        MessageWebAppData, MessageWriteAccessAllowed,
+        MessageChatBackgroundSet, MessageChatBoostAdded, MessageForumTopicClosed,
+        MessageForumTopicCreated, MessageForumTopicEdited, MessageForumTopicReopened,
+        MessageGeneralForumTopicHidden, MessageGeneralForumTopicUnhidden, MessageGiveaway,
+        MessageGiveawayCompleted, MessageGiveawayCreated, MessageGiveawayWinners,
+        MessageMessageAutoDeleteTimerChanged, MessageVideoChatEnded, MessageVideoChatScheduled,
+        MessageVideoChatStarted, MessageWebAppData, MessageWriteAccessAllowed,
     };
 
     /// Getters for [Message] fields from [telegram docs].
@@ -755,6 +811,14 @@ mod getters {
             }
         }
 
+        #[must_use]
+        pub fn effect_id(&self) -> Option<&EffectId> {
+            match &self.kind {
+                Common(MessageCommon { effect_id, .. }) => effect_id.as_ref(),
+                _ => None,
+            }
+        }
+
         #[deprecated(since = "0.13.0", note = "use `.sender_chat` field instead")]
         #[must_use]
         pub fn sender_chat(&self) -> Option<&Chat> {
@@ -945,6 +1009,10 @@ mod getters {
                     media_kind: MediaKind::Document(MediaDocument { caption_entities, .. }),
                     ..
                 })
+                | Common(MessageCommon {
+                    media_kind: MediaKind::PaidMedia(MediaPaidMedia { caption_entities, .. }),
+                    ..
+                })
                 | Common(MessageCommon {
                     media_kind: MediaKind::Photo(MediaPhoto { caption_entities, .. }),
                     ..
@@ -980,6 +1048,7 @@ mod getters {
                     | MediaKind::Game(_)
                     | MediaKind::Venue(_)
                     | MediaKind::Location(_)
+                    | MediaKind::PaidMedia(_)
                     | MediaKind::Poll(_)
                     | MediaKind::Sticker(_)
                     | MediaKind::Story(_)
@@ -991,6 +1060,28 @@ mod getters {
                 .unwrap_or(false)
         }
 
+        /// Returns `true` if the caption must be shown above the message
+        /// media.
+        ///
+        /// Getter for [`MediaAnimation::show_caption_above_media`],
+        /// [`MediaPaidMedia::show_caption_above_media`],
+        /// [`MediaPhoto::show_caption_above_media`] and
+        /// [`MediaVideo::show_caption_above_media`].
+        #[must_use]
+        pub fn show_caption_above_media(&self) -> bool {
+            match &self.kind {
+                Common(MessageCommon {
+                    media_kind:
+                        MediaKind::Animation(MediaAnimation { show_caption_above_media, .. })
+                        | MediaKind::PaidMedia(MediaPaidMedia { show_caption_above_media, .. })
+                        | MediaKind::Photo(MediaPhoto { show_caption_above_media, .. })
+                        | MediaKind::Video(MediaVideo { show_caption_above_media, .. }),
+                    ..
+                }) => *show_caption_above_media,
+                _ => false,
+            }
+        }
+
         #[must_use]
         pub fn audio(&self) -> Option<&types::Audio> {
             match &self.kind {
@@ -1035,6 +1126,17 @@ mod getters {
             }
         }
 
+        #[must_use]
+        pub fn paid_media(&self) -> Option<&types::PaidMediaInfo> {
+            match &self.kind {
+                Common(MessageCommon {
+                    media_kind: MediaKind::PaidMedia(MediaPaidMedia { paid_media, .. }),
+                    ..
+                }) => Some(paid_media),
+                _ => None,
+            }
+        }
+
         #[must_use]
         pub fn photo(&self) -> Option<&[PhotoSize]> {
             match &self.kind {
@@ -1109,6 +1211,7 @@ mod getters {
                         MediaKind::Animation(MediaAnimation { caption, .. })
                         | MediaKind::Audio(MediaAudio { caption, .. })
                         | MediaKind::Document(MediaDocument { caption, .. })
+                        | MediaKind::PaidMedia(MediaPaidMedia { caption, .. })
                         | MediaKind::Photo(MediaPhoto { caption, .. })
                         | MediaKind::Video(MediaVideo { caption, .. })
                         | MediaKind::Voice(MediaVoice { caption, .. }),
@@ -1344,6 +1447,16 @@ mod getters {
             }
         }
 
+        #[must_use]
+        pub fn refunded_payment(&self) -> Option<&types::RefundedPayment> {
+            match &self.kind {
+                RefundedPayment(MessageRefundedPayment { refunded_payment }) => {
+                    Some(refunded_payment)
+                }
+                _ => None,
+            }
+        }
+
         #[must_use]
         pub fn connected_website(&self) -> Option<&str> {
             match &self.kind {
@@ -1414,6 +1527,16 @@ mod getters {
             }
         }
 
+        #[must_use]
+        pub fn chat_background_set(&self) -> Option<&types::ChatBackground> {
+            match &self.kind {
+                ChatBackgroundSet(MessageChatBackgroundSet { chat_background_set }) => {
+                    Some(chat_background_set)
+                }
+                _ => None,
+            }
+        }
+
         #[must_use]
         pub fn forum_topic_created(&self) -> Option<&types::ForumTopicCreated> {
             match &self.kind {
@@ -2767,4 +2890,152 @@ mod tests {
             &ChatBoostAdded { boost_count: 4 }
         )
     }
+
+    #[test]
+    fn chat_background_set() {
+        let json = r#"{
+            "message_id": 30,
+            "from": {
+                "id": 1459074222,
+                "is_bot": false,
+                "first_name": "shadowchain",
+                "username": "shdwchn10"
+            },
+            "chat": {
+                "id": 1459074222,
+                "first_name": "shadowchain",
+                "username": "shdwchn10",
+                "type": "private"
+            },
+            "date": 1721162702,
+            "chat_background_set": {
+                "type": {
+                    "type": "chat_theme",
+                    "theme_name": "🏠"
+                }
+            }
+        }"#;
+        let message: Message = from_str(json).unwrap();
+        assert_eq!(
+            message.chat_background_set().expect("Failed to get ChatBackground from Message!"),
+            &ChatBackground {
+                kind: BackgroundType::ChatTheme(BackgroundTypeChatTheme {
+                    theme_name: "🏠".to_owned()
+                })
+            }
+        )
+    }
+
+    #[test]
+    fn refunded_payment() {
+        let json = r#"{
+            "message_id": 31,
+            "from": {
+                "id": 1459074222,
+                "is_bot": false,
+                "first_name": "shadowchain",
+                "username": "shdwchn10"
+            },
+            "chat": {
+                "id": 1459074222,
+                "first_name": "shadowchain",
+                "username": "shdwchn10",
+                "type": "private"
+            },
+            "date": 1721162702,
+            "refunded_payment": {
+                "currency": "XTR",
+                "total_amount": 1,
+                "invoice_payload": "payload",
+                "telegram_payment_charge_id": "stxabc"
+            }
+        }"#;
+        let message: Message = from_str(json).unwrap();
+        assert_eq!(
+            message.refunded_payment().expect("Failed to get RefundedPayment from Message!"),
+            &RefundedPayment {
+                currency: "XTR".to_owned(),
+                total_amount: 1,
+                invoice_payload: "payload".to_owned(),
+                telegram_payment_charge_id: "stxabc".to_owned(),
+                provider_payment_charge_id: None,
+            }
+        )
+    }
+
+    #[test]
+    fn paid_media() {
+        let json = r#"{
+            "message_id": 29,
+            "sender_chat": {
+                "id": -1002236736395,
+                "title": "Test",
+                "type": "channel"
+            },
+            "chat": {
+                "id": -1002236736395,
+                "title": "Test",
+                "type": "channel"
+            },
+            "date": 1721162702,
+            "paid_media": {
+                "star_count": 10,
+                "paid_media": [
+                    {
+                        "type": "preview",
+                        "width": 1280,
+                        "height": 720
+                    }
+                ]
+            },
+            "caption": "Exclusive"
+        }"#;
+        let message: Message = from_str(json).unwrap();
+        assert_eq!(
+            message.paid_media().expect("Failed to get PaidMediaInfo from Message!"),
+            &PaidMediaInfo {
+                star_count: 10,
+                paid_media: vec![PaidMedia::Preview(PaidMediaPreview {
+                    width: Some(1280),
+                    height: Some(720),
+                    duration: None
+                })]
+            }
+        );
+        assert_eq!(message.caption(), Some("Exclusive"));
+    }
+
+    #[test]
+    fn effect_id_and_caption_above_media() {
+        let json = r#"{
+            "message_id": 30,
+            "from": {
+                "id": 1459074222,
+                "is_bot": false,
+                "first_name": "shadowchain"
+            },
+            "chat": {
+                "id": 1459074222,
+                "first_name": "shadowchain",
+                "type": "private"
+            },
+            "date": 1721162702,
+            "photo": [
+                {
+                    "file_id": "AgACAgIAAxkBAAIBWWZ3",
+                    "file_unique_id": "AQADx9gxG",
+                    "file_size": 1033,
+                    "width": 90,
+                    "height": 51
+                }
+            ],
+            "caption": "above",
+            "show_caption_above_media": true,
+            "effect_id": "5104841245755180586"
+        }"#;
+        let message: Message = from_str(json).unwrap();
+        assert_eq!(message.effect_id(), Some(&EffectId("5104841245755180586".to_owned())));
+        assert!(message.show_caption_above_media());
+        assert_eq!(message.caption(), Some("above"));
+    }
 }
@@ -0,0 +1,166 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Gift, MessageEntity, UniqueGift, User};
+
+/// This object describes a gift received and owned by a user or a chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#ownedgift).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
+pub enum OwnedGift {
+    Regular(OwnedGiftRegular),
+    Unique(OwnedGiftUnique),
+}
+
+/// Describes a regular gift owned by a user or a chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#ownedgiftregular).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OwnedGiftRegular {
+    /// Information about the regular gift.
+    pub gift: Gift,
+
+    /// Unique identifier of the gift for the bot; for gifts received on
+    /// behalf of business accounts only.
+    pub owned_gift_id: Option<String>,
+
+    /// Sender of the gift if it is a known user.
+    pub sender_user: Option<User>,
+
+    /// Date the gift was sent.
+    #[serde(with = "crate::types::serde_date_from_unix_timestamp")]
+    pub send_date: DateTime<Utc>,
+
+    /// Text of the message that was added to the gift.
+    pub text: Option<String>,
+
+    /// Special entities that appear in the text.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entities: Vec<MessageEntity>,
+
+    /// `true`, if the sender and gift text are shown only to the gift
+    /// receiver; otherwise, everyone will be able to see them.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_private: bool,
+
+    /// `true`, if the gift is displayed on the account's profile page; for
+    /// gifts received on behalf of business accounts only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_saved: bool,
+
+    /// `true`, if the gift can be upgraded to a unique gift; for gifts
+    /// received on behalf of business accounts only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub can_be_upgraded: bool,
+
+    /// `true`, if the gift was refunded and isn't available anymore.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub was_refunded: bool,
+
+    /// Number of Telegram Stars that can be claimed by the receiver instead of
+    /// the gift; omitted if the gift cannot be converted to Telegram Stars.
+    pub convert_star_count: Option<u32>,
+
+    /// Number of Telegram Stars that were paid by the sender for the ability
+    /// to upgrade the gift.
+    pub prepaid_upgrade_star_count: Option<u32>,
+}
+
+/// Describes a unique gift received and owned by a user or a chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#ownedgiftunique).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OwnedGiftUnique {
+    /// Information about the unique gift.
+    pub gift: UniqueGift,
+
+    /// Unique identifier of the received gift for the bot; for gifts received
+    /// on behalf of business accounts only.
+    pub owned_gift_id: Option<String>,
+
+    /// Sender of the gift if it is a known user.
+    pub sender_user: Option<User>,
+
+    /// Date the gift was sent.
+    #[serde(with = "crate::types::serde_date_from_unix_timestamp")]
+    pub send_date: DateTime<Utc>,
+
+    /// `true`, if the gift is displayed on the account's profile page; for
+    /// gifts received on behalf of business accounts only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_saved: bool,
+
+    /// `true`, if the gift can be transferred to another owner; for gifts
+    /// received on behalf of business accounts only.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub can_be_transferred: bool,
+
+    /// Number of Telegram Stars that must be paid to transfer the gift;
+    /// omitted if the bot cannot transfer the gift.
+    pub transfer_star_count: Option<u32>,
+}
+
+impl OwnedGift {
+    /// Returns the unique identifier of the gift for the bot, if any.
+    #[must_use]
+    pub fn owned_gift_id(&self) -> Option<&str> {
+        match self {
+            Self::Regular(gift) => gift.owned_gift_id.as_deref(),
+            Self::Unique(gift) => gift.owned_gift_id.as_deref(),
+        }
+    }
+
+    /// Returns the sender of the gift, if it is a known user.
+    #[must_use]
+    pub fn sender_user(&self) -> Option<&User> {
+        match self {
+            Self::Regular(gift) => gift.sender_user.as_ref(),
+            Self::Unique(gift) => gift.sender_user.as_ref(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_regular() {
+        let data = r#"
+        {
+            "type": "regular",
+            "gift": {
+                "id": "5170145012310081615",
+                "sticker": {
+                    "width": 512,
+                    "height": 512,
+                    "emoji": "💝",
+                    "is_animated": true,
+                    "is_video": false,
+                    "type": "regular",
+                    "file_id": "CAACAgIAAxUAAWcZLtjbnzOm1b9skuybz9yb5aF6AAKHAgACjTpJS5AAAcPPfLIgyDYE",
+                    "file_unique_id": "AgADhwIAAo06SUs",
+                    "file_size": 16639
+                },
+                "star_count": 15
+            },
+            "owned_gift_id": "42",
+            "send_date": 1745000000,
+            "is_saved": true,
+            "convert_star_count": 13
+        }
+        "#;
+        let gift: OwnedGift = serde_json::from_str(data).unwrap();
+
+        assert_eq!(gift.owned_gift_id(), Some("42"));
+        assert_eq!(gift.sender_user(), None);
+        let OwnedGift::Regular(regular) = gift else { panic!("expected a regular gift") };
+        assert!(regular.is_saved);
+        assert_eq!(regular.convert_star_count, Some(13));
+    }
+}
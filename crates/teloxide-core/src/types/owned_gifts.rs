@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::OwnedGift;
+
+/// Contains the list of gifts received and owned by a user or a chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#ownedgifts).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OwnedGifts {
+    /// The total number of gifts owned by the user or the chat.
+    pub total_count: u32,
+
+    /// The list of gifts.
+    pub gifts: Vec<OwnedGift>,
+
+    /// Offset for the next request. If empty, then there are no more results.
+    pub next_offset: Option<String>,
+}
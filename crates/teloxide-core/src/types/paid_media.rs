@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{PhotoSize, Video};
+
+/// This object describes paid media.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmedia).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
+pub enum PaidMedia {
+    Preview(PaidMediaPreview),
+    Photo(PaidMediaPhoto),
+    Video(PaidMediaVideo),
+}
+
+/// The paid media isn't available before the payment.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaidMediaPreview {
+    /// Media width as defined by the sender.
+    pub width: Option<u32>,
+
+    /// Media height as defined by the sender.
+    pub height: Option<u32>,
+
+    /// Duration of the media in seconds as defined by the sender.
+    pub duration: Option<u32>,
+}
+
+/// The paid media is a photo.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaidMediaPhoto {
+    /// The photo.
+    pub photo: Vec<PhotoSize>,
+}
+
+/// The paid media is a video.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaidMediaVideo {
+    /// The video.
+    pub video: Video,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_preview() {
+        let data = r#"{"type": "preview", "width": 1280, "height": 720, "duration": 17}"#;
+        assert_eq!(
+            serde_json::from_str::<PaidMedia>(data).unwrap(),
+            PaidMedia::Preview(PaidMediaPreview {
+                width: Some(1280),
+                height: Some(720),
+                duration: Some(17)
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_photo() {
+        let data = r#"
+        {
+            "type": "photo",
+            "photo": [
+                {
+                    "file_id": "AgADAgADyqoxG2g8aEsu_KjjVsGF4-zetw8ABAEAAwIAA20AA_8QAwABFgQ",
+                    "file_unique_id": "AQADzN63DwAE_xADAAE",
+                    "file_size": 1234,
+                    "width": 90,
+                    "height": 90
+                }
+            ]
+        }
+        "#;
+        assert!(matches!(serde_json::from_str::<PaidMedia>(data).unwrap(), PaidMedia::Photo(_)));
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::PaidMedia;
+
+/// Describes the paid media added to a message.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmediainfo).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaidMediaInfo {
+    /// The number of Telegram Stars that must be paid to buy access to the
+    /// media.
+    pub star_count: u32,
+
+    /// Information about the paid media.
+    pub paid_media: Vec<PaidMedia>,
+}
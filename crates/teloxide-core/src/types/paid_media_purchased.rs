@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::User;
+
+/// This object contains information about a paid media purchase.
+///
+/// [The official docs](https://core.telegram.org/bots/api#paidmediapurchased).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaidMediaPurchased {
+    /// User who purchased the media.
+    pub from: User,
+
+    /// Bot-specified paid media payload.
+    pub paid_media_payload: String,
+}
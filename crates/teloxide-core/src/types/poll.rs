@@ -15,6 +15,10 @@ pub struct Poll {
     /// Poll question, 1-300 characters.
     pub question: String,
 
+    /// Special entities that appear in the question. Currently, only custom
+    /// emoji entities are allowed in poll questions.
+    pub question_entities: Option<Vec<MessageEntity>>,
+
     /// List of poll options.
     pub options: Vec<PollOption>,
 
@@ -58,11 +62,16 @@ pub struct Poll {
 /// This object contains information about one answer option in a poll.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#polloption).
+#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct PollOption {
     /// Option text, 1-100 characters.
     pub text: String,
 
+    /// Special entities that appear in the option text. Currently, only
+    /// custom emoji entities are allowed in poll option texts.
+    pub text_entities: Option<Vec<MessageEntity>>,
+
     /// Number of users that voted for this option.
     pub voter_count: u32,
 }
@@ -122,4 +131,35 @@ mod tests {
         "#;
         serde_json::from_str::<Poll>(data).unwrap();
     }
+
+    #[test]
+    fn deserialize_with_entities() {
+        let data = r#"
+        {
+            "allows_multiple_answers": false,
+            "id": "5377643193141559299",
+            "is_anonymous": true,
+            "is_closed": false,
+            "options": [
+                {
+                    "text": "👍 yes",
+                    "text_entities": [{"type": "custom_emoji", "offset": 0, "length": 2, "custom_emoji_id": "5368324170671202286"}],
+                    "voter_count": 1
+                },
+                {
+                    "text": "no",
+                    "voter_count": 0
+                }
+            ],
+            "question": "👀 Agree?",
+            "question_entities": [{"type": "custom_emoji", "offset": 0, "length": 2, "custom_emoji_id": "5368324170671202286"}],
+            "total_voter_count": 1,
+            "type": "regular"
+        }
+        "#;
+        let poll = serde_json::from_str::<Poll>(data).unwrap();
+        assert_eq!(poll.question_entities.map(|e| e.len()), Some(1));
+        assert_eq!(poll.options[0].text_entities.as_ref().map(Vec::len), Some(1));
+        assert_eq!(poll.options[1].text_entities, None);
+    }
 }
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Describes an inline message to be sent by a user of a Mini App.
+///
+/// [The official docs](https://core.telegram.org/bots/api#preparedinlinemessage).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PreparedInlineMessage {
+    /// Unique identifier of the prepared message.
+    pub id: String,
+
+    /// Expiration date of the prepared message. Expired prepared messages can
+    /// no longer be used.
+    #[serde(with = "crate::types::serde_date_from_unix_timestamp")]
+    pub expiration_date: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"{"id": "4Xb2Fq", "expiration_date": 1735689600}"#;
+        let expected = PreparedInlineMessage {
+            id: "4Xb2Fq".to_owned(),
+            expiration_date: DateTime::from_timestamp(1735689600, 0).unwrap(),
+        };
+
+        assert_eq!(serde_json::from_str::<PreparedInlineMessage>(data).unwrap(), expected);
+    }
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+/// This object describes the state of a revenue withdrawal operation.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "type")]
+pub enum RevenueWithdrawalState {
+    /// The withdrawal is in progress.
+    Pending,
+    Succeeded(RevenueWithdrawalStateSucceeded),
+    /// The withdrawal failed and the transaction was refunded.
+    Failed,
+}
+
+/// The withdrawal succeeded.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RevenueWithdrawalStateSucceeded {
+    /// Date the withdrawal was completed in Unix time.
+    #[serde(with = "crate::types::serde_date_from_unix_timestamp")]
+    pub date: DateTime<Utc>,
+
+    /// An HTTPS URL that can be used to see transaction details.
+    pub url: Url,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let data = r#"{"type": "failed"}"#;
+        assert_eq!(
+            serde_json::from_str::<RevenueWithdrawalState>(data).unwrap(),
+            RevenueWithdrawalState::Failed
+        );
+
+        let data = r#"{"type": "succeeded", "date": 1721399700, "url": "https://fragment.com/"}"#;
+        assert!(matches!(
+            serde_json::from_str::<RevenueWithdrawalState>(data).unwrap(),
+            RevenueWithdrawalState::Succeeded(RevenueWithdrawalStateSucceeded { .. })
+        ));
+    }
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::TransactionPartner;
+
+/// Describes a Telegram Star transaction.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StarTransaction {
+    /// Unique identifier of the transaction. Coincides with the identifier of
+    /// the original transaction for refund transactions. Coincides with
+    /// [`SuccessfulPayment::telegram_payment_charge_id`] for successful
+    /// incoming payments from users.
+    ///
+    /// [`SuccessfulPayment::telegram_payment_charge_id`]: crate::types::SuccessfulPayment::telegram_payment_charge_id
+    pub id: String,
+
+    /// Number of Telegram Stars transferred by the transaction.
+    pub amount: u32,
+
+    /// Date the transaction was created in Unix time.
+    #[serde(with = "crate::types::serde_date_from_unix_timestamp")]
+    pub date: DateTime<Utc>,
+
+    /// Source of an incoming transaction (e.g., a user purchasing goods or
+    /// services, Fragment refunding a failed withdrawal). Only for incoming
+    /// transactions.
+    pub source: Option<TransactionPartner>,
+
+    /// Receiver of an outgoing transaction (e.g., a user for a purchase
+    /// refund, Fragment for a withdrawal). Only for outgoing transactions.
+    pub receiver: Option<TransactionPartner>,
+}
+
+impl StarTransaction {
+    /// Returns `true` if the transaction is an incoming one, e.g. a payment
+    /// from a user.
+    #[must_use]
+    pub fn is_incoming(&self) -> bool {
+        self.source.is_some()
+    }
+
+    /// Returns `true` if the transaction is an outgoing one, e.g. a refund or
+    /// a withdrawal.
+    #[must_use]
+    pub fn is_outgoing(&self) -> bool {
+        self.receiver.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_incoming() {
+        let data = r#"
+        {
+            "id": "stxSomeTransactionId",
+            "amount": 50,
+            "date": 1721399621,
+            "source": {
+                "type": "user",
+                "user": {
+                    "id": 1459074222,
+                    "is_bot": false,
+                    "first_name": "shadowchain",
+                    "username": "shdwchn10",
+                    "language_code": "en",
+                    "is_premium": true
+                },
+                "invoice_payload": "sword"
+            }
+        }
+        "#;
+        let transaction = serde_json::from_str::<StarTransaction>(data).unwrap();
+        assert!(transaction.is_incoming());
+        assert!(!transaction.is_outgoing());
+    }
+
+    #[test]
+    fn deserialize_outgoing() {
+        let data = r#"
+        {
+            "id": "withdrawal",
+            "amount": 1000,
+            "date": 1721399621,
+            "receiver": {
+                "type": "fragment",
+                "withdrawal_state": {
+                    "type": "succeeded",
+                    "date": 1721399700,
+                    "url": "https://fragment.com/"
+                }
+            }
+        }
+        "#;
+        let transaction = serde_json::from_str::<StarTransaction>(data).unwrap();
+        assert!(transaction.is_outgoing());
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::StarTransaction;
+
+/// Contains a list of Telegram Star transactions.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StarTransactions {
+    /// The list of transactions.
+    pub transactions: Vec<StarTransaction>,
+}
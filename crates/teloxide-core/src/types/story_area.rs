@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::StoryAreaType;
+
+/// Describes a clickable area on a story media.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyarea).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryArea {
+    /// Position of the area.
+    pub position: StoryAreaPosition,
+
+    /// Type of the area.
+    #[serde(rename = "type")]
+    pub kind: StoryAreaType,
+}
+
+/// Describes the position of a clickable area within a story.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareaposition).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaPosition {
+    /// The abscissa of the area's center, as a percentage of the media width.
+    pub x_percentage: f64,
+
+    /// The ordinate of the area's center, as a percentage of the media height.
+    pub y_percentage: f64,
+
+    /// The width of the area's rectangle, as a percentage of the media width.
+    pub width_percentage: f64,
+
+    /// The height of the area's rectangle, as a percentage of the media
+    /// height.
+    pub height_percentage: f64,
+
+    /// The clockwise rotation angle of the rectangle, in degrees; 0-360.
+    pub rotation_angle: f64,
+
+    /// The radius of the rectangle corner rounding, as a percentage of the
+    /// media width.
+    pub corner_radius_percentage: f64,
+}
+
+impl StoryArea {
+    pub fn new<T>(position: StoryAreaPosition, kind: T) -> Self
+    where
+        T: Into<StoryAreaType>,
+    {
+        Self { position, kind: kind.into() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::StoryAreaTypeLink;
+
+    #[test]
+    fn serialize() {
+        let area = StoryArea::new(
+            StoryAreaPosition {
+                x_percentage: 50.0,
+                y_percentage: 50.0,
+                width_percentage: 20.0,
+                height_percentage: 10.0,
+                rotation_angle: 0.0,
+                corner_radius_percentage: 2.0,
+            },
+            StoryAreaTypeLink { url: "https://example.com".parse().unwrap() },
+        );
+
+        let json = serde_json::to_string(&area).unwrap();
+        assert_eq!(
+            json,
+            r#"{"position":{"x_percentage":50.0,"y_percentage":50.0,"width_percentage":20.0,"height_percentage":10.0,"rotation_angle":0.0,"corner_radius_percentage":2.0},"type":{"type":"link","url":"https://example.com/"}}"#
+        );
+        assert_eq!(serde_json::from_str::<StoryArea>(&json).unwrap(), area);
+    }
+}
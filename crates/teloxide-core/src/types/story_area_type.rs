@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::types::{LocationAddress, ReactionType};
+
+/// Describes the type of a clickable area on a story.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatype).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum StoryAreaType {
+    Location(StoryAreaTypeLocation),
+    SuggestedReaction(StoryAreaTypeSuggestedReaction),
+    Link(StoryAreaTypeLink),
+    Weather(StoryAreaTypeWeather),
+    UniqueGift(StoryAreaTypeUniqueGift),
+}
+
+/// Describes a story area pointing to a location. Currently, a story can have
+/// up to 10 location areas.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatypelocation).
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaTypeLocation {
+    /// Location latitude in degrees.
+    pub latitude: f64,
+
+    /// Location longitude in degrees.
+    pub longitude: f64,
+
+    /// Address of the location.
+    pub address: Option<LocationAddress>,
+}
+
+/// Describes a story area pointing to a suggested reaction. Currently, a story
+/// can have up to 5 suggested reaction areas.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatypesuggestedreaction).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaTypeSuggestedReaction {
+    /// Type of the reaction.
+    pub reaction_type: ReactionType,
+
+    /// `true`, if the reaction area has a dark background.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_dark: bool,
+
+    /// `true`, if reaction area corner is flipped.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_flipped: bool,
+}
+
+/// Describes a story area pointing to an HTTP or tg:// link. Currently, a
+/// story can have up to 3 link areas.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatypelink).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaTypeLink {
+    /// HTTP or tg:// URL to be opened when the area is clicked.
+    pub url: Url,
+}
+
+/// Describes a story area containing weather information. Currently, a story
+/// can have up to 3 weather areas.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatypeweather).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaTypeWeather {
+    /// Temperature, in degree Celsius.
+    pub temperature: f64,
+
+    /// Emoji representing the weather.
+    pub emoji: String,
+
+    /// A color of the area background in the ARGB format.
+    pub background_color: u32,
+}
+
+/// Describes a story area pointing to a unique gift. Currently, a story can
+/// have at most 1 unique gift area.
+///
+/// [The official docs](https://core.telegram.org/bots/api#storyareatypeuniquegift).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoryAreaTypeUniqueGift {
+    /// Unique name of the gift.
+    pub name: String,
+}
+
+impl From<StoryAreaTypeLocation> for StoryAreaType {
+    fn from(location: StoryAreaTypeLocation) -> Self {
+        Self::Location(location)
+    }
+}
+
+impl From<StoryAreaTypeSuggestedReaction> for StoryAreaType {
+    fn from(reaction: StoryAreaTypeSuggestedReaction) -> Self {
+        Self::SuggestedReaction(reaction)
+    }
+}
+
+impl From<StoryAreaTypeLink> for StoryAreaType {
+    fn from(link: StoryAreaTypeLink) -> Self {
+        Self::Link(link)
+    }
+}
+
+impl From<StoryAreaTypeWeather> for StoryAreaType {
+    fn from(weather: StoryAreaTypeWeather) -> Self {
+        Self::Weather(weather)
+    }
+}
+
+impl From<StoryAreaTypeUniqueGift> for StoryAreaType {
+    fn from(gift: StoryAreaTypeUniqueGift) -> Self {
+        Self::UniqueGift(gift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weather_background_color() {
+        let weather = StoryAreaType::Weather(StoryAreaTypeWeather {
+            temperature: 21.5,
+            emoji: "☀️".to_owned(),
+            background_color: 0xFF112233,
+        });
+
+        let json = serde_json::to_string(&weather).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"weather","temperature":21.5,"emoji":"☀️","background_color":4279312947}"#
+        );
+        assert_eq!(serde_json::from_str::<StoryAreaType>(&json).unwrap(), weather);
+    }
+}
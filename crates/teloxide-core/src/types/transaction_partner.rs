@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{PaidMedia, RevenueWithdrawalState, User};
+
+/// This object describes the source of a transaction, or its recipient for
+/// outgoing transactions.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "type")]
+pub enum TransactionPartner {
+    Fragment(TransactionPartnerFragment),
+    User(TransactionPartnerUser),
+    /// Withdrawal to the Telegram Ads platform.
+    TelegramAds,
+    /// Transaction with an unknown source or recipient.
+    Other,
+}
+
+/// Describes a withdrawal transaction with Fragment.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TransactionPartnerFragment {
+    /// State of the transaction if the transaction is outgoing.
+    pub withdrawal_state: Option<RevenueWithdrawalState>,
+}
+
+/// Describes a transaction with a user.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TransactionPartnerUser {
+    /// Information about the user.
+    pub user: User,
+
+    /// Bot-specified invoice payload.
+    pub invoice_payload: Option<String>,
+
+    /// Information about the paid media bought by the user.
+    pub paid_media: Option<Vec<PaidMedia>>,
+}
+
+impl TransactionPartner {
+    #[must_use]
+    pub fn user(&self) -> Option<&User> {
+        match self {
+            Self::User(partner) => Some(&partner.user),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_user() {
+        let data = r#"
+        {
+            "type": "user",
+            "user": {
+                "id": 1459074222,
+                "is_bot": false,
+                "first_name": "shadowchain",
+                "username": "shdwchn10",
+                "language_code": "en",
+                "is_premium": true
+            }
+        }
+        "#;
+        let partner = serde_json::from_str::<TransactionPartner>(data).unwrap();
+        assert_eq!(partner.user().unwrap().first_name, "shadowchain");
+    }
+
+    #[test]
+    fn deserialize_fragment() {
+        let data = r#"{"type": "fragment", "withdrawal_state": {"type": "pending"}}"#;
+        assert_eq!(
+            serde_json::from_str::<TransactionPartner>(data).unwrap(),
+            TransactionPartner::Fragment(TransactionPartnerFragment {
+                withdrawal_state: Some(RevenueWithdrawalState::Pending)
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_other() {
+        let data = r#"{"type": "telegram_ads"}"#;
+        assert_eq!(
+            serde_json::from_str::<TransactionPartner>(data).unwrap(),
+            TransactionPartner::TelegramAds
+        );
+
+        let data = r#"{"type": "other"}"#;
+        assert_eq!(
+            serde_json::from_str::<TransactionPartner>(data).unwrap(),
+            TransactionPartner::Other
+        );
+    }
+}
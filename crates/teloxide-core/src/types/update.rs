@@ -5,8 +5,8 @@ use serde_json::Value;
 use crate::types::{
     BusinessConnection, BusinessMessagesDeleted, CallbackQuery, Chat, ChatBoostRemoved,
     ChatBoostUpdated, ChatJoinRequest, ChatMemberUpdated, ChosenInlineResult, InlineQuery, Message,
-    MessageReactionCountUpdated, MessageReactionUpdated, Poll, PollAnswer, PreCheckoutQuery,
-    ShippingQuery, User,
+    MessageReactionCountUpdated, MessageReactionUpdated, PaidMediaPurchased, Poll, PollAnswer,
+    PreCheckoutQuery, ShippingQuery, User,
 };
 
 /// This [object] represents an incoming update.
@@ -149,6 +149,10 @@ pub enum UpdateKind {
     /// chat to receive these updates.
     RemovedChatBoost(ChatBoostRemoved),
 
+    /// A user purchased paid media with a non-empty payload sent by the bot in
+    /// a non-channel chat.
+    PurchasedPaidMedia(PaidMediaPurchased),
+
     /// An error that happened during deserialization.
     ///
     /// This allows `teloxide` to continue working even if telegram adds a new
@@ -185,6 +189,7 @@ impl Update {
             InlineQuery(query) => &query.from,
             ShippingQuery(query) => &query.from,
             PreCheckoutQuery(query) => &query.from,
+            PurchasedPaidMedia(purchased) => &purchased.from,
             PollAnswer(answer) => return answer.voter.user(),
 
             MyChatMember(m) | ChatMember(m) => &m.from,
@@ -258,6 +263,7 @@ impl Update {
             UpdateKind::CallbackQuery(query) => i2(query.mentioned_users()),
             UpdateKind::ShippingQuery(query) => i1(once(&query.from)),
             UpdateKind::PreCheckoutQuery(query) => i1(once(&query.from)),
+            UpdateKind::PurchasedPaidMedia(purchased) => i1(once(&purchased.from)),
             UpdateKind::Poll(poll) => i3(poll.mentioned_users()),
 
             UpdateKind::PollAnswer(answer) => {
@@ -319,6 +325,7 @@ impl Update {
             | ChosenInlineResult(_)
             | ShippingQuery(_)
             | PreCheckoutQuery(_)
+            | PurchasedPaidMedia(_)
             | Poll(_)
             | PollAnswer(_)
             | Error(_) => return None,
@@ -443,6 +450,10 @@ impl<'de> Deserialize<'de> for UpdateKind {
                             .next_value::<ChatBoostRemoved>()
                             .ok()
                             .map(UpdateKind::RemovedChatBoost),
+                        "purchased_paid_media" => map
+                            .next_value::<PaidMediaPurchased>()
+                            .ok()
+                            .map(UpdateKind::PurchasedPaidMedia),
                         _ => Some(empty_error()),
                     })
                     .unwrap_or_else(empty_error);
@@ -514,6 +525,9 @@ impl Serialize for UpdateKind {
             UpdateKind::RemovedChatBoost(v) => {
                 s.serialize_newtype_variant(name, 21, "removed_chat_boost", v)
             }
+            UpdateKind::PurchasedPaidMedia(v) => {
+                s.serialize_newtype_variant(name, 22, "purchased_paid_media", v)
+            }
             UpdateKind::Error(v) => v.serialize(s),
         }
     }
@@ -530,8 +544,8 @@ mod test {
         ChatBoostUpdated, ChatFullInfo, ChatId, ChatKind, ChatPrivate, ChatPublic,
         LinkPreviewOptions, MaybeAnonymousUser, MediaKind, MediaText, Message, MessageCommon,
         MessageId, MessageKind, MessageReactionCountUpdated, MessageReactionUpdated,
-        PublicChatChannel, PublicChatKind, PublicChatSupergroup, ReactionCount, ReactionType,
-        Update, UpdateId, UpdateKind, User, UserId,
+        PaidMediaPurchased, PublicChatChannel, PublicChatKind, PublicChatSupergroup, ReactionCount,
+        ReactionType, Update, UpdateId, UpdateKind, User, UserId,
     };
 
     use chrono::DateTime;
@@ -634,6 +648,7 @@ mod test {
                     has_protected_content: false,
                     is_from_offline: false,
                     business_connection_id: None,
+                    effect_id: None,
                 }),
             }),
         };
@@ -1276,4 +1291,47 @@ mod test {
         let actual = serde_json::from_str::<Update>(json).unwrap();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn purchased_paid_media() {
+        let json = r#"
+        {
+            "update_id": 71651298,
+            "purchased_paid_media": {
+                "from": {
+                    "id": 1459074222,
+                    "is_bot": false,
+                    "first_name": "shadowchain",
+                    "username": "shdwchn10",
+                    "language_code": "en",
+                    "is_premium": true
+                },
+                "paid_media_payload": "secret"
+            }
+        }
+        "#;
+
+        let user = User {
+            id: UserId(1459074222),
+            is_bot: false,
+            first_name: "shadowchain".to_owned(),
+            last_name: None,
+            username: Some("shdwchn10".to_owned()),
+            language_code: Some("en".to_owned()),
+            is_premium: true,
+            added_to_attachment_menu: false,
+        };
+        let expected = Update {
+            id: UpdateId(71651298),
+            kind: UpdateKind::PurchasedPaidMedia(PaidMediaPurchased {
+                from: user.clone(),
+                paid_media_payload: "secret".to_owned(),
+            }),
+        };
+
+        let actual = serde_json::from_str::<Update>(json).unwrap();
+        assert_eq!(expected, actual);
+        assert_eq!(actual.from(), Some(&user));
+        assert_eq!(actual.chat(), None);
+    }
 }
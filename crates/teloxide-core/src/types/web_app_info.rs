@@ -12,3 +12,15 @@ pub struct WebAppInfo {
     /// [Initializing Web Apps]: https://core.telegram.org/bots/webapps#initializing-web-apps
     pub url: Url,
 }
+
+impl WebAppInfo {
+    pub fn new(url: Url) -> Self {
+        Self { url }
+    }
+}
+
+impl From<Url> for WebAppInfo {
+    fn from(url: Url) -> Self {
+        Self::new(url)
+    }
+}
@@ -91,6 +91,22 @@
 //! }
 //! ```
 //!
+//! By default, dialogues are indexed by chat IDs, so all members of a group
+//! share one dialogue. To keep a separate dialogue for each user in a chat or
+//! for each forum topic, index them by `(ChatId, UserId)` or `(ChatId,
+//! ThreadId)` instead (see [`DialogueKey`] and [`enter_with_key`]):
+//!
+//! ```no_run
+//! # use teloxide::{dispatching::dialogue::InMemStorage, prelude::*, types::UserId};
+//! # #[derive(Clone, Default)] enum State { #[default] Start }
+//! type MyDialogue = Dialogue<State, InMemStorage<State, (ChatId, UserId)>, (ChatId, UserId)>;
+//!
+//! let handler = Update::filter_message()
+//!     .enter_dialogue_with_key::<Message, InMemStorage<State, (ChatId, UserId)>, State, (ChatId, UserId)>()
+//!     .endpoint(|dialogue: MyDialogue| async move { dialogue.reset().await.map_err(Into::into) });
+//! # let _: Handler<'_, _, Result<(), Box<dyn std::error::Error + Send + Sync>>, _> = handler;
+//! ```
+//!
 //! [`examples/dialogue.rs`]: https://github.com/teloxide/teloxide/blob/master/crates/teloxide/examples/dialogue.rs
 
 #[cfg(feature = "redis-storage")]
@@ -102,6 +118,7 @@ pub use self::{SqliteStorage, SqliteStorageError};
 #[cfg(feature = "postgres-storage-nativetls")]
 pub use self::{PostgresStorage, PostgresStorageError};
 
+pub use dialogue_key::{DialogueKey, GetDialogueKey};
 pub use get_chat_id::GetChatId;
 pub use storage::*;
 
@@ -112,53 +129,62 @@ use std::{fmt::Debug, marker::PhantomData, sync::Arc};
 
 use super::DpHandlerDescription;
 
+mod dialogue_key;
 mod get_chat_id;
 mod storage;
 
 /// A handle for controlling dialogue state.
 #[derive(Debug)]
-pub struct Dialogue<D, S>
+pub struct Dialogue<D, S, K = ChatId>
 where
     S: ?Sized,
 {
     storage: Arc<S>,
-    chat_id: ChatId,
+    key: K,
     _phantom: PhantomData<D>,
 }
 
 // `#[derive]` requires generics to implement `Clone`, but `S` is wrapped around
 // `Arc`, and `D` is wrapped around PhantomData.
-impl<D, S> Clone for Dialogue<D, S>
+impl<D, S, K> Clone for Dialogue<D, S, K>
 where
     S: ?Sized,
+    K: Clone,
 {
     fn clone(&self) -> Self {
-        Dialogue { storage: self.storage.clone(), chat_id: self.chat_id, _phantom: PhantomData }
+        Dialogue { storage: self.storage.clone(), key: self.key.clone(), _phantom: PhantomData }
     }
 }
 
-impl<D, S> Dialogue<D, S>
+impl<D, S, K> Dialogue<D, S, K>
 where
     D: Send + 'static,
-    S: Storage<D> + ?Sized,
+    S: Storage<D, K> + ?Sized,
+    K: DialogueKey,
 {
     /// Constructs a new dialogue with `storage` (where dialogues are stored)
-    /// and `chat_id` of a current dialogue.
+    /// and `key` of a current dialogue.
+    #[must_use]
+    pub fn new(storage: Arc<S>, key: K) -> Self {
+        Self { storage, key, _phantom: PhantomData }
+    }
+
+    /// Returns a key by which this dialogue is indexed in the storage.
     #[must_use]
-    pub fn new(storage: Arc<S>, chat_id: ChatId) -> Self {
-        Self { storage, chat_id, _phantom: PhantomData }
+    pub fn key(&self) -> K {
+        self.key
     }
 
     /// Returns a chat ID associated with this dialogue.
     #[must_use]
     pub fn chat_id(&self) -> ChatId {
-        self.chat_id
+        self.key.chat_id()
     }
 
     /// Retrieves the current state of the dialogue or `None` if there is no
     /// dialogue.
     pub async fn get(&self) -> Result<Option<D>, S::Error> {
-        self.storage.clone().get_dialogue(self.chat_id).await
+        self.storage.clone().get_dialogue(self.key).await
     }
 
     /// Like [`Dialogue::get`] but returns a default value if there is no
@@ -170,7 +196,7 @@ where
         match self.get().await? {
             Some(d) => Ok(d),
             None => {
-                self.storage.clone().update_dialogue(self.chat_id, D::default()).await?;
+                self.storage.clone().update_dialogue(self.key, D::default()).await?;
                 Ok(D::default())
             }
         }
@@ -185,10 +211,26 @@ where
         D: From<State>,
     {
         let new_dialogue = state.into();
-        self.storage.clone().update_dialogue(self.chat_id, new_dialogue).await?;
+        self.storage.clone().update_dialogue(self.key, new_dialogue).await?;
         Ok(())
     }
 
+    /// Updates the dialogue state based on the current one and returns the new
+    /// state.
+    ///
+    /// Unlike [`Dialogue::get`] followed by [`Dialogue::update`], this can't
+    /// lose a transition made concurrently (e.g. while handling another update
+    /// from the same chat): the dialogue is not changed between reading and
+    /// writing it. `f` receives `None` if there is no dialogue and may be
+    /// called more than once, see [`AtomicStorage::update_dialogue_with`].
+    pub async fn update_with<F>(&self, f: F) -> Result<D, S::Error>
+    where
+        S: AtomicStorage<D, K>,
+        F: FnMut(Option<D>) -> D + Send + 'static,
+    {
+        self.storage.clone().update_dialogue_with(self.key, Box::new(f)).await
+    }
+
     /// Updates the dialogue with a default value.
     pub async fn reset(&self) -> Result<(), S::Error>
     where
@@ -199,7 +241,7 @@ where
 
     /// Removes the dialogue from the storage provided to [`Dialogue::new`].
     pub async fn exit(&self) -> Result<(), S::Error> {
-        self.storage.clone().remove_dialogue(self.chat_id).await
+        self.storage.clone().remove_dialogue(self.key).await
     }
 }
 
@@ -209,12 +251,18 @@ where
 ///
 /// See [`HandlerExt::enter_dialogue`].
 ///
+/// If the dialogue can't be retrieved from the storage (e.g. it was written by
+/// an older version of the bot and can't be deserialized anymore), the error is
+/// logged and the update is dropped. See [`serializer::Versioned`] for
+/// upgrading old dialogues instead.
+///
 /// ## Dependency requirements
 ///
 ///  - `Arc<S>`
 ///  - `Upd`
 ///
 /// [`HandlerExt::enter_dialogue`]: super::HandlerExt::enter_dialogue
+/// [`serializer::Versioned`]: crate::dispatching::dialogue::serializer::Versioned
 #[must_use]
 pub fn enter<Upd, S, D, Output>() -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
 where
@@ -223,12 +271,39 @@ where
     D: Default + Send + Sync + 'static,
     Upd: GetChatId + Clone + Send + Sync + 'static,
     Output: Send + Sync + 'static,
+{
+    enter_with_key::<Upd, S, D, ChatId, Output>()
+}
+
+/// Enters a dialogue context indexed by keys of type `K`.
+///
+/// A call to this function is the same as
+/// `dptree::entry().enter_dialogue_with_key()`.
+///
+/// See [`HandlerExt::enter_dialogue_with_key`].
+///
+/// ## Dependency requirements
+///
+///  - `Arc<S>`
+///  - `Upd`
+///
+/// [`HandlerExt::enter_dialogue_with_key`]: super::HandlerExt::enter_dialogue_with_key
+#[must_use]
+pub fn enter_with_key<Upd, S, D, K, Output>(
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    S: Storage<D, K> + ?Sized + Send + Sync + 'static,
+    <S as Storage<D, K>>::Error: Debug + Send,
+    D: Default + Send + Sync + 'static,
+    K: DialogueKey,
+    Upd: GetDialogueKey<K> + Clone + Send + Sync + 'static,
+    Output: Send + Sync + 'static,
 {
     dptree::filter_map(|storage: Arc<S>, upd: Upd| {
-        let chat_id = upd.chat_id()?;
-        Some(Dialogue::new(storage, chat_id))
+        let key = upd.dialogue_key()?;
+        Some(Dialogue::new(storage, key))
     })
-    .filter_map_async(|dialogue: Dialogue<D, S>| async move {
+    .filter_map_async(|dialogue: Dialogue<D, S, K>| async move {
         match dialogue.get_or_default().await {
             Ok(dialogue) => Some(dialogue),
             Err(err) => {
@@ -0,0 +1,173 @@
+use std::{fmt::Debug, hash::Hash};
+
+use crate::{
+    dispatching::dialogue::GetChatId,
+    types::{
+        CallbackQuery, ChatId, ChatJoinRequest, ChatMemberUpdated, Message, MessageId, ThreadId,
+        Update, UpdateKind, UserId,
+    },
+};
+
+/// A key by which dialogues are indexed in a [`Storage`].
+///
+/// By default there is a single dialogue per chat, i.e. dialogues are indexed
+/// by [`ChatId`]. In groups this means that all members share the same state;
+/// if that's not what you want, use one of the composite keys:
+///
+///  - `(ChatId, UserId)` -- a separate dialogue for each user in a chat;
+///  - `(ChatId, ThreadId)` -- a separate dialogue for each thread in a chat
+///    (e.g. a forum topic).
+///
+/// Persistent storages save a key as its [`DialogueKey::KIND`] and two
+/// integers: [`DialogueKey::chat_id`] and [`DialogueKey::sub_id`]. Keys of
+/// different types never refer to the same dialogue, even if they are saved in
+/// the same storage.
+///
+/// [`Storage`]: super::Storage
+pub trait DialogueKey: Copy + Eq + Hash + Debug + Send + Sync + 'static {
+    /// The name of this key type, unique among the key types used with the
+    /// same storage. It must not contain `:`.
+    const KIND: &'static str;
+
+    /// Returns the chat in which the dialogue takes place.
+    #[must_use]
+    fn chat_id(&self) -> ChatId;
+
+    /// Returns the part of the key which identifies the dialogue within its
+    /// chat, or `0` if the key is a chat ID alone.
+    #[must_use]
+    fn sub_id(&self) -> i64;
+
+    /// Reassembles a key from [`DialogueKey::chat_id`] and
+    /// [`DialogueKey::sub_id`], or returns `None` if they don't form a key of
+    /// this type.
+    #[must_use]
+    fn from_parts(chat_id: ChatId, sub_id: i64) -> Option<Self>;
+}
+
+impl DialogueKey for ChatId {
+    const KIND: &'static str = "chat";
+
+    fn chat_id(&self) -> ChatId {
+        *self
+    }
+
+    fn sub_id(&self) -> i64 {
+        0
+    }
+
+    fn from_parts(chat_id: ChatId, sub_id: i64) -> Option<Self> {
+        (sub_id == 0).then_some(chat_id)
+    }
+}
+
+impl DialogueKey for (ChatId, UserId) {
+    const KIND: &'static str = "user";
+
+    fn chat_id(&self) -> ChatId {
+        self.0
+    }
+
+    fn sub_id(&self) -> i64 {
+        self.1 .0 as i64
+    }
+
+    fn from_parts(chat_id: ChatId, sub_id: i64) -> Option<Self> {
+        Some((chat_id, UserId(sub_id as u64)))
+    }
+}
+
+impl DialogueKey for (ChatId, ThreadId) {
+    const KIND: &'static str = "thread";
+
+    fn chat_id(&self) -> ChatId {
+        self.0
+    }
+
+    fn sub_id(&self) -> i64 {
+        self.1 .0 .0.into()
+    }
+
+    fn from_parts(chat_id: ChatId, sub_id: i64) -> Option<Self> {
+        Some((chat_id, ThreadId(MessageId(sub_id.try_into().ok()?))))
+    }
+}
+
+/// Something that may have a dialogue key of type `K`.
+///
+/// Every [`GetChatId`] implementor has a [`ChatId`] key. `(ChatId, UserId)`
+/// keys are available for updates which have both a chat and a sender, and
+/// `(ChatId, ThreadId)` keys -- for messages sent to a thread (messages
+/// outside of threads have no such key).
+pub trait GetDialogueKey<K> {
+    #[must_use]
+    fn dialogue_key(&self) -> Option<K>;
+}
+
+impl<T> GetDialogueKey<ChatId> for T
+where
+    T: GetChatId,
+{
+    fn dialogue_key(&self) -> Option<ChatId> {
+        self.chat_id()
+    }
+}
+
+impl GetDialogueKey<(ChatId, UserId)> for Message {
+    fn dialogue_key(&self) -> Option<(ChatId, UserId)> {
+        Some((self.chat.id, self.from.as_ref()?.id))
+    }
+}
+
+impl GetDialogueKey<(ChatId, UserId)> for CallbackQuery {
+    fn dialogue_key(&self) -> Option<(ChatId, UserId)> {
+        Some((self.chat_id()?, self.from.id))
+    }
+}
+
+impl GetDialogueKey<(ChatId, UserId)> for Update {
+    fn dialogue_key(&self) -> Option<(ChatId, UserId)> {
+        Some((self.chat()?.id, self.from()?.id))
+    }
+}
+
+impl GetDialogueKey<(ChatId, UserId)> for ChatMemberUpdated {
+    fn dialogue_key(&self) -> Option<(ChatId, UserId)> {
+        Some((self.chat.id, self.from.id))
+    }
+}
+
+impl GetDialogueKey<(ChatId, UserId)> for ChatJoinRequest {
+    fn dialogue_key(&self) -> Option<(ChatId, UserId)> {
+        Some((self.chat.id, self.from.id))
+    }
+}
+
+impl GetDialogueKey<(ChatId, ThreadId)> for Message {
+    fn dialogue_key(&self) -> Option<(ChatId, ThreadId)> {
+        Some((self.chat.id, self.thread_id?))
+    }
+}
+
+impl GetDialogueKey<(ChatId, ThreadId)> for CallbackQuery {
+    fn dialogue_key(&self) -> Option<(ChatId, ThreadId)> {
+        self.message.as_ref()?.regular_message()?.dialogue_key()
+    }
+}
+
+impl GetDialogueKey<(ChatId, ThreadId)> for Update {
+    fn dialogue_key(&self) -> Option<(ChatId, ThreadId)> {
+        use UpdateKind::*;
+
+        match &self.kind {
+            Message(m)
+            | EditedMessage(m)
+            | ChannelPost(m)
+            | EditedChannelPost(m)
+            | BusinessMessage(m)
+            | EditedBusinessMessage(m) => m.dialogue_key(),
+            CallbackQuery(q) => q.dialogue_key(),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,8 @@
 pub mod serializer;
 
+mod atomic_storage;
+mod enumerable_storage;
+mod expiring_storage;
 mod in_mem_storage;
 mod trace_storage;
 
@@ -12,10 +15,20 @@ mod sqlite_storage;
 #[cfg(feature = "postgres-storage-nativetls")]
 mod postgres_storage;
 
+#[cfg(any(
+    feature = "sqlite-storage-nativetls",
+    feature = "sqlite-storage-rustls",
+    feature = "postgres-storage-nativetls"
+))]
+mod sql;
+
 use futures::future::BoxFuture;
 use teloxide_core::types::ChatId;
 
 pub use self::{
+    atomic_storage::{AtomicStorage, UpdateFn},
+    enumerable_storage::EnumerableStorage,
+    expiring_storage::{spawn_sweeper, ExpiringStorage},
     in_mem_storage::{InMemStorage, InMemStorageError},
     trace_storage::TraceStorage,
 };
@@ -31,9 +44,16 @@ pub use sqlite_storage::{SqliteStorage, SqliteStorageError};
 #[cfg(feature = "postgres-storage-nativetls")]
 pub use postgres_storage::{PostgresStorage, PostgresStorageError};
 
+#[cfg(any(
+    feature = "sqlite-storage-nativetls",
+    feature = "sqlite-storage-rustls",
+    feature = "postgres-storage-nativetls"
+))]
+pub use sql::SqlStorageOptions;
+
 /// A storage with an erased error type.
-pub type ErasedStorage<D> =
-    dyn Storage<D, Error = Box<dyn std::error::Error + Send + Sync>> + Send + Sync;
+pub type ErasedStorage<D, K = ChatId> =
+    dyn Storage<D, K, Error = Box<dyn std::error::Error + Send + Sync>> + Send + Sync;
 
 /// A storage of dialogues.
 ///
@@ -49,46 +69,45 @@ pub type ErasedStorage<D> =
 /// - [`RedisStorage`] -- a Redis-based storage.
 /// - [`SqliteStorage`] -- an SQLite-based persistent storage.
 ///
+/// Dialogues are indexed by keys of type `K`, which is [`ChatId`] by default.
+/// See [`DialogueKey`] for the other options.
+///
 /// [`InMemStorage`]: crate::dispatching::dialogue::InMemStorage
 /// [`RedisStorage`]: crate::dispatching::dialogue::RedisStorage
 /// [`SqliteStorage`]: crate::dispatching::dialogue::SqliteStorage
-pub trait Storage<D> {
+/// [`DialogueKey`]: crate::dispatching::dialogue::DialogueKey
+pub trait Storage<D, K = ChatId> {
     type Error;
 
-    /// Removes a dialogue indexed by `chat_id`.
+    /// Removes a dialogue indexed by `key`.
     ///
-    /// If the dialogue indexed by `chat_id` does not exist, this function
+    /// If the dialogue indexed by `key` does not exist, this function
     /// results in an error.
     #[must_use = "Futures are lazy and do nothing unless polled with .await"]
-    fn remove_dialogue(
-        self: Arc<Self>,
-        chat_id: ChatId,
-    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    fn remove_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<(), Self::Error>>
     where
         D: Send + 'static;
 
-    /// Updates a dialogue indexed by `chat_id` with `dialogue`.
+    /// Updates a dialogue indexed by `key` with `dialogue`.
     #[must_use = "Futures are lazy and do nothing unless polled with .await"]
     fn update_dialogue(
         self: Arc<Self>,
-        chat_id: ChatId,
+        key: K,
         dialogue: D,
     ) -> BoxFuture<'static, Result<(), Self::Error>>
     where
         D: Send + 'static;
 
-    /// Returns the dialogue indexed by `chat_id`.
+    /// Returns the dialogue indexed by `key`.
     #[must_use = "Futures are lazy and do nothing unless polled with .await"]
-    fn get_dialogue(
-        self: Arc<Self>,
-        chat_id: ChatId,
-    ) -> BoxFuture<'static, Result<Option<D>, Self::Error>>;
+    fn get_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<Option<D>, Self::Error>>;
 
     /// Erases [`Self::Error`] to [`std::error::Error`].
     #[must_use]
-    fn erase(self: Arc<Self>) -> Arc<ErasedStorage<D>>
+    fn erase(self: Arc<Self>) -> Arc<ErasedStorage<D, K>>
     where
         Self: Sized + Send + Sync + 'static,
+        K: Send + 'static,
         Self::Error: std::error::Error + Send + Sync + 'static,
     {
         Arc::new(Eraser(self))
@@ -97,45 +116,38 @@ pub trait Storage<D> {
 
 struct Eraser<S>(Arc<S>);
 
-impl<D, S> Storage<D> for Eraser<S>
+impl<D, K, S> Storage<D, K> for Eraser<S>
 where
-    S: Storage<D> + Send + Sync + 'static,
+    S: Storage<D, K> + Send + Sync + 'static,
+    K: Send + 'static,
     S::Error: std::error::Error + Send + Sync + 'static,
 {
     type Error = Box<dyn std::error::Error + Send + Sync>;
 
-    fn remove_dialogue(
-        self: Arc<Self>,
-        chat_id: ChatId,
-    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    fn remove_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<(), Self::Error>>
     where
         D: Send + 'static,
     {
         Box::pin(
-            async move { Arc::clone(&self.0).remove_dialogue(chat_id).await.map_err(|e| e.into()) },
+            async move { Arc::clone(&self.0).remove_dialogue(key).await.map_err(|e| e.into()) },
         )
     }
 
     fn update_dialogue(
         self: Arc<Self>,
-        chat_id: ChatId,
+        key: K,
         dialogue: D,
     ) -> BoxFuture<'static, Result<(), Self::Error>>
     where
         D: Send + 'static,
     {
         Box::pin(async move {
-            Arc::clone(&self.0).update_dialogue(chat_id, dialogue).await.map_err(|e| e.into())
+            Arc::clone(&self.0).update_dialogue(key, dialogue).await.map_err(|e| e.into())
         })
     }
 
-    fn get_dialogue(
-        self: Arc<Self>,
-        chat_id: ChatId,
-    ) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
-        Box::pin(
-            async move { Arc::clone(&self.0).get_dialogue(chat_id).await.map_err(|e| e.into()) },
-        )
+    fn get_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
+        Box::pin(async move { Arc::clone(&self.0).get_dialogue(key).await.map_err(|e| e.into()) })
     }
 }
 
@@ -153,4 +165,79 @@ mod tests {
         Arc::clone(&erased).remove_dialogue(chat_id).await.unwrap();
         assert_eq!(Arc::clone(&erased).get_dialogue(chat_id).await.unwrap(), None);
     }
+
+    #[tokio::test]
+    async fn test_composite_keys() {
+        use teloxide_core::types::UserId;
+
+        let storage = InMemStorage::<_, (ChatId, UserId)>::new();
+        let (alice, bob) = ((ChatId(-1), UserId(1)), (ChatId(-1), UserId(2)));
+
+        Arc::clone(&storage).update_dialogue(alice, 1).await.unwrap();
+        Arc::clone(&storage).update_dialogue(bob, 2).await.unwrap();
+        assert_eq!(Arc::clone(&storage).get_dialogue(alice).await.unwrap(), Some(1));
+        assert_eq!(Arc::clone(&storage).get_dialogue(bob).await.unwrap(), Some(2));
+
+        Arc::clone(&storage).remove_dialogue(alice).await.unwrap();
+        assert_eq!(Arc::clone(&storage).get_dialogue(alice).await.unwrap(), None);
+        assert_eq!(Arc::clone(&storage).get_dialogue(bob).await.unwrap(), Some(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_expiry() {
+        let storage = InMemStorage::with_ttl(std::time::Duration::from_millis(100));
+
+        Arc::clone(&storage).update_dialogue(ChatId(1), 1).await.unwrap();
+        tokio::time::advance(std::time::Duration::from_millis(150)).await;
+        Arc::clone(&storage).update_dialogue(ChatId(2), 2).await.unwrap();
+
+        assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(1)).await.unwrap(), None);
+        assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(2)).await.unwrap(), Some(2));
+        assert!(Arc::clone(&storage).remove_dialogue(ChatId(1)).await.is_err());
+
+        assert_eq!(Arc::clone(&storage).remove_expired().await.unwrap(), [ChatId(1)]);
+        assert!(Arc::clone(&storage).remove_expired().await.unwrap().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_enumerable() {
+        use futures::TryStreamExt;
+
+        let storage = InMemStorage::with_ttl(std::time::Duration::from_millis(100));
+
+        Arc::clone(&storage).update_dialogue(ChatId(1), 1).await.unwrap();
+        tokio::time::advance(std::time::Duration::from_millis(150)).await;
+        Arc::clone(&storage).update_dialogue(ChatId(2), 2).await.unwrap();
+        Arc::clone(&storage).update_dialogue(ChatId(3), 3).await.unwrap();
+
+        let mut dialogues: Vec<_> =
+            Arc::clone(&storage).list_dialogues().try_collect().await.unwrap();
+        dialogues.sort();
+        assert_eq!(dialogues, [(ChatId(2), 2), (ChatId(3), 3)]);
+        assert_eq!(Arc::clone(&storage).count().await.unwrap(), 2);
+
+        Arc::clone(&storage).clear().await.unwrap();
+        assert_eq!(Arc::clone(&storage).count().await.unwrap(), 0);
+        assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(2)).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_atomic() {
+        let storage = InMemStorage::new();
+
+        let tasks: Vec<_> = (0..100)
+            .map(|_| {
+                let storage = Arc::clone(&storage);
+                tokio::spawn(async move {
+                    let increment = |old: Option<i32>| old.unwrap_or_default() + 1;
+                    storage.update_dialogue_with(ChatId(1), Box::new(increment)).await.unwrap()
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(1)).await.unwrap(), Some(100));
+    }
 }
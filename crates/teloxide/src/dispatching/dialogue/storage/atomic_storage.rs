@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use teloxide_core::types::ChatId;
+
+use crate::dispatching::dialogue::Storage;
+
+/// A function computing a new dialogue from the current one (if any), see
+/// [`AtomicStorage::update_dialogue_with`].
+pub type UpdateFn<D> = Box<dyn FnMut(Option<D>) -> D + Send>;
+
+/// A storage which can update a dialogue based on its current state
+/// atomically.
+///
+/// Getting a dialogue and then updating it is racy: if two updates from the
+/// same chat are handled concurrently, one of the transitions may be lost. With
+/// [`AtomicStorage::update_dialogue_with`], a dialogue can't be changed in
+/// between.
+///
+/// See also [`Dialogue::update_with`].
+///
+/// [`Dialogue::update_with`]: crate::dispatching::dialogue::Dialogue::update_with
+pub trait AtomicStorage<D, K = ChatId>: Storage<D, K> {
+    /// Replaces the dialogue with `f(current)` and returns the new dialogue.
+    ///
+    /// `f` is called with `None` if there is no dialogue (or it has expired).
+    /// It may be called more than once if a storage detects a concurrent
+    /// change and retries, so it should not have side effects.
+    #[must_use = "Futures are lazy and do nothing unless polled with .await"]
+    fn update_dialogue_with(
+        self: Arc<Self>,
+        key: K,
+        f: UpdateFn<D>,
+    ) -> BoxFuture<'static, Result<D, Self::Error>>;
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use futures::{future::BoxFuture, stream::BoxStream};
+use teloxide_core::types::ChatId;
+
+use crate::dispatching::dialogue::Storage;
+
+/// A storage which can enumerate and remove all of its dialogues at once.
+///
+/// This is useful e.g. for admin dashboards, broadcasts, or to drop all
+/// dialogues after their type has changed in an incompatible way.
+///
+/// If a storage holds dialogues with keys of different types (say, `ChatId`
+/// and `(ChatId, UserId)`), these methods only see dialogues with keys of type
+/// `K`. Expired dialogues (see [`ExpiringStorage`]) are neither listed nor
+/// counted.
+///
+/// [`ExpiringStorage`]: super::ExpiringStorage
+pub trait EnumerableStorage<D, K = ChatId>: Storage<D, K> {
+    /// Returns a stream of all dialogues along with their keys.
+    ///
+    /// Dialogues are fetched in batches, so the stream may or may not reflect
+    /// changes made while it's consumed.
+    #[must_use = "Streams are lazy and do nothing unless polled"]
+    fn list_dialogues(self: Arc<Self>) -> BoxStream<'static, Result<(K, D), Self::Error>>;
+
+    /// Returns the number of dialogues.
+    #[must_use = "Futures are lazy and do nothing unless polled with .await"]
+    fn count(self: Arc<Self>) -> BoxFuture<'static, Result<u64, Self::Error>>;
+
+    /// Removes all dialogues.
+    #[must_use = "Futures are lazy and do nothing unless polled with .await"]
+    fn clear(self: Arc<Self>) -> BoxFuture<'static, Result<(), Self::Error>>;
+}
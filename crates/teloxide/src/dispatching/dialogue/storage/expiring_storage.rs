@@ -0,0 +1,96 @@
+use std::{fmt::Debug, future::Future, sync::Arc, time::Duration};
+
+use futures::future::BoxFuture;
+use teloxide_core::types::ChatId;
+use tokio::task::JoinHandle;
+
+use crate::dispatching::dialogue::Storage;
+
+/// A storage whose dialogues expire after some time-to-live.
+///
+/// A dialogue which hasn't been updated for longer than the TTL of a storage
+/// is treated as absent by [`Storage::get_dialogue`] and
+/// [`Storage::remove_dialogue`]. It stays in the storage until it's collected
+/// by [`ExpiringStorage::remove_expired`], usually called periodically by
+/// [`spawn_sweeper`].
+///
+/// Storages constructed without a TTL never expire dialogues.
+pub trait ExpiringStorage<D, K = ChatId>: Storage<D, K> {
+    /// Removes all expired dialogues and returns their keys.
+    #[must_use = "Futures are lazy and do nothing unless polled with .await"]
+    fn remove_expired(self: Arc<Self>) -> BoxFuture<'static, Result<Vec<K>, Self::Error>>;
+}
+
+/// Spawns a task which calls [`ExpiringStorage::remove_expired`] every
+/// `period` and `on_expire` for every removed dialogue.
+///
+/// `on_expire` may be used e.g. to tell a user that their session timed out.
+/// Note that it isn't called for dialogues which were updated after they
+/// expired but before they were collected: these are simply replaced.
+///
+/// Errors from the storage are logged. The task runs until it's aborted via the
+/// returned handle.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use teloxide::{
+///     dispatching::dialogue::{spawn_sweeper, InMemStorage},
+///     prelude::*,
+/// };
+///
+/// # #[derive(Clone, Default)] enum State { #[default] Start }
+/// # async fn f() {
+/// let bot = Bot::from_env();
+/// let storage = InMemStorage::<State>::with_ttl(Duration::from_secs(15 * 60));
+///
+/// spawn_sweeper(storage.clone(), Duration::from_secs(60), move |chat_id: ChatId| {
+///     let bot = bot.clone();
+///     async move {
+///         let _ = bot.send_message(chat_id, "Your session has timed out.").await;
+///     }
+/// });
+/// # }
+/// ```
+pub fn spawn_sweeper<S, D, K, F, Fut>(
+    storage: Arc<S>,
+    period: Duration,
+    on_expire: F,
+) -> JoinHandle<()>
+where
+    S: ExpiringStorage<D, K> + ?Sized + Send + Sync + 'static,
+    S::Error: Debug + Send,
+    K: Send + 'static,
+    F: Fn(K) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send,
+{
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+
+        loop {
+            interval.tick().await;
+
+            match Arc::clone(&storage).remove_expired().await {
+                Ok(keys) => {
+                    for key in keys {
+                        on_expire(key).await;
+                    }
+                }
+                Err(err) => log::error!("Failed to remove expired dialogues: {:?}", err),
+            }
+        }
+    })
+}
+
+/// Returns the number of milliseconds since the Unix epoch, which is how
+/// persistent storages record the time of the last update.
+#[cfg(any(
+    feature = "sqlite-storage-nativetls",
+    feature = "sqlite-storage-rustls",
+    feature = "postgres-storage-nativetls",
+    feature = "redis-storage"
+))]
+pub(super) fn unix_millis(time: std::time::SystemTime) -> i64 {
+    time.duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_millis() as i64
+}
@@ -1,9 +1,13 @@
-use super::Storage;
-use futures::future::BoxFuture;
-use std::{collections::HashMap, sync::Arc};
+use super::{AtomicStorage, EnumerableStorage, ExpiringStorage, Storage, UpdateFn};
+use futures::{
+    future::BoxFuture,
+    stream::{self, BoxStream},
+    StreamExt,
+};
+use std::{collections::HashMap, hash::Hash, sync::Arc, time::Duration};
 use teloxide_core::types::ChatId;
 use thiserror::Error;
-use tokio::sync::Mutex;
+use tokio::{sync::Mutex, time::Instant};
 
 /// An error returned from [`InMemStorage`].
 #[derive(Debug, Error)]
@@ -20,58 +24,162 @@ pub enum InMemStorageError {
 /// store them somewhere on a drive, you should use e.g.
 /// [`super::SqliteStorage`] or implement your own.
 #[derive(Debug)]
-pub struct InMemStorage<D> {
-    map: Mutex<HashMap<ChatId, D>>,
+pub struct InMemStorage<D, K = ChatId> {
+    map: Mutex<HashMap<K, (D, Instant)>>,
+    ttl: Option<Duration>,
 }
 
-impl<S> InMemStorage<S> {
+impl<S, K> InMemStorage<S, K> {
     #[must_use]
     pub fn new() -> Arc<Self> {
-        Arc::new(Self { map: Mutex::new(HashMap::new()) })
+        Arc::new(Self { map: Mutex::new(HashMap::new()), ttl: None })
+    }
+
+    /// Creates a storage in which dialogues expire after `ttl` since their
+    /// last update.
+    ///
+    /// See [`ExpiringStorage`].
+    #[must_use]
+    pub fn with_ttl(ttl: Duration) -> Arc<Self> {
+        Arc::new(Self { map: Mutex::new(HashMap::new()), ttl: Some(ttl) })
+    }
+
+    fn is_expired(&self, updated_at: Instant) -> bool {
+        self.ttl.is_some_and(|ttl| updated_at.elapsed() >= ttl)
     }
 }
 
-impl<D> Storage<D> for InMemStorage<D>
+impl<D, K> Storage<D, K> for InMemStorage<D, K>
 where
     D: Clone,
     D: Send + 'static,
+    K: Eq + Hash + Send + Sync + 'static,
 {
     type Error = InMemStorageError;
 
-    fn remove_dialogue(
-        self: Arc<Self>,
-        chat_id: ChatId,
-    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    fn remove_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<(), Self::Error>>
     where
         D: Send + 'static,
     {
         Box::pin(async move {
-            self.map
-                .lock()
-                .await
-                .remove(&chat_id)
-                .map_or(Err(InMemStorageError::DialogueNotFound), |_| Ok(()))
+            let mut map = self.map.lock().await;
+
+            match map.get(&key) {
+                Some(&(_, updated_at)) if !self.is_expired(updated_at) => {
+                    map.remove(&key);
+                    Ok(())
+                }
+                _ => Err(InMemStorageError::DialogueNotFound),
+            }
         })
     }
 
     fn update_dialogue(
         self: Arc<Self>,
-        chat_id: ChatId,
+        key: K,
         dialogue: D,
     ) -> BoxFuture<'static, Result<(), Self::Error>>
     where
         D: Send + 'static,
     {
         Box::pin(async move {
-            self.map.lock().await.insert(chat_id, dialogue);
+            self.map.lock().await.insert(key, (dialogue, Instant::now()));
             Ok(())
         })
     }
 
-    fn get_dialogue(
+    fn get_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
+        Box::pin(async move {
+            Ok(self
+                .map
+                .lock()
+                .await
+                .get(&key)
+                .filter(|(_, updated_at)| !self.is_expired(*updated_at))
+                .map(|(dialogue, _)| dialogue.clone()))
+        })
+    }
+}
+
+impl<D, K> ExpiringStorage<D, K> for InMemStorage<D, K>
+where
+    D: Clone,
+    D: Send + 'static,
+    K: Eq + Hash + Send + Sync + 'static,
+{
+    fn remove_expired(self: Arc<Self>) -> BoxFuture<'static, Result<Vec<K>, Self::Error>> {
+        Box::pin(async move {
+            let mut map = self.map.lock().await;
+            let (expired, alive): (Vec<_>, Vec<_>) =
+                map.drain().partition(|&(_, (_, updated_at))| self.is_expired(updated_at));
+            map.extend(alive);
+
+            Ok(expired.into_iter().map(|(key, _)| key).collect())
+        })
+    }
+}
+
+impl<D, K> AtomicStorage<D, K> for InMemStorage<D, K>
+where
+    D: Clone,
+    D: Send + 'static,
+    K: Eq + Hash + Send + Sync + 'static,
+{
+    fn update_dialogue_with(
         self: Arc<Self>,
-        chat_id: ChatId,
-    ) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
-        Box::pin(async move { Ok(self.map.lock().await.get(&chat_id).map(ToOwned::to_owned)) })
+        key: K,
+        mut f: UpdateFn<D>,
+    ) -> BoxFuture<'static, Result<D, Self::Error>> {
+        Box::pin(async move {
+            // The map is locked for the whole update
+            let mut map = self.map.lock().await;
+
+            let old = map
+                .remove(&key)
+                .filter(|(_, updated_at)| !self.is_expired(*updated_at))
+                .map(|(dialogue, _)| dialogue);
+            let new = f(old);
+            map.insert(key, (new.clone(), Instant::now()));
+
+            Ok(new)
+        })
+    }
+}
+
+impl<D, K> EnumerableStorage<D, K> for InMemStorage<D, K>
+where
+    D: Clone,
+    D: Send + 'static,
+    K: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    fn list_dialogues(self: Arc<Self>) -> BoxStream<'static, Result<(K, D), Self::Error>> {
+        stream::once(async move {
+            let dialogues: Vec<_> = self
+                .map
+                .lock()
+                .await
+                .iter()
+                .filter(|(_, (_, updated_at))| !self.is_expired(*updated_at))
+                .map(|(key, (dialogue, _))| Ok((key.clone(), dialogue.clone())))
+                .collect();
+
+            stream::iter(dialogues)
+        })
+        .flatten()
+        .boxed()
+    }
+
+    fn count(self: Arc<Self>) -> BoxFuture<'static, Result<u64, Self::Error>> {
+        Box::pin(async move {
+            let map = self.map.lock().await;
+            Ok(map.values().filter(|(_, updated_at)| !self.is_expired(*updated_at)).count() as u64)
+        })
+    }
+
+    fn clear(self: Arc<Self>) -> BoxFuture<'static, Result<(), Self::Error>> {
+        Box::pin(async move {
+            self.map.lock().await.clear();
+            Ok(())
+        })
     }
 }
@@ -3,15 +3,29 @@ use std::{
     fmt::{Debug, Display},
     str,
     sync::Arc,
+    time::{Duration, SystemTime},
 };
 
-use futures::future::BoxFuture;
+use futures::{
+    future::BoxFuture,
+    stream::{self, BoxStream},
+    StreamExt, TryStreamExt,
+};
 use serde::{de::DeserializeOwned, Serialize};
-use sqlx::postgres::{PgPool, PgPoolOptions};
+use sqlx::{
+    postgres::{PgPool, PgPoolOptions, Postgres},
+    Executor, Transaction,
+};
 use teloxide_core::types::ChatId;
 use thiserror::Error;
 
-use super::{serializer::Serializer, Storage};
+use super::{
+    expiring_storage::unix_millis,
+    serializer::Serializer,
+    sql::{self, Migration, MigrationBackend, SqlStorageOptions, TableName, PAGE_SIZE},
+    AtomicStorage, EnumerableStorage, ExpiringStorage, Storage, UpdateFn,
+};
+use crate::dispatching::dialogue::DialogueKey;
 
 /// An error returned from [`PostgresStorage`].
 #[derive(Debug, Error)]
@@ -34,8 +48,62 @@ where
 pub struct PostgresStorage<S> {
     pool: PgPool,
     serializer: S,
+    queries: Queries,
+    ttl: Option<Duration>,
+}
+
+/// Queries with the name of the dialogue table substituted.
+struct Queries {
+    get_dialogue: String,
+    remove_dialogue: String,
+    update_dialogue: String,
+    remove_expired: String,
+    lock_dialogue: String,
+    insert_dialogue: String,
+    list_dialogues: String,
+    count_dialogues: String,
+    clear_dialogues: String,
 }
 
+impl Queries {
+    fn new(table: &TableName) -> Self {
+        Self {
+            get_dialogue: table.render(include_str!("postgres_storage/queries/get_dialogue.sql")),
+            remove_dialogue: table
+                .render(include_str!("postgres_storage/queries/remove_dialogue.sql")),
+            update_dialogue: table
+                .render(include_str!("postgres_storage/queries/update_dialogue.sql")),
+            remove_expired: table
+                .render(include_str!("postgres_storage/queries/remove_expired.sql")),
+            lock_dialogue: table.render(include_str!("postgres_storage/queries/lock_dialogue.sql")),
+            insert_dialogue: table
+                .render(include_str!("postgres_storage/queries/insert_dialogue.sql")),
+            list_dialogues: table
+                .render(include_str!("postgres_storage/queries/list_dialogues.sql")),
+            count_dialogues: table
+                .render(include_str!("postgres_storage/queries/count_dialogues.sql")),
+            clear_dialogues: table
+                .render(include_str!("postgres_storage/queries/clear_dialogues.sql")),
+        }
+    }
+}
+
+/// Layouts of the dialogue table, see [`sql::migrate`].
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        sql: include_str!("postgres_storage/migrations/0001_create_table.sql"),
+    },
+    Migration {
+        version: 2,
+        sql: include_str!("postgres_storage/migrations/0002_add_composite_keys.sql"),
+    },
+    Migration {
+        version: 3,
+        sql: include_str!("postgres_storage/migrations/0003_add_updated_at.sql"),
+    },
+];
+
 impl<S> PostgresStorage<S> {
     /// Opens a connection pool to the [Postgres](https://www.postgresql.org/) database and creates the table
     /// for storing dialogues.
@@ -56,62 +124,173 @@ impl<S> PostgresStorage<S> {
         database_url: &str,
         max_connections: u32,
         serializer: S,
+    ) -> Result<Arc<Self>, PostgresStorageError<Infallible>> {
+        Self::open_with_options(database_url, max_connections, serializer, SqlStorageOptions::new())
+            .await
+    }
+
+    /// Like [`PostgresStorage::open`], but dialogues expire after `ttl` since
+    /// their last update.
+    ///
+    /// See [`ExpiringStorage`].
+    pub async fn open_with_ttl(
+        database_url: &str,
+        max_connections: u32,
+        serializer: S,
+        ttl: Duration,
+    ) -> Result<Arc<Self>, PostgresStorageError<Infallible>> {
+        let options = SqlStorageOptions::new().ttl(ttl);
+        Self::open_with_options(database_url, max_connections, serializer, options).await
+    }
+
+    /// Like [`PostgresStorage::open`], but with the given
+    /// [`SqlStorageOptions`].
+    ///
+    /// If the dialogue table doesn't exist, it's created. If it was created by
+    /// an older version of teloxide, it's upgraded to the current layout.
+    pub async fn open_with_options(
+        database_url: &str,
+        max_connections: u32,
+        serializer: S,
+        options: SqlStorageOptions,
     ) -> Result<Arc<Self>, PostgresStorageError<Infallible>> {
         let pool =
             PgPoolOptions::new().max_connections(max_connections).connect(database_url).await?;
-        sqlx::query(include_str!("postgres_storage/queries/create_teloxide_dialogues.sql"))
-            .execute(&pool)
-            .await?;
+        let table = options.table_name();
+        sql::migrate(&pool, &table, MIGRATIONS).await?;
 
-        Ok(Arc::new(Self { pool, serializer }))
+        Ok(Arc::new(Self { pool, serializer, queries: Queries::new(&table), ttl: options.ttl }))
     }
 
-    async fn get_dialogue(
+    /// Dialogues updated at or before the returned time are expired.
+    fn expiry_cutoff(&self) -> i64 {
+        match self.ttl {
+            Some(ttl) => unix_millis(SystemTime::now()) - ttl.as_millis() as i64,
+            None => i64::MIN,
+        }
+    }
+
+    async fn get_dialogue<K: DialogueKey>(
         self: Arc<Self>,
-        ChatId(chat_id): ChatId,
+        key: K,
     ) -> Result<Option<Vec<u8>>, sqlx::Error> {
         #[derive(sqlx::FromRow)]
         struct DialogueDbRow {
             dialogue: Vec<u8>,
         }
 
-        let bytes = sqlx::query_as::<_, DialogueDbRow>(include_str!(
-            "postgres_storage/queries/get_dialogue.sql"
-        ))
-        .bind(chat_id)
-        .fetch_optional(&self.pool)
-        .await?
-        .map(|r| r.dialogue);
+        let bytes = sqlx::query_as::<_, DialogueDbRow>(&self.queries.get_dialogue)
+            .bind(key.chat_id().0)
+            .bind(K::KIND)
+            .bind(key.sub_id())
+            .bind(self.expiry_cutoff())
+            .fetch_optional(&self.pool)
+            .await?
+            .map(|r| r.dialogue);
 
         Ok(bytes)
     }
 }
 
+impl MigrationBackend for PgPool {
+    type Database = Postgres;
+
+    fn begin_locked<'a>(
+        &'a self,
+        table: &'a TableName,
+    ) -> BoxFuture<'a, Result<Transaction<'static, Postgres>, sqlx::Error>> {
+        Box::pin(async move {
+            let mut tx = self.begin().await?;
+
+            // Concurrent `CREATE TABLE IF NOT EXISTS` may fail as well, so the table is
+            // created under the lock
+            tx.execute("SELECT pg_advisory_xact_lock(hashtext('teloxide_migrations'))").await?;
+            tx.execute(sql::create_migrations_table(table).as_str()).await?;
+
+            Ok(tx)
+        })
+    }
+
+    fn recorded_version<'a>(
+        tx: &'a mut Transaction<'static, Postgres>,
+        table: &'a TableName,
+    ) -> BoxFuture<'a, Result<Option<i64>, sqlx::Error>> {
+        Box::pin(async move {
+            sqlx::query_scalar(&format!(
+                "SELECT version FROM {} WHERE table_name = $1",
+                table.sibling("teloxide_migrations")
+            ))
+            .bind(&table.name)
+            .fetch_optional(&mut **tx)
+            .await
+        })
+    }
+
+    fn columns<'a>(
+        tx: &'a mut Transaction<'static, Postgres>,
+        table: &'a TableName,
+    ) -> BoxFuture<'a, Result<Vec<String>, sqlx::Error>> {
+        Box::pin(
+            sqlx::query_scalar(
+                "SELECT column_name::TEXT FROM information_schema.columns WHERE table_schema = \
+                 COALESCE($1, current_schema()) AND table_name = $2",
+            )
+            .bind(table.schema.as_deref())
+            .bind(&table.name)
+            .fetch_all(&mut **tx),
+        )
+    }
+
+    fn apply<'a>(
+        tx: &'a mut Transaction<'static, Postgres>,
+        table: &'a TableName,
+        sql: Option<String>,
+        version: i64,
+    ) -> BoxFuture<'a, Result<(), sqlx::Error>> {
+        Box::pin(async move {
+            if let Some(sql) = sql {
+                tx.execute(sql.as_str()).await?;
+            }
+            tx.execute(
+                sqlx::query(&format!(
+                    "INSERT INTO {} (table_name, version) VALUES ($1, $2) ON CONFLICT \
+                     (table_name) DO UPDATE SET version = excluded.version",
+                    table.sibling("teloxide_migrations")
+                ))
+                .bind(&table.name)
+                .bind(version),
+            )
+            .await?;
+            Ok(())
+        })
+    }
+}
+
 // FIXME: these methods' bodies are almostly the same as SqliteStorage ones
 // (except actual queries) Maybe combine them somehow?
 
-impl<S, D> Storage<D> for PostgresStorage<S>
+impl<S, D, K> Storage<D, K> for PostgresStorage<S>
 where
+    K: DialogueKey,
     S: Send + Sync + Serializer<D> + 'static,
     D: Send + Serialize + DeserializeOwned + 'static,
     <S as Serializer<D>>::Error: Debug + Display,
 {
     type Error = PostgresStorageError<<S as Serializer<D>>::Error>;
 
-    fn remove_dialogue(
-        self: Arc<Self>,
-        ChatId(chat_id): ChatId,
-    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    fn remove_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<(), Self::Error>>
     where
         D: Send + 'static,
     {
         Box::pin(async move {
-            let deleted_rows_count =
-                sqlx::query(include_str!("postgres_storage/queries/remove_dialogue.sql"))
-                    .bind(chat_id)
-                    .execute(&self.pool)
-                    .await?
-                    .rows_affected();
+            let deleted_rows_count = sqlx::query(&self.queries.remove_dialogue)
+                .bind(key.chat_id().0)
+                .bind(K::KIND)
+                .bind(key.sub_id())
+                .bind(self.expiry_cutoff())
+                .execute(&self.pool)
+                .await?
+                .rows_affected();
 
             if deleted_rows_count == 0 {
                 return Err(PostgresStorageError::DialogueNotFound);
@@ -123,7 +302,7 @@ where
 
     fn update_dialogue(
         self: Arc<Self>,
-        ChatId(chat_id): ChatId,
+        key: K,
         dialogue: D,
     ) -> BoxFuture<'static, Result<(), Self::Error>>
     where
@@ -132,25 +311,185 @@ where
         Box::pin(async move {
             let d =
                 self.serializer.serialize(&dialogue).map_err(PostgresStorageError::SerdeError)?;
-            sqlx::query(include_str!("postgres_storage/queries/update_dialogue.sql"))
-                .bind(chat_id)
+            sqlx::query(&self.queries.update_dialogue)
+                .bind(key.chat_id().0)
+                .bind(K::KIND)
+                .bind(key.sub_id())
                 .bind(d)
+                .bind(unix_millis(SystemTime::now()))
                 .execute(&self.pool)
                 .await?;
             Ok(())
         })
     }
 
-    fn get_dialogue(
-        self: Arc<Self>,
-        chat_id: ChatId,
-    ) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
+    fn get_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
         Box::pin(async move {
             self.clone()
-                .get_dialogue(chat_id)
+                .get_dialogue(key)
                 .await?
                 .map(|d| self.serializer.deserialize(&d).map_err(PostgresStorageError::SerdeError))
                 .transpose()
         })
     }
 }
+
+impl<S, D, K> ExpiringStorage<D, K> for PostgresStorage<S>
+where
+    K: DialogueKey,
+    S: Send + Sync + Serializer<D> + 'static,
+    D: Send + Serialize + DeserializeOwned + 'static,
+    <S as Serializer<D>>::Error: Debug + Display,
+{
+    fn remove_expired(self: Arc<Self>) -> BoxFuture<'static, Result<Vec<K>, Self::Error>> {
+        Box::pin(async move {
+            if self.ttl.is_none() {
+                return Ok(Vec::new());
+            }
+
+            let keys = sqlx::query_as::<_, (i64, i64)>(&self.queries.remove_expired)
+                .bind(K::KIND)
+                .bind(self.expiry_cutoff())
+                .fetch_all(&self.pool)
+                .await?
+                .into_iter()
+                .filter_map(|(chat_id, sub_id)| K::from_parts(ChatId(chat_id), sub_id))
+                .collect();
+
+            Ok(keys)
+        })
+    }
+}
+
+impl<S, D, K> AtomicStorage<D, K> for PostgresStorage<S>
+where
+    K: DialogueKey,
+    S: Send + Sync + Serializer<D> + 'static,
+    D: Send + Serialize + DeserializeOwned + 'static,
+    <S as Serializer<D>>::Error: Debug + Display,
+{
+    fn update_dialogue_with(
+        self: Arc<Self>,
+        key: K,
+        mut f: UpdateFn<D>,
+    ) -> BoxFuture<'static, Result<D, Self::Error>> {
+        Box::pin(async move {
+            loop {
+                let mut tx = self.pool.begin().await?;
+
+                // Expired rows are locked as well, so that they're updated rather than
+                // inserted
+                let row = sqlx::query_as::<_, (Vec<u8>, i64)>(&self.queries.lock_dialogue)
+                    .bind(key.chat_id().0)
+                    .bind(K::KIND)
+                    .bind(key.sub_id())
+                    .fetch_optional(&mut *tx)
+                    .await?;
+                let exists = row.is_some();
+
+                let old = row
+                    .filter(|&(_, updated_at)| updated_at > self.expiry_cutoff())
+                    .map(|(d, _)| {
+                        self.serializer.deserialize(&d).map_err(PostgresStorageError::SerdeError)
+                    })
+                    .transpose()?;
+                let new = f(old);
+                let d =
+                    self.serializer.serialize(&new).map_err(PostgresStorageError::SerdeError)?;
+
+                let query = match exists {
+                    true => &self.queries.update_dialogue,
+                    false => &self.queries.insert_dialogue,
+                };
+                let affected_rows_count = sqlx::query(query)
+                    .bind(key.chat_id().0)
+                    .bind(K::KIND)
+                    .bind(key.sub_id())
+                    .bind(d)
+                    .bind(unix_millis(SystemTime::now()))
+                    .execute(&mut *tx)
+                    .await?
+                    .rows_affected();
+
+                // A missing row can't be locked, so if it was inserted concurrently, the
+                // transaction is rolled back and `f` is retried on top of it
+                if affected_rows_count == 0 {
+                    continue;
+                }
+
+                tx.commit().await?;
+                return Ok(new);
+            }
+        })
+    }
+}
+
+impl<S, D, K> EnumerableStorage<D, K> for PostgresStorage<S>
+where
+    K: DialogueKey,
+    S: Send + Sync + Serializer<D> + 'static,
+    D: Send + Serialize + DeserializeOwned + 'static,
+    <S as Serializer<D>>::Error: Debug + Display + Send,
+{
+    fn list_dialogues(self: Arc<Self>) -> BoxStream<'static, Result<(K, D), Self::Error>> {
+        // Keyset pagination: every page starts after the last key of the previous one
+        stream::try_unfold(Some((i64::MIN, i64::MIN)), move |after| {
+            let this = Arc::clone(&self);
+
+            async move {
+                let Some((chat_id, sub_id)) = after else { return Ok::<_, Self::Error>(None) };
+
+                let rows = sqlx::query_as::<_, (i64, i64, Vec<u8>)>(&this.queries.list_dialogues)
+                    .bind(K::KIND)
+                    .bind(chat_id)
+                    .bind(sub_id)
+                    .bind(this.expiry_cutoff())
+                    .bind(PAGE_SIZE)
+                    .fetch_all(&this.pool)
+                    .await?;
+
+                let next = match rows.last() {
+                    Some(&(chat_id, sub_id, _)) if rows.len() as i64 == PAGE_SIZE => {
+                        Some((chat_id, sub_id))
+                    }
+                    _ => None,
+                };
+                let page: Vec<Result<_, Self::Error>> = rows
+                    .into_iter()
+                    .filter_map(|(chat_id, sub_id, d)| {
+                        Some((K::from_parts(ChatId(chat_id), sub_id)?, d))
+                    })
+                    .map(|(key, d)| {
+                        let dialogue = this
+                            .serializer
+                            .deserialize(&d)
+                            .map_err(PostgresStorageError::SerdeError)?;
+                        Ok((key, dialogue))
+                    })
+                    .collect();
+
+                Ok(Some((stream::iter(page), next)))
+            }
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    fn count(self: Arc<Self>) -> BoxFuture<'static, Result<u64, Self::Error>> {
+        Box::pin(async move {
+            let count: i64 = sqlx::query_scalar(&self.queries.count_dialogues)
+                .bind(K::KIND)
+                .bind(self.expiry_cutoff())
+                .fetch_one(&self.pool)
+                .await?;
+            Ok(count as u64)
+        })
+    }
+
+    fn clear(self: Arc<Self>) -> BoxFuture<'static, Result<(), Self::Error>> {
+        Box::pin(async move {
+            sqlx::query(&self.queries.clear_dialogues).bind(K::KIND).execute(&self.pool).await?;
+            Ok(())
+        })
+    }
+}
@@ -1,4 +1,4 @@
-CREATE TABLE IF NOT EXISTS teloxide_dialogues (
+CREATE TABLE {table} (
     chat_id BIGINT PRIMARY KEY,
     dialogue BYTEA NOT NULL
 )
\ No newline at end of file
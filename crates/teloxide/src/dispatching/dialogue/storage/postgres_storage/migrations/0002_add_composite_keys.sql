@@ -0,0 +1,4 @@
+ALTER TABLE {table} ADD COLUMN key_kind TEXT NOT NULL DEFAULT 'chat';
+ALTER TABLE {table} ADD COLUMN sub_id BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE {table} DROP CONSTRAINT {pkey};
+ALTER TABLE {table} ADD PRIMARY KEY (chat_id, key_kind, sub_id)
\ No newline at end of file
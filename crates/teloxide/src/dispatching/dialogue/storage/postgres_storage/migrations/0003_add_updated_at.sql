@@ -0,0 +1,3 @@
+-- Existing dialogues are considered updated right now
+ALTER TABLE {table} ADD COLUMN updated_at BIGINT NOT NULL DEFAULT 0;
+UPDATE {table} SET updated_at = (extract(epoch FROM now()) * 1000)::BIGINT
\ No newline at end of file
@@ -0,0 +1 @@
+DELETE FROM {table} WHERE key_kind = $1
\ No newline at end of file
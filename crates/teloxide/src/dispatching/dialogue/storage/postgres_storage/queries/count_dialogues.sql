@@ -0,0 +1 @@
+SELECT COUNT(*) FROM {table} WHERE key_kind = $1 AND updated_at > $2
\ No newline at end of file
@@ -1 +1,2 @@
-SELECT dialogue FROM teloxide_dialogues WHERE chat_id = $1
\ No newline at end of file
+SELECT dialogue FROM {table}
+WHERE chat_id = $1 AND key_kind = $2 AND sub_id = $3 AND updated_at > $4
\ No newline at end of file
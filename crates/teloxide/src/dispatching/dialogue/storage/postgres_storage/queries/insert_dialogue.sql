@@ -0,0 +1,2 @@
+INSERT INTO {table} (chat_id, key_kind, sub_id, dialogue, updated_at) VALUES ($1, $2, $3, $4, $5)
+ON CONFLICT(chat_id, key_kind, sub_id) DO NOTHING
\ No newline at end of file
@@ -0,0 +1,3 @@
+SELECT chat_id, sub_id, dialogue FROM {table}
+WHERE key_kind = $1 AND (chat_id, sub_id) > ($2, $3) AND updated_at > $4
+ORDER BY chat_id, sub_id LIMIT $5
\ No newline at end of file
@@ -0,0 +1,2 @@
+SELECT dialogue, updated_at FROM {table}
+WHERE chat_id = $1 AND key_kind = $2 AND sub_id = $3 FOR UPDATE
\ No newline at end of file
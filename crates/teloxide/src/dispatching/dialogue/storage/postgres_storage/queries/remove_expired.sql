@@ -0,0 +1 @@
+DELETE FROM {table} WHERE key_kind = $1 AND updated_at <= $2 RETURNING chat_id, sub_id
\ No newline at end of file
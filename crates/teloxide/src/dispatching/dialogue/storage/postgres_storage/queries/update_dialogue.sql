@@ -1,2 +1,2 @@
-INSERT INTO teloxide_dialogues VALUES ($1, $2)
-ON CONFLICT(chat_id) DO UPDATE SET dialogue=excluded.dialogue
\ No newline at end of file
+INSERT INTO {table} (chat_id, key_kind, sub_id, dialogue, updated_at) VALUES ($1, $2, $3, $4, $5)
+ON CONFLICT(chat_id, key_kind, sub_id) DO UPDATE SET dialogue=excluded.dialogue, updated_at=excluded.updated_at
\ No newline at end of file
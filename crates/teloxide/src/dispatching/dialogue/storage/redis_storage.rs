@@ -1,12 +1,21 @@
-use super::{serializer::Serializer, Storage};
+use super::{
+    expiring_storage::unix_millis, serializer::Serializer, AtomicStorage, EnumerableStorage,
+    ExpiringStorage, Storage, UpdateFn,
+};
+use crate::dispatching::dialogue::DialogueKey;
 use deadpool_redis::{redis, CreatePoolError, PoolError, Runtime};
-use futures::future::BoxFuture;
+use futures::{
+    future::BoxFuture,
+    stream::{self, BoxStream},
+    StreamExt, TryStreamExt,
+};
 use redis::AsyncCommands;
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     convert::Infallible,
     fmt::{Debug, Display},
     sync::Arc,
+    time::{Duration, SystemTime},
 };
 use teloxide_core::types::ChatId;
 use thiserror::Error;
@@ -34,42 +43,92 @@ where
     DialogueNotFound,
 }
 
+/// The number of keys requested from Redis by a single `SCAN`.
+const SCAN_COUNT: usize = 100;
+
 /// A dialogue storage based on [Redis](https://redis.io/).
+///
+/// Dialogues indexed by [`ChatId`] are stored under chat IDs, while dialogues
+/// indexed by other keys are stored under `{chat_id}:{kind}:{sub_id}` keys
+/// (see [`DialogueKey`]). [`EnumerableStorage`] methods treat every such key in
+/// the database as a dialogue, so use a dedicated database if you need to
+/// enumerate dialogues.
 pub struct RedisStorage<S> {
     pool: deadpool_redis::Pool,
     serializer: S,
+    ttl: Option<Duration>,
 }
 
 impl<S> RedisStorage<S> {
     pub async fn open(
         url: &str,
         serializer: S,
+    ) -> Result<Arc<Self>, RedisStorageError<Infallible>> {
+        Self::open_inner(url, serializer, None)
+    }
+
+    /// Like [`RedisStorage::open`], but dialogues expire after `ttl` since
+    /// their last update.
+    ///
+    /// Keys of dialogues are set to expire natively, while their deadlines are
+    /// also recorded in the `teloxide_dialogues_expiry:{kind}` sorted sets, so
+    /// that [`ExpiringStorage::remove_expired`] can report them.
+    pub async fn open_with_ttl(
+        url: &str,
+        serializer: S,
+        ttl: Duration,
+    ) -> Result<Arc<Self>, RedisStorageError<Infallible>> {
+        Self::open_inner(url, serializer, Some(ttl))
+    }
+
+    fn open_inner(
+        url: &str,
+        serializer: S,
+        ttl: Option<Duration>,
     ) -> Result<Arc<Self>, RedisStorageError<Infallible>> {
         let config = deadpool_redis::Config::from_url(url);
         let pool = config.create_pool(Some(Runtime::Tokio1))?;
 
-        Ok(Arc::new(Self { pool, serializer }))
+        Ok(Arc::new(Self { pool, serializer, ttl }))
+    }
+
+    /// Returns a transaction which sets a dialogue under `key` and, if there's
+    /// a TTL, makes it expire.
+    fn set_pipe<K: DialogueKey>(&self, key: &str, dialogue: Vec<u8>) -> redis::Pipeline {
+        let mut pipe = redis::pipe();
+        pipe.atomic().set(key, dialogue).ignore();
+
+        if let Some(ttl) = self.ttl {
+            let deadline = unix_millis(SystemTime::now() + ttl);
+            pipe.pexpire(key, ttl.as_millis() as i64)
+                .ignore()
+                .zadd(expiry_index::<K>(), key, deadline)
+                .ignore();
+        }
+
+        pipe
     }
 }
 
-impl<S, D> Storage<D> for RedisStorage<S>
+impl<S, D, K> Storage<D, K> for RedisStorage<S>
 where
+    K: DialogueKey,
     S: Send + Sync + Serializer<D> + 'static,
     D: Send + Serialize + DeserializeOwned + 'static,
     <S as Serializer<D>>::Error: Debug + Display,
 {
     type Error = RedisStorageError<<S as Serializer<D>>::Error>;
 
-    fn remove_dialogue(
-        self: Arc<Self>,
-        ChatId(chat_id): ChatId,
-    ) -> BoxFuture<'static, Result<(), Self::Error>> {
+    fn remove_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<(), Self::Error>> {
         Box::pin(async move {
             let mut conn = self.pool.get().await?;
 
+            let key = redis_key(key);
             let deleted_rows_count = redis::pipe()
                 .atomic()
-                .del(chat_id)
+                .del(&key)
+                .zrem(expiry_index::<K>(), &key)
+                .ignore()
                 .query_async::<_, redis::Value>(&mut conn)
                 .await?;
 
@@ -90,29 +149,277 @@ where
 
     fn update_dialogue(
         self: Arc<Self>,
-        ChatId(chat_id): ChatId,
+        key: K,
         dialogue: D,
     ) -> BoxFuture<'static, Result<(), Self::Error>> {
         Box::pin(async move {
             let dialogue =
                 self.serializer.serialize(&dialogue).map_err(RedisStorageError::SerdeError)?;
-            () = self.pool.get().await?.set::<_, Vec<u8>, _>(chat_id, dialogue).await?;
+            let mut conn = self.pool.get().await?;
+
+            () = self.set_pipe::<K>(&redis_key(key), dialogue).query_async(&mut conn).await?;
             Ok(())
         })
     }
 
-    fn get_dialogue(
-        self: Arc<Self>,
-        ChatId(chat_id): ChatId,
-    ) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
+    fn get_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
         Box::pin(async move {
             self.pool
                 .get()
                 .await?
-                .get::<_, Option<Vec<u8>>>(chat_id)
+                .get::<_, Option<Vec<u8>>>(redis_key(key))
                 .await?
                 .map(|d| self.serializer.deserialize(&d).map_err(RedisStorageError::SerdeError))
                 .transpose()
         })
     }
 }
+
+impl<S, D, K> ExpiringStorage<D, K> for RedisStorage<S>
+where
+    K: DialogueKey,
+    S: Send + Sync + Serializer<D> + 'static,
+    D: Send + Serialize + DeserializeOwned + 'static,
+    <S as Serializer<D>>::Error: Debug + Display,
+{
+    fn remove_expired(self: Arc<Self>) -> BoxFuture<'static, Result<Vec<K>, Self::Error>> {
+        Box::pin(async move {
+            // Keys themselves are already deleted by Redis, so only the index needs
+            // to be cleaned up. A script makes sure that a dialogue updated in the
+            // meantime is not reported.
+            let expired: Vec<String> = redis::cmd("EVAL")
+                .arg(
+                    "local expired = redis.call('ZRANGEBYSCORE', KEYS[1], '-inf', ARGV[1])
+                    redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', ARGV[1])
+                    return expired",
+                )
+                .arg(1)
+                .arg(expiry_index::<K>())
+                .arg(unix_millis(SystemTime::now()))
+                .query_async(&mut self.pool.get().await?)
+                .await?;
+
+            Ok(expired.iter().filter_map(|key| parse_redis_key(key)).collect())
+        })
+    }
+}
+
+impl<S, D, K> AtomicStorage<D, K> for RedisStorage<S>
+where
+    K: DialogueKey,
+    S: Send + Sync + Serializer<D> + 'static,
+    D: Send + Serialize + DeserializeOwned + 'static,
+    <S as Serializer<D>>::Error: Debug + Display + Send,
+{
+    /// Uses optimistic locking: the dialogue is `WATCH`ed while `f` is called,
+    /// and `f` is retried if the dialogue was changed before it was set.
+    fn update_dialogue_with(
+        self: Arc<Self>,
+        key: K,
+        mut f: UpdateFn<D>,
+    ) -> BoxFuture<'static, Result<D, Self::Error>> {
+        Box::pin(async move {
+            let key = redis_key(key);
+            let mut conn = self.pool.get().await?;
+
+            let res: Result<D, Self::Error> = async {
+                loop {
+                    () = redis::cmd("WATCH").arg(&key).query_async(&mut conn).await?;
+
+                    let old = conn.get::<_, Option<Vec<u8>>>(&key).await?;
+                    let old = old
+                        .map(|d| self.serializer.deserialize(&d))
+                        .transpose()
+                        .map_err(RedisStorageError::SerdeError)?;
+                    let new = f(old);
+                    let dialogue =
+                        self.serializer.serialize(&new).map_err(RedisStorageError::SerdeError)?;
+
+                    // `EXEC` replies with nil if the watched key was modified
+                    let committed: Option<()> =
+                        self.set_pipe::<K>(&key, dialogue).query_async(&mut conn).await?;
+                    if committed.is_some() {
+                        return Ok(new);
+                    }
+                }
+            }
+            .await;
+
+            // Otherwise the connection could return to the pool with the key watched,
+            // aborting the next transaction on it. If even `UNWATCH` fails, the
+            // connection is detached from the pool and closed.
+            if res.is_err() && redis::cmd("UNWATCH").query_async::<_, ()>(&mut conn).await.is_err()
+            {
+                drop(deadpool_redis::Connection::take(conn));
+            }
+
+            res
+        })
+    }
+}
+
+impl<S, D, K> EnumerableStorage<D, K> for RedisStorage<S>
+where
+    K: DialogueKey,
+    S: Send + Sync + Serializer<D> + 'static,
+    D: Send + Serialize + DeserializeOwned + 'static,
+    <S as Serializer<D>>::Error: Debug + Display + Send,
+{
+    /// Note that `SCAN` may return a key more than once, so the stream may
+    /// contain duplicates if the database is modified while it's consumed.
+    fn list_dialogues(self: Arc<Self>) -> BoxStream<'static, Result<(K, D), Self::Error>> {
+        stream::try_unfold(Some(0), move |cursor| {
+            let this = Arc::clone(&self);
+
+            async move {
+                let Some(cursor) = cursor else { return Ok::<_, Self::Error>(None) };
+                let mut conn = this.pool.get().await?;
+
+                let (next, keys) = scan_dialogue_keys::<K>(&mut conn, cursor).await?;
+                let values: Vec<Option<Vec<u8>>> = if keys.is_empty() {
+                    Vec::new()
+                } else {
+                    redis::cmd("MGET")
+                        .arg(keys.iter().map(|(key, _)| key).collect::<Vec<_>>())
+                        .query_async(&mut conn)
+                        .await?
+                };
+
+                // Dialogues removed after the scan are skipped
+                let page: Vec<Result<_, Self::Error>> = keys
+                    .into_iter()
+                    .zip(values)
+                    .filter_map(|((_, key), value)| Some((key, value?)))
+                    .map(|(key, d)| {
+                        let dialogue = this
+                            .serializer
+                            .deserialize(&d)
+                            .map_err(RedisStorageError::SerdeError)?;
+                        Ok((key, dialogue))
+                    })
+                    .collect();
+
+                Ok(Some((stream::iter(page), (next != 0).then_some(next))))
+            }
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    fn count(self: Arc<Self>) -> BoxFuture<'static, Result<u64, Self::Error>> {
+        Box::pin(async move {
+            let mut conn = self.pool.get().await?;
+            let (mut cursor, mut count) = (0, 0);
+
+            loop {
+                let (next, keys) = scan_dialogue_keys::<K>(&mut conn, cursor).await?;
+                count += keys.len() as u64;
+
+                match next {
+                    0 => return Ok(count),
+                    next => cursor = next,
+                }
+            }
+        })
+    }
+
+    fn clear(self: Arc<Self>) -> BoxFuture<'static, Result<(), Self::Error>> {
+        Box::pin(async move {
+            let mut conn = self.pool.get().await?;
+            let mut cursor = 0;
+
+            loop {
+                let (next, keys) = scan_dialogue_keys::<K>(&mut conn, cursor).await?;
+                if !keys.is_empty() {
+                    let keys: Vec<_> = keys.into_iter().map(|(key, _)| key).collect();
+                    () = conn.del(keys).await?;
+                }
+
+                match next {
+                    0 => break,
+                    next => cursor = next,
+                }
+            }
+
+            () = conn.del(expiry_index::<K>()).await?;
+            Ok(())
+        })
+    }
+}
+
+/// Runs a single `SCAN` iteration and returns the next cursor along with the
+/// found keys which are dialogue keys of type `K`.
+async fn scan_dialogue_keys<K: DialogueKey>(
+    conn: &mut deadpool_redis::Connection,
+    cursor: u64,
+) -> Result<(u64, Vec<(String, K)>), redis::RedisError> {
+    let (next, keys): (u64, Vec<String>) =
+        redis::cmd("SCAN").arg(cursor).arg("COUNT").arg(SCAN_COUNT).query_async(conn).await?;
+
+    // A key of another type (which has a different kind, see `redis_key`) or not
+    // in the canonical form (e.g. `0123`) doesn't survive a roundtrip
+    let keys = keys
+        .into_iter()
+        .filter_map(|raw| {
+            let key = parse_redis_key::<K>(&raw)?;
+            (redis_key(key) == raw).then_some((raw, key))
+        })
+        .collect();
+
+    Ok((next, keys))
+}
+
+/// Returns the name of a sorted set of keys of expiring dialogues of type `K`,
+/// scored by their deadlines.
+fn expiry_index<K: DialogueKey>() -> String {
+    format!("teloxide_dialogues_expiry:{}", K::KIND)
+}
+
+/// Dialogues indexed by a chat ID alone are stored under the chat ID itself, as
+/// they were before composite keys were introduced.
+fn redis_key<K: DialogueKey>(key: K) -> String {
+    if K::KIND == ChatId::KIND {
+        key.chat_id().to_string()
+    } else {
+        format!("{}:{}:{}", key.chat_id(), K::KIND, key.sub_id())
+    }
+}
+
+fn parse_redis_key<K: DialogueKey>(key: &str) -> Option<K> {
+    let (chat_id, sub_id) = if K::KIND == ChatId::KIND {
+        (key.parse().ok()?, 0)
+    } else {
+        let (chat_id, rest) = key.split_once(':')?;
+        let (kind, sub_id) = rest.split_once(':')?;
+        if kind != K::KIND {
+            return None;
+        }
+        (chat_id.parse().ok()?, sub_id.parse().ok()?)
+    };
+
+    K::from_parts(ChatId(chat_id), sub_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use teloxide_core::types::{MessageId, ThreadId, UserId};
+
+    use super::*;
+
+    #[test]
+    fn keys() {
+        let chat = ChatId(-100);
+        assert_eq!(redis_key(chat), "-100");
+        assert_eq!(redis_key((chat, UserId(0))), "-100:user:0");
+        assert_eq!(redis_key((chat, ThreadId(MessageId(0)))), "-100:thread:0");
+
+        assert_eq!(parse_redis_key::<ChatId>("-100"), Some(chat));
+        assert_eq!(parse_redis_key::<(ChatId, UserId)>("-100:user:7"), Some((chat, UserId(7))));
+
+        // Keys of other types are not parsed
+        assert_eq!(parse_redis_key::<ChatId>("-100:user:0"), None);
+        assert_eq!(parse_redis_key::<(ChatId, UserId)>("-100"), None);
+        assert_eq!(parse_redis_key::<(ChatId, ThreadId)>("-100:user:7"), None);
+        assert_eq!(parse_redis_key::<(ChatId, ThreadId)>("-100:thread:4294967296"), None);
+    }
+}
@@ -1,7 +1,11 @@
 //! Various serializers for dialogue storages.
 
+mod versioned;
+
 use serde::{de::DeserializeOwned, ser::Serialize};
 
+pub use versioned::{DecodeFailure, Versioned, VersionedError};
+
 /// A serializer for memory storages.
 pub trait Serializer<D> {
     type Error;
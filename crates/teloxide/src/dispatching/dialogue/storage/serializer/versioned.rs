@@ -0,0 +1,236 @@
+use std::{collections::HashMap, error::Error};
+
+use thiserror::Error;
+
+use super::Serializer;
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+type Migration<S, D> = Box<dyn Fn(&S, &[u8]) -> Result<D, BoxError> + Send + Sync>;
+
+type Recover<D> = Box<dyn Fn(&DecodeFailure<'_>) -> Option<D> + Send + Sync>;
+
+/// Marks data written by [`Versioned`]. Data without it was written before the
+/// versioning was introduced and has version 0.
+const MAGIC: &[u8; 4] = b"\xF0TXV";
+
+const HEADER_LEN: usize = MAGIC.len() + 4;
+
+/// A serializer which stores a version of the dialogue type along with the
+/// data and upgrades dialogues of older versions.
+///
+/// When you change your dialogue type in an incompatible way, bump the version
+/// and register a [migration](Versioned::migration) from the old one, which
+/// decodes the old type with the inner serializer and converts it to the new
+/// one. Dialogues written by a plain serializer (i.e. before `Versioned` was
+/// used) have version 0.
+///
+/// If a dialogue can't be decoded -- its version is unknown or the data is
+/// malformed -- the storage returns an error by default, so the update is
+/// dropped by [`dialogue::enter`]. Instead, you can [reset] such dialogues to
+/// their default value or [handle] failures by yourself.
+///
+/// ## Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use teloxide::dispatching::dialogue::serializer::{Json, Serializer, Versioned};
+///
+/// #[derive(Serialize, Deserialize)]
+/// enum OldState {
+///     Start,
+///     ReceiveName,
+/// }
+///
+/// #[derive(Debug, PartialEq, Default, Serialize, Deserialize)]
+/// enum State {
+///     #[default]
+///     Start,
+///     ReceiveFullName {
+///         first_name: Option<String>,
+///     },
+/// }
+///
+/// let serializer = Versioned::new(Json, 1)
+///     .migration(0, |old: OldState| match old {
+///         OldState::Start => State::Start,
+///         OldState::ReceiveName => State::ReceiveFullName { first_name: None },
+///     })
+///     .reset_on_failure();
+///
+/// let old = serde_json::to_vec(&OldState::ReceiveName).unwrap();
+/// assert_eq!(serializer.deserialize(&old).unwrap(), State::ReceiveFullName { first_name: None });
+/// assert_eq!(serializer.deserialize(b"garbage").unwrap(), State::Start);
+/// ```
+///
+/// [`dialogue::enter`]: crate::dispatching::dialogue::enter
+/// [reset]: Versioned::reset_on_failure
+/// [handle]: Versioned::on_failure
+#[must_use]
+pub struct Versioned<S, D> {
+    inner: S,
+    version: u32,
+    migrations: HashMap<u32, Migration<S, D>>,
+    recover: Option<Recover<D>>,
+}
+
+/// A dialogue which [`Versioned`] failed to decode.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct DecodeFailure<'a> {
+    /// The version the dialogue was written with.
+    pub version: u32,
+
+    /// The data without the version header.
+    pub data: &'a [u8],
+
+    /// Why the dialogue couldn't be decoded.
+    pub error: &'a VersionedError,
+}
+
+/// An error returned from [`Versioned`].
+#[derive(Debug, Error)]
+pub enum VersionedError {
+    /// The inner serializer or a migration failed.
+    #[error("{0}")]
+    Serializer(BoxError),
+
+    /// There is no migration from the version of a dialogue.
+    #[error("no migration from dialogue version {0}")]
+    UnknownVersion(u32),
+}
+
+impl<S, D> Versioned<S, D> {
+    /// Wraps the `inner` serializer, writing dialogues with `version`.
+    pub fn new(inner: S, version: u32) -> Self {
+        Self { inner, version, migrations: HashMap::new(), recover: None }
+    }
+
+    /// Registers a migration of dialogues written with `version`: they are
+    /// decoded as `Old` and converted by `f`.
+    ///
+    /// ## Panics
+    ///
+    /// If `version` is not less than the current version.
+    pub fn migration<Old, F>(mut self, version: u32, f: F) -> Self
+    where
+        S: Serializer<Old>,
+        <S as Serializer<Old>>::Error: Error + Send + Sync + 'static,
+        F: Fn(Old) -> D + Send + Sync + 'static,
+    {
+        assert!(
+            version < self.version,
+            "a migration from version {version} is not older than the current version {}",
+            self.version
+        );
+
+        let migration = move |inner: &S, data: &[u8]| -> Result<D, BoxError> {
+            Ok(f(inner.deserialize(data)?))
+        };
+        self.migrations.insert(version, Box::new(migration));
+        self
+    }
+
+    /// Returns a default dialogue instead of failing to decode one.
+    pub fn reset_on_failure(self) -> Self
+    where
+        D: Default,
+    {
+        self.on_failure(|_| Some(D::default()))
+    }
+
+    /// Calls `f` if a dialogue can't be decoded. If it returns `None`, the
+    /// error is returned from the storage.
+    pub fn on_failure<F>(self, f: F) -> Self
+    where
+        F: Fn(&DecodeFailure<'_>) -> Option<D> + Send + Sync + 'static,
+    {
+        Self { recover: Some(Box::new(f)), ..self }
+    }
+}
+
+impl<S, D> Serializer<D> for Versioned<S, D>
+where
+    S: Serializer<D>,
+    <S as Serializer<D>>::Error: Error + Send + Sync + 'static,
+{
+    type Error = VersionedError;
+
+    fn serialize(&self, val: &D) -> Result<Vec<u8>, Self::Error> {
+        let data =
+            self.inner.serialize(val).map_err(|err| VersionedError::Serializer(err.into()))?;
+
+        let mut versioned = Vec::with_capacity(HEADER_LEN + data.len());
+        versioned.extend_from_slice(MAGIC);
+        versioned.extend_from_slice(&self.version.to_be_bytes());
+        versioned.extend_from_slice(&data);
+        Ok(versioned)
+    }
+
+    fn deserialize(&self, data: &[u8]) -> Result<D, Self::Error> {
+        let (version, data) = match data.strip_prefix(MAGIC) {
+            Some(rest) if rest.len() >= 4 => {
+                let (version, data) = rest.split_at(4);
+                (u32::from_be_bytes(version.try_into().unwrap()), data)
+            }
+            _ => (0, data),
+        };
+
+        let result = if version == self.version {
+            self.inner.deserialize(data).map_err(|err| VersionedError::Serializer(err.into()))
+        } else {
+            match self.migrations.get(&version) {
+                Some(migration) => migration(&self.inner, data).map_err(VersionedError::Serializer),
+                None => Err(VersionedError::UnknownVersion(version)),
+            }
+        };
+
+        result.or_else(|error| {
+            let failure = DecodeFailure { version, data, error: &error };
+            self.recover.as_ref().and_then(|recover| recover(&failure)).ok_or(error)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dispatching::dialogue::serializer::Json;
+
+    #[test]
+    fn migrations() {
+        let v1 = Versioned::new(Json, 1).migration(0, |old: i32| old.to_string());
+        let v2 = Versioned::new(Json, 2)
+            .migration(0, |old: i32| vec![old.to_string()])
+            .migration(1, |old: String| vec![old]);
+
+        let legacy = Json.serialize(&42).unwrap();
+        assert_eq!(v1.deserialize(&legacy).unwrap(), "42");
+        assert_eq!(v2.deserialize(&legacy).unwrap(), ["42"]);
+
+        let data = v1.serialize(&"ABC".to_owned()).unwrap();
+        assert_eq!(v1.deserialize(&data).unwrap(), "ABC");
+        assert_eq!(v2.deserialize(&data).unwrap(), ["ABC"]);
+
+        // Version 2 is unknown to `v1`
+        let data = v2.serialize(&vec!["ABC".to_owned()]).unwrap();
+        assert!(matches!(v1.deserialize(&data), Err(VersionedError::UnknownVersion(2))));
+    }
+
+    #[test]
+    fn failure_policies() {
+        let data = Versioned::new(Json, 1).serialize(&"ABC".to_owned()).unwrap();
+
+        let error = Versioned::<_, i32>::new(Json, 1);
+        assert!(matches!(error.deserialize(&data), Err(VersionedError::Serializer(_))));
+
+        let reset = Versioned::<_, i32>::new(Json, 1).reset_on_failure();
+        assert_eq!(reset.deserialize(&data).unwrap(), 0);
+
+        let handle = Versioned::new(Json, 1).on_failure(|failure| {
+            (failure.version == 1 && failure.data == b"\"ABC\"").then_some(1)
+        });
+        assert_eq!(handle.deserialize(&data).unwrap(), 1);
+        assert!(matches!(handle.deserialize(b"1"), Err(VersionedError::UnknownVersion(0))));
+    }
+}
@@ -0,0 +1,252 @@
+//! Parts shared by [`SqliteStorage`] and [`PostgresStorage`].
+//!
+//! [`SqliteStorage`]: super::SqliteStorage
+//! [`PostgresStorage`]: super::PostgresStorage
+
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use sqlx::Transaction;
+
+/// Settings of SQL-based dialogue storages.
+///
+/// Used by [`SqliteStorage::open_with_options`] and
+/// [`PostgresStorage::open_with_options`].
+///
+/// [`SqliteStorage::open_with_options`]: super::SqliteStorage::open_with_options
+/// [`PostgresStorage::open_with_options`]: super::PostgresStorage::open_with_options
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct SqlStorageOptions {
+    /// Name of the table in which dialogues are stored.
+    ///
+    /// Default - `teloxide_dialogues`.
+    pub table: String,
+
+    /// Schema in which the table is located.
+    ///
+    /// For SQLite this is a name of an attached database, which must be
+    /// attached on every connection of a pool passed to
+    /// [`SqliteStorage::from_pool`].
+    ///
+    /// [`SqliteStorage::from_pool`]: super::SqliteStorage::from_pool
+    ///
+    /// Default - None, i.e. the current schema (`main` database for SQLite).
+    pub schema: Option<String>,
+
+    /// Time after the last update since which a dialogue is considered
+    /// expired. See [`ExpiringStorage`].
+    ///
+    /// Default - None, i.e. dialogues never expire.
+    ///
+    /// [`ExpiringStorage`]: super::ExpiringStorage
+    pub ttl: Option<Duration>,
+}
+
+impl SqlStorageOptions {
+    /// Constructs default options, see the fields for details.
+    pub fn new() -> Self {
+        Self { table: "teloxide_dialogues".to_owned(), schema: None, ttl: None }
+    }
+
+    /// Specify a custom name of the table.
+    pub fn table(self, table: String) -> Self {
+        Self { table, ..self }
+    }
+
+    /// Specify a schema in which the table is located.
+    pub fn schema(self, schema: String) -> Self {
+        Self { schema: Some(schema), ..self }
+    }
+
+    /// Make dialogues expire after `ttl` since their last update.
+    pub fn ttl(self, ttl: Duration) -> Self {
+        Self { ttl: Some(ttl), ..self }
+    }
+
+    pub(super) fn table_name(&self) -> TableName {
+        TableName { schema: self.schema.clone(), name: self.table.clone() }
+    }
+}
+
+impl Default for SqlStorageOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The number of rows fetched at once by [`EnumerableStorage::list_dialogues`].
+///
+/// [`EnumerableStorage::list_dialogues`]: super::EnumerableStorage::list_dialogues
+pub(super) const PAGE_SIZE: i64 = 100;
+
+/// A possibly schema-qualified name of a table.
+#[derive(Clone, Debug)]
+pub(super) struct TableName {
+    pub(super) schema: Option<String>,
+    pub(super) name: String,
+}
+
+impl TableName {
+    /// Returns the quoted (and qualified, if there is a schema) name, suitable
+    /// for interpolation into queries.
+    pub(super) fn qualified(&self) -> String {
+        self.sibling(&self.name)
+    }
+
+    /// Returns the quoted name of a table in the same schema.
+    pub(super) fn sibling(&self, name: &str) -> String {
+        match &self.schema {
+            Some(schema) => format!("{}.{}", quote(schema), quote(name)),
+            None => quote(name),
+        }
+    }
+
+    /// Substitutes the following placeholders in `sql`:
+    ///
+    ///  - `{table}` -- the qualified name;
+    ///  - `{table_name}` -- the unqualified name;
+    ///  - `{tmp_table}` -- the qualified name of a temporary table to be used
+    ///    while rebuilding the table;
+    ///  - `{pkey}` -- the default name of the primary key constraint in
+    ///    Postgres.
+    pub(super) fn render(&self, sql: &str) -> String {
+        sql.replace("{table}", &self.qualified())
+            .replace("{table_name}", &quote(&self.name))
+            .replace("{tmp_table}", &self.sibling(&format!("{}_tmp", self.name)))
+            .replace("{pkey}", &quote(&format!("{}_pkey", self.name)))
+    }
+}
+
+fn quote(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// A step of the dialogue table layout evolution.
+pub(super) struct Migration {
+    /// Layout version after the migration is applied. Starts from 1 and
+    /// increases by 1 with every migration.
+    pub(super) version: i64,
+
+    /// Statements to run, with placeholders described in
+    /// [`TableName::render`].
+    pub(super) sql: &'static str,
+}
+
+/// Database operations needed by [`migrate`].
+///
+/// Versions of tables are recorded in the `teloxide_migrations` table (in the
+/// same schema), with a row per table.
+pub(super) trait MigrationBackend {
+    type Database: sqlx::Database;
+
+    /// Creates the `teloxide_migrations` table if needed and starts a
+    /// transaction in which no other connection can migrate tables until it
+    /// ends.
+    fn begin_locked<'a>(
+        &'a self,
+        table: &'a TableName,
+    ) -> BoxFuture<'a, Result<Transaction<'static, Self::Database>, sqlx::Error>>;
+
+    /// Returns the recorded version of `table`, if there is one.
+    fn recorded_version<'a>(
+        tx: &'a mut Transaction<'static, Self::Database>,
+        table: &'a TableName,
+    ) -> BoxFuture<'a, Result<Option<i64>, sqlx::Error>>;
+
+    /// Returns names of the columns of `table`, which is empty if the table
+    /// doesn't exist.
+    fn columns<'a>(
+        tx: &'a mut Transaction<'static, Self::Database>,
+        table: &'a TableName,
+    ) -> BoxFuture<'a, Result<Vec<String>, sqlx::Error>>;
+
+    /// Runs `sql` (if any) and records `version`.
+    fn apply<'a>(
+        tx: &'a mut Transaction<'static, Self::Database>,
+        table: &'a TableName,
+        sql: Option<String>,
+        version: i64,
+    ) -> BoxFuture<'a, Result<(), sqlx::Error>>;
+}
+
+/// SQL creating the table recording versions.
+pub(super) fn create_migrations_table(table: &TableName) -> String {
+    format!(
+        "CREATE TABLE IF NOT EXISTS {} (table_name TEXT PRIMARY KEY, version BIGINT NOT NULL)",
+        table.sibling("teloxide_migrations")
+    )
+}
+
+/// Brings `table` up to the last version of `migrations`.
+///
+/// Tables which were created before versions were recorded have their version
+/// inferred from their columns. All of this happens in a single transaction,
+/// so that processes opening the same database at once don't migrate a table
+/// twice.
+pub(super) async fn migrate<B>(
+    backend: &B,
+    table: &TableName,
+    migrations: &[Migration],
+) -> Result<(), sqlx::Error>
+where
+    B: MigrationBackend,
+{
+    let mut tx = backend.begin_locked(table).await?;
+
+    let version = match B::recorded_version(&mut tx, table).await? {
+        Some(version) => version,
+        None => {
+            let version = legacy_version(&B::columns(&mut tx, table).await?);
+            B::apply(&mut tx, table, None, version).await?;
+            version
+        }
+    };
+
+    for migration in migrations.iter().filter(|m| m.version > version) {
+        B::apply(&mut tx, table, Some(table.render(migration.sql)), migration.version).await?;
+    }
+
+    tx.commit().await
+}
+
+/// Infers a version of a table created before versions were recorded.
+fn legacy_version(columns: &[String]) -> i64 {
+    let has = |name| columns.iter().any(|c| c == name);
+
+    if columns.is_empty() {
+        0
+    } else if has("updated_at") {
+        3
+    } else if has("sub_id") {
+        2
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let table =
+            TableName { schema: Some("bot".to_owned()), name: "my \"dialogues\"".to_owned() };
+
+        assert_eq!(
+            table.render("ALTER TABLE {table} RENAME TO {table_name}; DROP TABLE {tmp_table}"),
+            r#"ALTER TABLE "bot"."my ""dialogues""" RENAME TO "my ""dialogues"""; DROP TABLE "bot"."my ""dialogues""_tmp""#
+        );
+    }
+
+    #[test]
+    fn legacy_versions() {
+        let columns = |names: &[&str]| names.iter().map(|&n| n.to_owned()).collect::<Vec<_>>();
+
+        assert_eq!(legacy_version(&[]), 0);
+        assert_eq!(legacy_version(&columns(&["chat_id", "dialogue"])), 1);
+        assert_eq!(legacy_version(&columns(&["chat_id", "sub_id", "dialogue"])), 2);
+        assert_eq!(legacy_version(&columns(&["chat_id", "sub_id", "dialogue", "updated_at"])), 3);
+    }
+}
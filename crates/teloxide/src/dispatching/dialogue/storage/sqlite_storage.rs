@@ -1,12 +1,26 @@
-use super::{serializer::Serializer, Storage};
-use futures::future::BoxFuture;
+use super::{
+    expiring_storage::unix_millis,
+    serializer::Serializer,
+    sql::{self, Migration, MigrationBackend, SqlStorageOptions, TableName, PAGE_SIZE},
+    AtomicStorage, EnumerableStorage, ExpiringStorage, Storage, UpdateFn,
+};
+use crate::dispatching::dialogue::DialogueKey;
+use futures::{
+    future::BoxFuture,
+    stream::{self, BoxStream},
+    StreamExt, TryStreamExt,
+};
 use serde::{de::DeserializeOwned, Serialize};
-use sqlx::{sqlite::SqlitePool, Executor};
+use sqlx::{
+    sqlite::{Sqlite, SqlitePool},
+    Executor, Transaction,
+};
 use std::{
     convert::Infallible,
     fmt::{Debug, Display},
     str,
     sync::Arc,
+    time::{Duration, SystemTime},
 };
 use teloxide_core::types::ChatId;
 use thiserror::Error;
@@ -15,6 +29,8 @@ use thiserror::Error;
 pub struct SqliteStorage<S> {
     pool: SqlitePool,
     serializer: S,
+    table: String,
+    ttl: Option<Duration>,
 }
 
 /// An error returned from [`SqliteStorage`].
@@ -34,29 +50,182 @@ where
     DialogueNotFound,
 }
 
+/// Layouts of the dialogue table, see [`sql::migrate`].
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        sql: "CREATE TABLE {table} (chat_id BIGINT PRIMARY KEY, dialogue BLOB NOT NULL)",
+    },
+    // SQLite can't change a primary key in place, so the table is rebuilt
+    Migration {
+        version: 2,
+        sql: "
+CREATE TABLE {tmp_table} (
+    chat_id BIGINT NOT NULL,
+    key_kind TEXT NOT NULL DEFAULT 'chat',
+    sub_id BIGINT NOT NULL DEFAULT 0,
+    dialogue BLOB NOT NULL,
+    PRIMARY KEY (chat_id, key_kind, sub_id)
+);
+INSERT INTO {tmp_table} (chat_id, sub_id, dialogue) SELECT chat_id, 0, dialogue FROM {table};
+DROP TABLE {table};
+ALTER TABLE {tmp_table} RENAME TO {table_name};
+        ",
+    },
+    // Existing dialogues are considered updated right now
+    Migration {
+        version: 3,
+        sql: "
+ALTER TABLE {table} ADD COLUMN updated_at BIGINT NOT NULL DEFAULT 0;
+UPDATE {table} SET updated_at = CAST(strftime('%s', 'now') AS BIGINT) * 1000;
+        ",
+    },
+];
+
 impl<S> SqliteStorage<S> {
     pub async fn open(
         path: &str,
         serializer: S,
+    ) -> Result<Arc<Self>, SqliteStorageError<Infallible>> {
+        Self::open_with_options(path, serializer, SqlStorageOptions::new()).await
+    }
+
+    /// Like [`SqliteStorage::open`], but dialogues expire after `ttl` since
+    /// their last update.
+    ///
+    /// See [`ExpiringStorage`].
+    pub async fn open_with_ttl(
+        path: &str,
+        serializer: S,
+        ttl: Duration,
+    ) -> Result<Arc<Self>, SqliteStorageError<Infallible>> {
+        Self::open_with_options(path, serializer, SqlStorageOptions::new().ttl(ttl)).await
+    }
+
+    /// Opens a database at `path` with the given [`SqlStorageOptions`].
+    ///
+    /// If the dialogue table doesn't exist, it's created. If it was created by
+    /// an older version of teloxide, it's upgraded to the current layout.
+    pub async fn open_with_options(
+        path: &str,
+        serializer: S,
+        options: SqlStorageOptions,
     ) -> Result<Arc<Self>, SqliteStorageError<Infallible>> {
         let pool = SqlitePool::connect(format!("sqlite:{path}?mode=rwc").as_str()).await?;
-        sqlx::query(
-            "
-CREATE TABLE IF NOT EXISTS teloxide_dialogues (
-    chat_id BIGINT PRIMARY KEY,
-    dialogue BLOB NOT NULL
-);
-        ",
+        Self::from_pool(pool, serializer, options).await
+    }
+
+    /// Like [`SqliteStorage::open_with_options`], but uses an existing
+    /// connection pool.
+    ///
+    /// This is needed to keep dialogues in an attached database (see
+    /// [`SqlStorageOptions::schema`]), since it has to be attached on every
+    /// connection of the pool, e.g. with
+    /// [`SqlitePoolOptions::after_connect`].
+    ///
+    /// [`SqlitePoolOptions::after_connect`]: sqlx::pool::PoolOptions::after_connect
+    pub async fn from_pool(
+        pool: SqlitePool,
+        serializer: S,
+        options: SqlStorageOptions,
+    ) -> Result<Arc<Self>, SqliteStorageError<Infallible>> {
+        let table = options.table_name();
+        sql::migrate(&pool, &table, MIGRATIONS).await?;
+
+        Ok(Arc::new(Self { pool, serializer, table: table.qualified(), ttl: options.ttl }))
+    }
+
+    /// Dialogues updated at or before the returned time are expired.
+    fn expiry_cutoff(&self) -> i64 {
+        match self.ttl {
+            Some(ttl) => unix_millis(SystemTime::now()) - ttl.as_millis() as i64,
+            None => i64::MIN,
+        }
+    }
+}
+
+impl MigrationBackend for SqlitePool {
+    type Database = Sqlite;
+
+    fn begin_locked<'a>(
+        &'a self,
+        table: &'a TableName,
+    ) -> BoxFuture<'a, Result<Transaction<'static, Sqlite>, sqlx::Error>> {
+        Box::pin(async move {
+            self.execute(sql::create_migrations_table(table).as_str()).await?;
+
+            // A deferred transaction which reads first fails if another connection
+            // migrates in the meantime, so the write lock is taken upfront by a no-op
+            // write (waiting for other writers according to the busy timeout).
+            let mut tx = self.begin().await?;
+            tx.execute(
+                sqlx::query(&format!(
+                    "UPDATE {} SET version = version WHERE table_name = ?",
+                    table.sibling("teloxide_migrations")
+                ))
+                .bind(&table.name),
+            )
+            .await?;
+
+            Ok(tx)
+        })
+    }
+
+    fn recorded_version<'a>(
+        tx: &'a mut Transaction<'static, Sqlite>,
+        table: &'a TableName,
+    ) -> BoxFuture<'a, Result<Option<i64>, sqlx::Error>> {
+        Box::pin(async move {
+            sqlx::query_scalar(&format!(
+                "SELECT version FROM {} WHERE table_name = ?",
+                table.sibling("teloxide_migrations")
+            ))
+            .bind(&table.name)
+            .fetch_optional(&mut **tx)
+            .await
+        })
+    }
+
+    fn columns<'a>(
+        tx: &'a mut Transaction<'static, Sqlite>,
+        table: &'a TableName,
+    ) -> BoxFuture<'a, Result<Vec<String>, sqlx::Error>> {
+        Box::pin(
+            sqlx::query_scalar("SELECT name FROM pragma_table_info(?, ?)")
+                .bind(&table.name)
+                .bind(table.schema.as_deref().unwrap_or("main"))
+                .fetch_all(&mut **tx),
         )
-        .execute(&pool)
-        .await?;
+    }
 
-        Ok(Arc::new(Self { pool, serializer }))
+    fn apply<'a>(
+        tx: &'a mut Transaction<'static, Sqlite>,
+        table: &'a TableName,
+        sql: Option<String>,
+        version: i64,
+    ) -> BoxFuture<'a, Result<(), sqlx::Error>> {
+        Box::pin(async move {
+            if let Some(sql) = sql {
+                tx.execute(sql.as_str()).await?;
+            }
+            tx.execute(
+                sqlx::query(&format!(
+                    "INSERT INTO {} (table_name, version) VALUES (?, ?) ON CONFLICT (table_name) \
+                     DO UPDATE SET version = excluded.version",
+                    table.sibling("teloxide_migrations")
+                ))
+                .bind(&table.name)
+                .bind(version),
+            )
+            .await?;
+            Ok(())
+        })
     }
 }
 
-impl<S, D> Storage<D> for SqliteStorage<S>
+impl<S, D, K> Storage<D, K> for SqliteStorage<S>
 where
+    K: DialogueKey,
     S: Send + Sync + Serializer<D> + 'static,
     D: Send + Serialize + DeserializeOwned + 'static,
     <S as Serializer<D>>::Error: Debug + Display,
@@ -64,17 +233,20 @@ where
     type Error = SqliteStorageError<<S as Serializer<D>>::Error>;
 
     /// Returns [`sqlx::Error::RowNotFound`] if a dialogue does not exist.
-    fn remove_dialogue(
-        self: Arc<Self>,
-        ChatId(chat_id): ChatId,
-    ) -> BoxFuture<'static, Result<(), Self::Error>> {
+    fn remove_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<(), Self::Error>> {
         Box::pin(async move {
-            let deleted_rows_count =
-                sqlx::query("DELETE FROM teloxide_dialogues WHERE chat_id = ?")
-                    .bind(chat_id)
-                    .execute(&self.pool)
-                    .await?
-                    .rows_affected();
+            let deleted_rows_count = sqlx::query(&format!(
+                "DELETE FROM {} WHERE chat_id = ? AND key_kind = ? AND sub_id = ? AND updated_at \
+                 > ?",
+                self.table
+            ))
+            .bind(key.chat_id().0)
+            .bind(K::KIND)
+            .bind(key.sub_id())
+            .bind(self.expiry_cutoff())
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
 
             if deleted_rows_count == 0 {
                 return Err(SqliteStorageError::DialogueNotFound);
@@ -86,36 +258,20 @@ where
 
     fn update_dialogue(
         self: Arc<Self>,
-        ChatId(chat_id): ChatId,
+        key: K,
         dialogue: D,
     ) -> BoxFuture<'static, Result<(), Self::Error>> {
         Box::pin(async move {
             let d = self.serializer.serialize(&dialogue).map_err(SqliteStorageError::SerdeError)?;
 
-            self.pool
-                .acquire()
-                .await?
-                .execute(
-                    sqlx::query(
-                        "
-            INSERT INTO teloxide_dialogues VALUES (?, ?)
-            ON CONFLICT(chat_id) DO UPDATE SET dialogue=excluded.dialogue
-                                ",
-                    )
-                    .bind(chat_id)
-                    .bind(d),
-                )
-                .await?;
+            update_dialogue(&self.pool, &self.table, key, d).await?;
             Ok(())
         })
     }
 
-    fn get_dialogue(
-        self: Arc<Self>,
-        chat_id: ChatId,
-    ) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
+    fn get_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
         Box::pin(async move {
-            get_dialogue(&self.pool, chat_id)
+            get_dialogue(&self.pool, &self.table, key, self.expiry_cutoff())
                 .await?
                 .map(|d| self.serializer.deserialize(&d).map_err(SqliteStorageError::SerdeError))
                 .transpose()
@@ -123,22 +279,351 @@ where
     }
 }
 
-async fn get_dialogue(
-    pool: &SqlitePool,
-    ChatId(chat_id): ChatId,
-) -> Result<Option<Vec<u8>>, sqlx::Error> {
+impl<S, D, K> ExpiringStorage<D, K> for SqliteStorage<S>
+where
+    K: DialogueKey,
+    S: Send + Sync + Serializer<D> + 'static,
+    D: Send + Serialize + DeserializeOwned + 'static,
+    <S as Serializer<D>>::Error: Debug + Display,
+{
+    fn remove_expired(self: Arc<Self>) -> BoxFuture<'static, Result<Vec<K>, Self::Error>> {
+        Box::pin(async move {
+            if self.ttl.is_none() {
+                return Ok(Vec::new());
+            }
+
+            let keys = sqlx::query_as::<_, (i64, i64)>(&format!(
+                "DELETE FROM {} WHERE key_kind = ? AND updated_at <= ? RETURNING chat_id, sub_id",
+                self.table
+            ))
+            .bind(K::KIND)
+            .bind(self.expiry_cutoff())
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .filter_map(|(chat_id, sub_id)| K::from_parts(ChatId(chat_id), sub_id))
+            .collect();
+
+            Ok(keys)
+        })
+    }
+}
+
+impl<S, D, K> AtomicStorage<D, K> for SqliteStorage<S>
+where
+    K: DialogueKey,
+    S: Send + Sync + Serializer<D> + 'static,
+    D: Send + Serialize + DeserializeOwned + 'static,
+    <S as Serializer<D>>::Error: Debug + Display,
+{
+    fn update_dialogue_with(
+        self: Arc<Self>,
+        key: K,
+        mut f: UpdateFn<D>,
+    ) -> BoxFuture<'static, Result<D, Self::Error>> {
+        Box::pin(async move {
+            let mut tx = self.pool.begin().await?;
+
+            // A deferred transaction which reads first fails if another connection
+            // writes in the meantime, so the write lock is taken upfront by a no-op
+            // write (waiting for other writers according to the busy timeout).
+            tx.execute(
+                sqlx::query(&format!(
+                    "UPDATE {} SET dialogue = dialogue WHERE chat_id = ? AND key_kind = ? AND \
+                     sub_id = ?",
+                    self.table
+                ))
+                .bind(key.chat_id().0)
+                .bind(K::KIND)
+                .bind(key.sub_id()),
+            )
+            .await?;
+
+            let old = get_dialogue(&mut *tx, &self.table, key, self.expiry_cutoff())
+                .await?
+                .map(|d| self.serializer.deserialize(&d).map_err(SqliteStorageError::SerdeError))
+                .transpose()?;
+            let new = f(old);
+            let d = self.serializer.serialize(&new).map_err(SqliteStorageError::SerdeError)?;
+
+            update_dialogue(&mut *tx, &self.table, key, d).await?;
+            tx.commit().await?;
+
+            Ok(new)
+        })
+    }
+}
+
+impl<S, D, K> EnumerableStorage<D, K> for SqliteStorage<S>
+where
+    K: DialogueKey,
+    S: Send + Sync + Serializer<D> + 'static,
+    D: Send + Serialize + DeserializeOwned + 'static,
+    <S as Serializer<D>>::Error: Debug + Display + Send,
+{
+    fn list_dialogues(self: Arc<Self>) -> BoxStream<'static, Result<(K, D), Self::Error>> {
+        // Keyset pagination: every page starts after the last key of the previous one
+        stream::try_unfold(Some((i64::MIN, i64::MIN)), move |after| {
+            let this = Arc::clone(&self);
+
+            async move {
+                let Some((chat_id, sub_id)) = after else { return Ok::<_, Self::Error>(None) };
+
+                let rows = sqlx::query_as::<_, (i64, i64, Vec<u8>)>(&format!(
+                    "SELECT chat_id, sub_id, dialogue FROM {} WHERE key_kind = ? AND (chat_id, \
+                     sub_id) > (?, ?) AND updated_at > ? ORDER BY chat_id, sub_id LIMIT ?",
+                    this.table
+                ))
+                .bind(K::KIND)
+                .bind(chat_id)
+                .bind(sub_id)
+                .bind(this.expiry_cutoff())
+                .bind(PAGE_SIZE)
+                .fetch_all(&this.pool)
+                .await?;
+
+                let next = match rows.last() {
+                    Some(&(chat_id, sub_id, _)) if rows.len() as i64 == PAGE_SIZE => {
+                        Some((chat_id, sub_id))
+                    }
+                    _ => None,
+                };
+                let page: Vec<Result<_, Self::Error>> = rows
+                    .into_iter()
+                    .filter_map(|(chat_id, sub_id, d)| {
+                        Some((K::from_parts(ChatId(chat_id), sub_id)?, d))
+                    })
+                    .map(|(key, d)| {
+                        let dialogue = this
+                            .serializer
+                            .deserialize(&d)
+                            .map_err(SqliteStorageError::SerdeError)?;
+                        Ok((key, dialogue))
+                    })
+                    .collect();
+
+                Ok(Some((stream::iter(page), next)))
+            }
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    fn count(self: Arc<Self>) -> BoxFuture<'static, Result<u64, Self::Error>> {
+        Box::pin(async move {
+            let count: i64 = sqlx::query_scalar(&format!(
+                "SELECT COUNT(*) FROM {} WHERE key_kind = ? AND updated_at > ?",
+                self.table
+            ))
+            .bind(K::KIND)
+            .bind(self.expiry_cutoff())
+            .fetch_one(&self.pool)
+            .await?;
+            Ok(count as u64)
+        })
+    }
+
+    fn clear(self: Arc<Self>) -> BoxFuture<'static, Result<(), Self::Error>> {
+        Box::pin(async move {
+            sqlx::query(&format!("DELETE FROM {} WHERE key_kind = ?", self.table))
+                .bind(K::KIND)
+                .execute(&self.pool)
+                .await?;
+            Ok(())
+        })
+    }
+}
+
+async fn get_dialogue<'e, K, E>(
+    executor: E,
+    table: &str,
+    key: K,
+    expiry_cutoff: i64,
+) -> Result<Option<Vec<u8>>, sqlx::Error>
+where
+    K: DialogueKey,
+    E: Executor<'e, Database = sqlx::Sqlite>,
+{
     #[derive(sqlx::FromRow)]
     struct DialogueDbRow {
         dialogue: Vec<u8>,
     }
 
-    let bytes = sqlx::query_as::<_, DialogueDbRow>(
-        "SELECT dialogue FROM teloxide_dialogues WHERE chat_id = ?",
-    )
-    .bind(chat_id)
-    .fetch_optional(pool)
+    let bytes = sqlx::query_as::<_, DialogueDbRow>(&format!(
+        "SELECT dialogue FROM {table} WHERE chat_id = ? AND key_kind = ? AND sub_id = ? AND \
+         updated_at > ?"
+    ))
+    .bind(key.chat_id().0)
+    .bind(K::KIND)
+    .bind(key.sub_id())
+    .bind(expiry_cutoff)
+    .fetch_optional(executor)
     .await?
     .map(|r| r.dialogue);
 
     Ok(bytes)
 }
+
+async fn update_dialogue<'e, K, E>(
+    executor: E,
+    table: &str,
+    key: K,
+    dialogue: Vec<u8>,
+) -> Result<(), sqlx::Error>
+where
+    K: DialogueKey,
+    E: Executor<'e, Database = sqlx::Sqlite>,
+{
+    sqlx::query(&format!(
+        "
+INSERT INTO {table} (chat_id, key_kind, sub_id, dialogue, updated_at) VALUES (?, ?, ?, ?, ?)
+ON CONFLICT(chat_id, key_kind, sub_id) DO UPDATE SET dialogue=excluded.dialogue, \
+         updated_at=excluded.updated_at
+        "
+    ))
+    .bind(key.chat_id().0)
+    .bind(K::KIND)
+    .bind(key.sub_id())
+    .bind(dialogue)
+    .bind(unix_millis(SystemTime::now()))
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dispatching::dialogue::serializer::Json;
+
+    #[tokio::test]
+    async fn upgrades_legacy_table() {
+        let path =
+            std::env::temp_dir().join(format!("teloxide_legacy_{}.sqlite", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let pool = SqlitePool::connect(&format!("sqlite:{path}?mode=rwc")).await.unwrap();
+        pool.execute(
+            "
+CREATE TABLE teloxide_dialogues (chat_id BIGINT PRIMARY KEY, dialogue BLOB NOT NULL);
+INSERT INTO teloxide_dialogues VALUES (1, '\"ABC\"');
+            ",
+        )
+        .await
+        .unwrap();
+        pool.close().await;
+
+        let storage = SqliteStorage::open(path, Json).await.unwrap();
+        let dialogue: Option<String> = Arc::clone(&storage).get_dialogue(ChatId(1)).await.unwrap();
+        assert_eq!(dialogue.as_deref(), Some("ABC"));
+
+        let version: i64 =
+            sqlx::query_scalar("SELECT version FROM teloxide_migrations WHERE table_name = ?")
+                .bind("teloxide_dialogues")
+                .fetch_one(&storage.pool)
+                .await
+                .unwrap();
+        assert_eq!(version, MIGRATIONS.last().unwrap().version);
+
+        // Opening an up-to-date table changes nothing
+        let storage = SqliteStorage::open(path, Json).await.unwrap();
+        let dialogue: Option<String> = Arc::clone(&storage).get_dialogue(ChatId(1)).await.unwrap();
+        assert_eq!(dialogue.as_deref(), Some("ABC"));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn concurrent_upgrades() {
+        let path =
+            std::env::temp_dir().join(format!("teloxide_concurrent_{}.sqlite", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let pool = SqlitePool::connect(&format!("sqlite:{path}?mode=rwc")).await.unwrap();
+        pool.execute(
+            "
+CREATE TABLE teloxide_dialogues (chat_id BIGINT PRIMARY KEY, dialogue BLOB NOT NULL);
+INSERT INTO teloxide_dialogues VALUES (1, '\"ABC\"');
+            ",
+        )
+        .await
+        .unwrap();
+        pool.close().await;
+
+        // Every storage has its own pool, like separate processes would
+        let storages =
+            futures::future::try_join_all((0..4).map(|_| SqliteStorage::open(path, Json)))
+                .await
+                .unwrap();
+        for storage in storages {
+            let dialogue: Option<String> = storage.get_dialogue(ChatId(1)).await.unwrap();
+            assert_eq!(dialogue.as_deref(), Some("ABC"));
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn custom_table() {
+        let path =
+            std::env::temp_dir().join(format!("teloxide_custom_{}.sqlite", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let options = SqlStorageOptions::new().table("bot dialogues".to_owned());
+        let storage = SqliteStorage::open_with_options(path, Json, options).await.unwrap();
+        Arc::clone(&storage).update_dialogue(ChatId(1), "ABC".to_owned()).await.unwrap();
+
+        let count: i64 = sqlx::query_scalar(r#"SELECT COUNT(*) FROM "bot dialogues""#)
+            .fetch_one(&storage.pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn attached_database() {
+        use sqlx::sqlite::SqlitePoolOptions;
+
+        let dir = std::env::temp_dir();
+        let main = dir.join(format!("teloxide_main_{}.sqlite", std::process::id()));
+        let other = dir.join(format!("teloxide_attached_{}.sqlite", std::process::id()));
+        let (main, other) = (main.to_str().unwrap(), other.to_str().unwrap());
+
+        let pool = SqlitePoolOptions::new()
+            .after_connect({
+                let other = other.to_owned();
+                move |conn, _| {
+                    let other = other.clone();
+                    Box::pin(async move {
+                        sqlx::query("ATTACH DATABASE ? AS other").bind(other).execute(conn).await?;
+                        Ok(())
+                    })
+                }
+            })
+            .connect(&format!("sqlite:{main}?mode=rwc"))
+            .await
+            .unwrap();
+
+        let options = SqlStorageOptions::new().schema("other".to_owned());
+        let storage = SqliteStorage::from_pool(pool, Json, options).await.unwrap();
+        Arc::clone(&storage).update_dialogue(ChatId(1), "ABC".to_owned()).await.unwrap();
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM other.teloxide_dialogues")
+            .fetch_one(&storage.pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+        let main_tables: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM main.sqlite_master")
+            .fetch_one(&storage.pool)
+            .await
+            .unwrap();
+        assert_eq!(main_tables, 0);
+
+        storage.pool.close().await;
+        std::fs::remove_file(main).unwrap();
+        std::fs::remove_file(other).unwrap();
+    }
+}
@@ -1,9 +1,9 @@
 use std::{fmt::Debug, sync::Arc};
 
-use futures::future::BoxFuture;
-use teloxide_core::types::ChatId;
-
-use crate::dispatching::dialogue::Storage;
+use crate::dispatching::dialogue::{
+    AtomicStorage, EnumerableStorage, ExpiringStorage, Storage, UpdateFn,
+};
+use futures::{future::BoxFuture, stream::BoxStream};
 
 /// A dialogue storage wrapper which logs all actions performed on an underlying
 /// storage.
@@ -25,45 +25,99 @@ impl<S> TraceStorage<S> {
     }
 }
 
-impl<S, D> Storage<D> for TraceStorage<S>
+impl<S, D, K> Storage<D, K> for TraceStorage<S>
 where
     D: Debug,
-    S: Storage<D> + Send + Sync + 'static,
+    K: Debug + Send + 'static,
+    S: Storage<D, K> + Send + Sync + 'static,
 {
-    type Error = <S as Storage<D>>::Error;
+    type Error = <S as Storage<D, K>>::Error;
 
-    fn remove_dialogue(
-        self: Arc<Self>,
-        chat_id: ChatId,
-    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    fn remove_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<(), Self::Error>>
     where
         D: Send + 'static,
     {
-        log::trace!("Removing dialogue #{}", chat_id);
-        <S as Storage<D>>::remove_dialogue(self.inner.clone(), chat_id)
+        log::trace!("Removing dialogue {:?}", key);
+        <S as Storage<D, K>>::remove_dialogue(self.inner.clone(), key)
     }
 
     fn update_dialogue(
         self: Arc<Self>,
-        chat_id: ChatId,
+        key: K,
         dialogue: D,
     ) -> BoxFuture<'static, Result<(), Self::Error>>
     where
         D: Send + 'static,
     {
         Box::pin(async move {
+            let key_repr = format!("{key:?}");
             let to = format!("{dialogue:#?}");
-            <S as Storage<D>>::update_dialogue(self.inner.clone(), chat_id, dialogue).await?;
-            log::trace!("Updated a dialogue #{}: {:#?}", chat_id, to);
+            <S as Storage<D, K>>::update_dialogue(self.inner.clone(), key, dialogue).await?;
+            log::trace!("Updated a dialogue {}: {:#?}", key_repr, to);
             Ok(())
         })
     }
 
-    fn get_dialogue(
+    fn get_dialogue(self: Arc<Self>, key: K) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
+        log::trace!("Requested a dialogue {:?}", key);
+        <S as Storage<D, K>>::get_dialogue(self.inner.clone(), key)
+    }
+}
+
+impl<S, D, K> ExpiringStorage<D, K> for TraceStorage<S>
+where
+    D: Debug,
+    K: Debug + Send + 'static,
+    S: ExpiringStorage<D, K> + Send + Sync + 'static,
+{
+    fn remove_expired(self: Arc<Self>) -> BoxFuture<'static, Result<Vec<K>, Self::Error>> {
+        Box::pin(async move {
+            let keys = <S as ExpiringStorage<D, K>>::remove_expired(self.inner.clone()).await?;
+            log::trace!("Removed expired dialogues: {:?}", keys);
+            Ok(keys)
+        })
+    }
+}
+
+impl<S, D, K> EnumerableStorage<D, K> for TraceStorage<S>
+where
+    D: Debug,
+    K: Debug + Send + 'static,
+    S: EnumerableStorage<D, K> + Send + Sync + 'static,
+{
+    fn list_dialogues(self: Arc<Self>) -> BoxStream<'static, Result<(K, D), Self::Error>> {
+        log::trace!("Listing dialogues");
+        <S as EnumerableStorage<D, K>>::list_dialogues(self.inner.clone())
+    }
+
+    fn count(self: Arc<Self>) -> BoxFuture<'static, Result<u64, Self::Error>> {
+        log::trace!("Counting dialogues");
+        <S as EnumerableStorage<D, K>>::count(self.inner.clone())
+    }
+
+    fn clear(self: Arc<Self>) -> BoxFuture<'static, Result<(), Self::Error>> {
+        log::trace!("Removing all dialogues");
+        <S as EnumerableStorage<D, K>>::clear(self.inner.clone())
+    }
+}
+
+impl<S, D, K> AtomicStorage<D, K> for TraceStorage<S>
+where
+    D: Debug + Send + 'static,
+    K: Debug + Send + 'static,
+    S: AtomicStorage<D, K> + Send + Sync + 'static,
+{
+    fn update_dialogue_with(
         self: Arc<Self>,
-        chat_id: ChatId,
-    ) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
-        log::trace!("Requested a dialogue #{}", chat_id);
-        <S as Storage<D>>::get_dialogue(self.inner.clone(), chat_id)
+        key: K,
+        f: UpdateFn<D>,
+    ) -> BoxFuture<'static, Result<D, Self::Error>> {
+        Box::pin(async move {
+            let key_repr = format!("{key:?}");
+            let to = <S as AtomicStorage<D, K>>::update_dialogue_with(self.inner.clone(), key, f)
+                .await?;
+            log::trace!("Atomically updated a dialogue {}: {:#?}", key_repr, to);
+            Ok(to)
+        })
     }
 }
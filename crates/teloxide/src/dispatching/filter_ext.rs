@@ -83,6 +83,7 @@ define_message_ext! {
     (filter_game, Message::game),
     (filter_venue, Message::venue),
     (filter_location, Message::location),
+    (filter_paid_media, Message::paid_media),
     (filter_photo, Message::photo),
     (filter_poll, Message::poll),
     (filter_sticker, Message::sticker),
@@ -110,12 +111,14 @@ define_message_ext! {
     (filter_pinned, Message::pinned_message),
     (filter_invoice, Message::invoice),
     (filter_successful_payment, Message::successful_payment),
+    (filter_refunded_payment, Message::refunded_payment),
     (filter_connected_website, Message::connected_website),
     (filter_write_access_allowed, Message::write_access_allowed),
     (filter_passport_data, Message::passport_data),
     (filter_dice, Message::dice),
     (filter_proximity_alert_triggered, Message::proximity_alert_triggered),
     (filter_boost_added, Message::boost_added),
+    (filter_chat_background_set, Message::chat_background_set),
     (filter_forum_topic_created, Message::forum_topic_created),
     (filter_forum_topic_edited, Message::forum_topic_edited),
     (filter_forum_topic_closed, Message::forum_topic_closed),
@@ -166,6 +169,7 @@ define_update_ext! {
     (filter_callback_query, UpdateKind::CallbackQuery, CallbackQuery),
     (filter_shipping_query, UpdateKind::ShippingQuery, ShippingQuery),
     (filter_pre_checkout_query, UpdateKind::PreCheckoutQuery, PreCheckoutQuery),
+    (filter_purchased_paid_media, UpdateKind::PurchasedPaidMedia, PurchasedPaidMedia),
     (filter_poll, UpdateKind::Poll, Poll),
     (filter_poll_answer, UpdateKind::PollAnswer, PollAnswer),
     (filter_my_chat_member, UpdateKind::MyChatMember, MyChatMember),
@@ -76,6 +76,7 @@ impl EventKind for Kind {
             CallbackQuery,
             ShippingQuery,
             PreCheckoutQuery,
+            PurchasedPaidMedia,
             Poll,
             PollAnswer,
             MyChatMember,
@@ -156,6 +157,7 @@ mod tests {
             CallbackQuery,
             ShippingQuery,
             PreCheckoutQuery,
+            PurchasedPaidMedia,
             Poll,
             PollAnswer,
             MyChatMember,
@@ -179,6 +181,7 @@ mod tests {
                 | CallbackQuery
                 | ShippingQuery
                 | PreCheckoutQuery
+                | PurchasedPaidMedia
                 | Poll
                 | PollAnswer
                 | MyChatMember
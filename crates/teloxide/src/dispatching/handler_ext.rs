@@ -1,6 +1,6 @@
 use crate::{
     dispatching::{
-        dialogue::{GetChatId, Storage},
+        dialogue::{DialogueKey, GetChatId, GetDialogueKey, Storage},
         DpHandlerDescription,
     },
     types::{Me, Message},
@@ -60,6 +60,27 @@ pub trait HandlerExt<Output> {
         <S as Storage<D>>::Error: Debug + Send,
         D: Default + Send + Sync + 'static,
         Upd: GetChatId + Clone + Send + Sync + 'static;
+
+    /// Like [`HandlerExt::enter_dialogue`], but dialogues are indexed by keys
+    /// of type `K` instead of chat IDs.
+    ///
+    /// E.g. with `K = (ChatId, UserId)` each user in a group has their own
+    /// dialogue. If an incoming update has no key of type `K`
+    /// ([`GetDialogueKey::dialogue_key`] returns `None`), the rest of the
+    /// chain will not be executed.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - `Arc<S>`
+    ///  - `Upd`
+    #[must_use]
+    fn enter_dialogue_with_key<Upd, S, D, K>(self) -> Self
+    where
+        S: Storage<D, K> + ?Sized + Send + Sync + 'static,
+        <S as Storage<D, K>>::Error: Debug + Send,
+        D: Default + Send + Sync + 'static,
+        K: DialogueKey,
+        Upd: GetDialogueKey<K> + Clone + Send + Sync + 'static;
 }
 
 impl<Output> HandlerExt<Output> for Handler<'static, DependencyMap, Output, DpHandlerDescription>
@@ -89,6 +110,17 @@ where
     {
         self.chain(super::dialogue::enter::<Upd, S, D, Output>())
     }
+
+    fn enter_dialogue_with_key<Upd, S, D, K>(self) -> Self
+    where
+        S: Storage<D, K> + ?Sized + Send + Sync + 'static,
+        <S as Storage<D, K>>::Error: Debug + Send,
+        D: Default + Send + Sync + 'static,
+        K: DialogueKey,
+        Upd: GetDialogueKey<K> + Clone + Send + Sync + 'static,
+    {
+        self.chain(super::dialogue::enter_with_key::<Upd, S, D, K, Output>())
+    }
 }
 
 /// Returns a handler that accepts a parsed command `C`.
@@ -239,6 +271,7 @@ mod tests {
                     sender_boost_count: None,
                     is_from_offline: false,
                     business_connection_id: None,
+                    effect_id: None,
                 }),
             }),
         }
@@ -8,6 +8,8 @@
 //! - [`Polling`] function, which returns a long polling listener with your
 //!   configuration.
 //! - Various functions in the [`webhooks`] module that return webhook listeners
+//! - [`from_updates`] function, which returns a listener yielding a fixed set
+//!   of updates (useful for testing).
 //!
 //! And then you can extract updates from it or pass them directly to a
 //! [`Dispatcher`].
@@ -18,6 +20,7 @@
 //!
 //! [`UpdateListener`]: UpdateListener
 //! [`polling_default`]: polling_default
+//! [`from_updates`]: fn@from_updates
 //! [`Dispatcher`]: crate::dispatching::Dispatcher
 //! [`Box::get_updates`]: crate::requests::Requester::get_updates
 //! [getting updates]: https://core.telegram.org/bots/api#getting-updates
@@ -36,11 +39,13 @@ use crate::{
     types::{AllowedUpdate, Update},
 };
 
+mod from_updates;
 mod polling;
 mod stateful_listener;
 
 #[allow(deprecated)]
 pub use self::{
+    from_updates::from_updates,
     polling::{polling_default, Polling, PollingBuilder, PollingStream},
     stateful_listener::StatefulListener,
 };
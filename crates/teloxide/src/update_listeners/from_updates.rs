@@ -0,0 +1,84 @@
+use std::{convert::Infallible, vec};
+
+use futures::{
+    stream::{self, BoxStream},
+    StreamExt,
+};
+
+use crate::{
+    stop::{mk_stop_token, StopFlag, StopToken},
+    types::Update,
+    update_listeners::{assert_update_listener, AsUpdateStream, UpdateListener},
+};
+
+/// Returns a listener which yields the given updates and then stops.
+///
+/// This is mostly useful for testing: [`Dispatcher::dispatch_with_listener`]
+/// returns once all the updates are handled, so after that you can inspect
+/// what your handlers did (e.g. via [`MockBot::requests`]).
+///
+/// ## Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "testing")]
+/// # async fn f(update: teloxide::types::Update) {
+/// use teloxide::{
+///     dispatching::{Dispatcher, UpdateFilterExt},
+///     error_handlers::LoggingErrorHandler,
+///     prelude::*,
+///     testing::MockBot,
+///     update_listeners,
+/// };
+///
+/// let bot = MockBot::new();
+/// let handler = Update::filter_message().endpoint(|bot: MockBot, msg: Message| async move {
+///     bot.send_message(msg.chat.id, "pong").await?;
+///     respond(())
+/// });
+///
+/// Dispatcher::builder(bot.clone(), handler)
+///     .build()
+///     .dispatch_with_listener(
+///         update_listeners::from_updates([update]),
+///         LoggingErrorHandler::new(),
+///     )
+///     .await;
+///
+/// assert_eq!(bot.requests_of::<teloxide::payloads::SendMessage>()[0].text, "pong");
+/// # }
+/// ```
+///
+/// [`Dispatcher::dispatch_with_listener`]: crate::dispatching::Dispatcher::dispatch_with_listener
+/// [`MockBot::requests`]: https://docs.rs/teloxide-core/latest/teloxide_core/testing/struct.MockBot.html#method.requests
+pub fn from_updates<I>(updates: I) -> impl UpdateListener<Err = Infallible>
+where
+    I: IntoIterator<Item = Update>,
+{
+    let updates = updates.into_iter().collect::<Vec<_>>().into_iter();
+    let (token, flag) = mk_stop_token();
+
+    assert_update_listener(FromUpdates { updates, token, flag })
+}
+
+struct FromUpdates {
+    updates: vec::IntoIter<Update>,
+    token: StopToken,
+    flag: StopFlag,
+}
+
+impl<'a> AsUpdateStream<'a> for FromUpdates {
+    type StreamErr = Infallible;
+    type Stream = BoxStream<'a, Result<Update, Infallible>>;
+
+    fn as_stream(&'a mut self) -> Self::Stream {
+        stream::iter(self.updates.by_ref().map(Ok)).take_until(self.flag.clone()).boxed()
+    }
+}
+
+impl UpdateListener for FromUpdates {
+    type Err = Infallible;
+
+    fn stop_token(&mut self) -> StopToken {
+        self.token.clone()
+    }
+}
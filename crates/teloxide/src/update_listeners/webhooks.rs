@@ -1,7 +1,19 @@
 //!
-use std::net::SocketAddr;
-
-use crate::{requests::Requester, types::InputFile};
+use std::{
+    future::Future,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use tokio::sync::watch;
+
+use crate::{
+    requests::{HasPayload, Requester},
+    types::{AllowedUpdate, InputFile},
+};
 
 /// Options related to setting up webhooks.
 #[must_use]
@@ -58,6 +70,118 @@ pub struct Options {
     ///
     /// Default - `teloxide` will generate a random token.
     pub secret_token: Option<String>,
+
+    /// A list of the types of updates you want to receive.
+    ///
+    /// `teloxide` normally (when using [`Dispatcher`] or [`repl`]s) sets this
+    /// automatically via [`hint_allowed_updates`], calling `set_webhook` again
+    /// if the hinted updates differ.
+    ///
+    /// [`Dispatcher`]: crate::dispatching::Dispatcher
+    /// [`repl`]: fn@crate::repl
+    /// [`hint_allowed_updates`]: crate::update_listeners::UpdateListener::hint_allowed_updates
+    ///
+    /// Default - None (all update types except `chat_member`,
+    /// `message_reaction` and `message_reaction_count`).
+    pub allowed_updates: Option<Vec<AllowedUpdate>>,
+
+    /// Maximum number of received updates that wait to be processed.
+    ///
+    /// When the queue is full, incoming updates are handled according to
+    /// [`queue_full_policy`].
+    ///
+    /// [`queue_full_policy`]: Options::queue_full_policy
+    ///
+    /// Default - [`DEFAULT_QUEUE_CAPACITY`].
+    pub queue_capacity: usize,
+
+    /// What to do with an incoming update when the queue is full.
+    ///
+    /// Default - [`QueueFullPolicy::Wait`].
+    pub queue_full_policy: QueueFullPolicy,
+
+    /// Number of updates that are currently in the queue.
+    ///
+    /// Clone this handle before passing the options to a webhook function to
+    /// observe the queue from your code (for example, to export it as a
+    /// metric).
+    ///
+    /// Default - a new handle.
+    pub queue_depth: QueueDepth,
+
+    /// TLS configuration of the server.
+    ///
+    /// If set, the server spawned by [`axum`] or [`hyper`] terminates TLS
+    /// itself, so no reverse proxy is needed. Lower-level functions leave
+    /// running the server to you, use [`TlsConfig::server_config`] to serve
+    /// webhooks over TLS with them.
+    ///
+    /// Bots served by a single server share its TLS configuration, so
+    /// [`axum_multi`] ignores this field, use [`axum_multi_tls`] instead.
+    ///
+    /// **Note**: if the certificate is self-signed, it must also be uploaded
+    /// with [`certificate`], see [`Options::self_signed_tls`].
+    ///
+    /// [`axum`]: fn@axum
+    /// [`hyper`]: fn@hyper
+    /// [`certificate`]: Options::certificate
+    ///
+    /// Default - None.
+    #[cfg(feature = "webhooks-tls")]
+    pub tls: Option<TlsConfig>,
+}
+
+/// Default value of [`Options::queue_capacity`].
+pub const DEFAULT_QUEUE_CAPACITY: usize = 1024;
+
+/// Policy of handling incoming updates when the webhook queue is full, see
+/// [`Options::queue_full_policy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum QueueFullPolicy {
+    /// Don't respond to Telegram until there is room in the queue.
+    ///
+    /// Telegram won't send more than [`max_connections`] updates at once, so
+    /// this applies backpressure without losing updates.
+    ///
+    /// [`max_connections`]: Options::max_connections
+    #[default]
+    Wait,
+
+    /// Respond with `429 Too Many Requests`, Telegram will redeliver the update
+    /// later.
+    TooManyRequests,
+
+    /// Respond with `503 Service Unavailable`, Telegram will redeliver the
+    /// update later.
+    ServiceUnavailable,
+}
+
+/// A handle reporting the number of updates received by a webhook, but not
+/// yet taken by the update listener.
+///
+/// See [`Options::queue_depth`].
+#[derive(Clone, Debug, Default)]
+pub struct QueueDepth(Arc<AtomicUsize>);
+
+impl QueueDepth {
+    /// Creates a new handle with depth of zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of updates currently in the queue.
+    #[must_use]
+    pub fn get(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn inc(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn dec(&self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
 }
 
 impl Options {
@@ -73,6 +197,12 @@ impl Options {
             max_connections: None,
             drop_pending_updates: false,
             secret_token: None,
+            allowed_updates: None,
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            queue_full_policy: QueueFullPolicy::Wait,
+            queue_depth: QueueDepth::new(),
+            #[cfg(feature = "webhooks-tls")]
+            tls: None,
         }
     }
 
@@ -119,6 +249,71 @@ impl Options {
         Self { secret_token: Some(token), ..self }
     }
 
+    /// A list of the types of updates you want to receive.
+    ///
+    /// ## Note
+    ///
+    /// `teloxide` normally (when using [`Dispatcher`] or [`repl`]s) sets this
+    /// automatically via [`hint_allowed_updates`], so you rarely need to use
+    /// `allowed_updates` explicitly.
+    ///
+    /// [`Dispatcher`]: crate::dispatching::Dispatcher
+    /// [`repl`]: fn@crate::repl
+    /// [`hint_allowed_updates`]: crate::update_listeners::UpdateListener::hint_allowed_updates
+    pub fn allowed_updates(self, allowed_updates: Vec<AllowedUpdate>) -> Self {
+        Self { allowed_updates: Some(allowed_updates), ..self }
+    }
+
+    /// Maximum number of received updates that wait to be processed.
+    ///
+    /// ## Panics
+    ///
+    /// If `capacity` is zero.
+    #[track_caller]
+    pub fn queue_capacity(self, capacity: usize) -> Self {
+        assert!(capacity > 0, "Webhook queue capacity must be greater than zero");
+
+        Self { queue_capacity: capacity, ..self }
+    }
+
+    /// What to do with an incoming update when the queue is full.
+    pub fn queue_full_policy(self, policy: QueueFullPolicy) -> Self {
+        Self { queue_full_policy: policy, ..self }
+    }
+
+    /// Serve webhooks over TLS, see [`Options::tls`].
+    #[cfg(feature = "webhooks-tls")]
+    pub fn tls(self, config: TlsConfig) -> Self {
+        Self { tls: Some(config), ..self }
+    }
+
+    /// Serve webhooks over TLS with a self-signed certificate.
+    ///
+    /// This generates a certificate for the host of [`Options::url`] (a
+    /// domain name or an IP address) and sets it as both [`Options::tls`] and
+    /// [`Options::certificate`], so that it's uploaded with `set_webhook`.
+    ///
+    /// ## Fails
+    ///
+    /// If the url doesn't have a host or the certificate generation fails.
+    #[cfg(feature = "webhooks-tls")]
+    pub fn self_signed_tls(self) -> Result<Self, TlsError> {
+        use url::Host;
+
+        let host = match self.url.host().ok_or(TlsError::NoHost)? {
+            Host::Domain(domain) => domain.to_owned(),
+            Host::Ipv4(ip) => ip.to_string(),
+            Host::Ipv6(ip) => ip.to_string(),
+        };
+
+        let tls = TlsConfig::self_signed(&host)?;
+        let certificate = tls
+            .self_signed_certificate()
+            .map(|pem| InputFile::memory(pem.to_owned()).file_name("certificate.pem"));
+
+        Ok(Self { tls: Some(tls), certificate, ..self })
+    }
+
     /// Returns `self.secret_token`, generating a new one if it's `None`.
     ///
     /// After a call to this function `self.secret_token` is always `Some(_)`.
@@ -131,38 +326,166 @@ impl Options {
     }
 }
 
+pub use self::handler::{handler, handler_no_setup, WebhookHandler, SECRET_TOKEN_HEADER};
+
+#[cfg(feature = "webhooks-tower")]
+pub use self::tower::{tower_no_setup, tower_service, WebhookService};
+
 #[cfg(feature = "webhooks-axum")]
-pub use self::axum::{axum, axum_no_setup, axum_to_router};
+pub use self::axum::{
+    axum, axum_multi, axum_multi_no_setup, axum_multi_to_router, axum_no_setup, axum_to_router,
+};
+
+#[cfg(all(feature = "webhooks-axum", feature = "webhooks-tls"))]
+pub use self::axum::axum_multi_tls;
+
+#[cfg(feature = "webhooks-hyper")]
+pub use self::hyper::hyper;
+
+#[cfg(feature = "webhooks-actix")]
+pub use self::actix::{actix_no_setup, actix_to_config};
+
+#[cfg(feature = "webhooks-tls")]
+pub use self::tls::{TlsConfig, TlsError};
+
+mod handler;
+
+#[cfg(feature = "webhooks-tower")]
+mod tower;
 
 #[cfg(feature = "webhooks-axum")]
 mod axum;
 
+#[cfg(feature = "webhooks-hyper")]
+mod hyper;
+
+#[cfg(feature = "webhooks-actix")]
+mod actix;
+
+#[cfg(feature = "webhooks-tls")]
+mod tls;
+
 // TODO: add different implementation (for example: warp)
 
+/// Sender of allowed updates hinted to a webhook update listener, see
+/// [`UpdateListener::hint_allowed_updates`].
+///
+/// [`UpdateListener::hint_allowed_updates`]: crate::update_listeners::UpdateListener::hint_allowed_updates
+type AllowedUpdatesSender = watch::Sender<Option<Vec<AllowedUpdate>>>;
+
+/// Webhook that was set up by [`setup_webhook`].
+struct WebhookSetup<R> {
+    bot: R,
+    url: url::Url,
+    certificate: Option<InputFile>,
+    max_connections: Option<u8>,
+    secret_token: String,
+    allowed_updates: watch::Receiver<Option<Vec<AllowedUpdate>>>,
+}
+
 /// Calls `set_webhook` with arguments from `options`.
 ///
-/// Note: this takes out `certificate`.
-async fn setup_webhook<R>(bot: R, options: &mut Options) -> Result<(), R::Err>
+/// Returns the webhook and a sender which should receive allowed updates
+/// hinted to the update listener, see [`WebhookSetup::run`].
+async fn setup_webhook<R>(
+    bot: R,
+    options: &mut Options,
+) -> Result<(WebhookSetup<R>, AllowedUpdatesSender), R::Err>
 where
     R: Requester,
 {
     use crate::requests::Request;
-    use teloxide_core::requests::HasPayload;
 
-    let secret = options.get_or_gen_secret_token().to_owned();
-    let &mut Options {
-        ref url, ref mut certificate, max_connections, drop_pending_updates, ..
-    } = options;
+    let secret_token = options.get_or_gen_secret_token().to_owned();
+    let (tx, rx) = watch::channel(options.allowed_updates.clone());
 
-    let mut req = bot.set_webhook(url.clone());
-    req.payload_mut().certificate = certificate.take();
-    req.payload_mut().max_connections = max_connections;
-    req.payload_mut().drop_pending_updates = Some(drop_pending_updates);
-    req.payload_mut().secret_token = Some(secret);
+    let webhook = WebhookSetup {
+        bot,
+        url: options.url.clone(),
+        certificate: options.certificate.clone(),
+        max_connections: options.max_connections,
+        secret_token,
+        allowed_updates: rx,
+    };
 
+    let mut req = webhook.set_webhook(options.allowed_updates.clone());
+    req.payload_mut().drop_pending_updates = Some(options.drop_pending_updates);
     req.send().await?;
 
-    Ok(())
+    Ok((webhook, tx))
+}
+
+impl<R> WebhookSetup<R>
+where
+    R: Requester,
+{
+    /// Returns a future that calls `set_webhook` again whenever the update
+    /// listener is hinted with different allowed updates, and calls
+    /// `delete_webhook` after `stop_flag` is resolved.
+    fn run(mut self, stop_flag: impl Future<Output = ()>) -> impl Future<Output = ()> {
+        use crate::requests::Request;
+        use futures::future::{select, Either};
+
+        async move {
+            let mut stop_flag = std::pin::pin!(stop_flag);
+
+            loop {
+                let changed = {
+                    let changed = std::pin::pin!(self.allowed_updates.changed());
+                    match select(changed, stop_flag.as_mut()).await {
+                        Either::Left((changed, _)) => changed,
+                        Either::Right(_) => break,
+                    }
+                };
+
+                // The update listener was dropped, so there won't be any more hints
+                if changed.is_err() {
+                    stop_flag.as_mut().await;
+                    break;
+                }
+
+                let allowed_updates = self.allowed_updates.borrow_and_update().clone();
+                log::debug!("setting webhook allowed updates: {:?}", allowed_updates);
+
+                let req = self.set_webhook(allowed_updates).send();
+                let res = req.await;
+                if let Err(err) = res {
+                    log::error!("Couldn't set webhook allowed updates: {}", err);
+                }
+            }
+
+            // This assignment is needed to not require `R: Sync` since without it `&bot`
+            // temporary lives across `.await` points.
+            let req = self.bot.delete_webhook().send();
+            let res = req.await;
+            if let Err(err) = res {
+                log::error!("Couldn't delete webhook: {}", err);
+            }
+        }
+    }
+
+    fn set_webhook(&self, allowed_updates: Option<Vec<AllowedUpdate>>) -> R::SetWebhook {
+        let mut req = self.bot.set_webhook(self.url.clone());
+        let payload = req.payload_mut();
+        payload.certificate = self.certificate.clone();
+        payload.max_connections = self.max_connections;
+        payload.secret_token = Some(self.secret_token.clone());
+        payload.allowed_updates = allowed_updates;
+
+        req
+    }
+}
+
+/// Passes allowed updates hinted to an update listener to `hints`.
+fn send_hint(hints: &Option<AllowedUpdatesSender>, hint: &mut dyn Iterator<Item = AllowedUpdate>) {
+    let Some(hints) = hints else { return };
+    let hint: Vec<_> = hint.collect();
+
+    hints.send_if_modified(|allowed_updates| {
+        let modified = allowed_updates.as_ref() != Some(&hint);
+        *allowed_updates = Some(hint);
+        modified
+    });
 }
 
 /// Generates a random string consisting of 32 characters (`a-z`, `A-Z`, `0-9`,
@@ -212,3 +535,11 @@ fn check_secret(bytes: &[u8]) -> Result<&[u8], &'static str> {
 fn tuple_first_mut<A, B>(tuple: &mut (A, B)) -> &mut A {
     &mut tuple.0
 }
+
+/// Options for a webhook at `url` with the `"secret"` secret token, used by
+/// the tests of the webhook implementations.
+#[cfg(test)]
+fn test_options(url: &str) -> Options {
+    Options::new(([127, 0, 0, 1], 8443).into(), url.parse().unwrap())
+        .secret_token("secret".to_owned())
+}
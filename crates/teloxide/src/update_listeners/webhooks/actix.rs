@@ -0,0 +1,149 @@
+use std::{convert::Infallible, future::Future};
+
+use actix_web::{http::StatusCode, web, HttpRequest, HttpResponse};
+
+use crate::{
+    requests::Requester,
+    update_listeners::{
+        webhooks::{handler, handler_no_setup, Options, WebhookHandler, SECRET_TOKEN_HEADER},
+        UpdateListener,
+    },
+};
+
+/// Webhook implementation based on the [actix-web] framework.
+///
+/// This function does most of the work necessary for webhook to work, it:
+/// - Calls [`set_webhook`], so telegram starts sending updates our way
+/// - When the update listener is hinted with [allowed updates], calls
+///   [`set_webhook`] again with them
+/// - When the update listener is [`stop`]ped, calls [`delete_webhook`]
+///
+/// The only missing part is adding the returned configuration function to
+/// your [actix-web] app with [`App::configure`] and running the server.
+///
+/// **Note**: in order for webhooks to work, the server must be bound to
+/// [`options.address`].
+///
+/// [actix-web]: https://docs.rs/actix-web
+/// [`set_webhook`]: crate::payloads::SetWebhook
+/// [`delete_webhook`]: crate::payloads::DeleteWebhook
+/// [`stop`]: crate::stop::StopToken::stop
+/// [allowed updates]: crate::update_listeners::UpdateListener::hint_allowed_updates
+/// [`App::configure`]: actix_web::App::configure
+/// [`options.address`]: Options::address
+///
+/// ## Returns
+///
+/// A update listener, stop-future, configuration function triplet on success.
+///
+/// The "stop-future" is resolved after [`stop`] is called on the stop token of
+/// the returned update listener and the webhook is deleted. It also applies
+/// allowed updates hints, so it should be polled while the server is running.
+///
+/// ## Fails
+///
+/// If `set_webhook()` fails.
+///
+/// ## See also
+///
+/// [`actix_no_setup`] for lower-level version of this function.
+pub async fn actix_to_config<R>(
+    bot: R,
+    options: Options,
+) -> Result<
+    (
+        impl UpdateListener<Err = Infallible>,
+        impl Future<Output = ()> + Send,
+        impl Fn(&mut web::ServiceConfig) + Clone + Send + 'static,
+    ),
+    R::Err,
+>
+where
+    R: Requester + Send,
+    <R as Requester>::DeleteWebhook: Send,
+{
+    let path = options.path.clone();
+    let (listener, stop_flag, handler) = handler(bot, options).await?;
+
+    Ok((listener, stop_flag, config(path, handler)))
+}
+
+/// Webhook implementation based on the [actix-web] framework that doesn't
+/// perform any setup work.
+///
+/// See [`handler_no_setup`] for the description of the stop-future.
+///
+/// [actix-web]: https://docs.rs/actix-web
+///
+/// ## See also
+///
+/// [`actix_to_config`] for higher-level version of this function.
+pub fn actix_no_setup(
+    options: Options,
+) -> (
+    impl UpdateListener<Err = Infallible>,
+    impl Future<Output = ()>,
+    impl Fn(&mut web::ServiceConfig) + Clone + Send + 'static,
+) {
+    let path = options.path.clone();
+    let (listener, stop_flag, handler) = handler_no_setup(options);
+
+    (listener, stop_flag, config(path, handler))
+}
+
+fn config(
+    path: String,
+    handler: WebhookHandler,
+) -> impl Fn(&mut web::ServiceConfig) + Clone + Send + 'static {
+    move |cfg| {
+        let handler = handler.clone();
+        cfg.route(
+            &path,
+            web::post().to(move |req: HttpRequest, body: web::Bytes| {
+                let handler = handler.clone();
+                async move {
+                    let secret_token =
+                        req.headers().get(SECRET_TOKEN_HEADER).map(|header| header.as_bytes());
+                    let status = handler.handle(secret_token, &body).await;
+
+                    // actix-web uses a different version of the `http` crate
+                    let status = StatusCode::from_u16(status.as_u16())
+                        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+                    HttpResponse::new(status)
+                }
+            }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{test, App};
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::update_listeners::{webhooks::test_options, AsUpdateStream};
+
+    #[actix_web::test]
+    async fn handles_requests() {
+        let (mut listener, _stop_flag, config) =
+            actix_no_setup(test_options("https://example.com/webhook"));
+        let app = test::init_service(App::new().configure(config)).await;
+
+        let request = |secret: &str| {
+            test::TestRequest::post()
+                .uri("/webhook")
+                .insert_header((SECRET_TOKEN_HEADER, secret))
+                .set_payload(r#"{"update_id":1,"unknown":{}}"#)
+                .to_request()
+        };
+
+        let response = test::call_service(&app, request("wrong")).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let response = test::call_service(&app, request("secret")).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let update = std::pin::pin!(listener.as_stream()).next().await.unwrap().unwrap();
+        assert_eq!(update.id.0, 1);
+    }
+}
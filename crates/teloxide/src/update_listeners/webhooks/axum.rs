@@ -1,28 +1,40 @@
-use std::{convert::Infallible, future::Future, pin::Pin};
+use std::{convert::Infallible, future::Future, net::SocketAddr, sync::Arc};
 
 use axum::{
-    extract::{FromRequestParts, State},
-    http::{request::Parts, status::StatusCode},
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
 };
-use tokio::sync::mpsc;
 
 use crate::{
     requests::Requester,
-    stop::StopFlag,
-    types::{Update, UpdateKind},
-    update_listeners::{webhooks::Options, UpdateListener},
+    stop::{StopFlag, StopToken},
+    update_listeners::{
+        webhooks::{
+            handler::handler_with_hints, AllowedUpdatesSender, Options, WebhookHandler,
+            SECRET_TOKEN_HEADER,
+        },
+        UpdateListener,
+    },
 };
 
+#[cfg(feature = "webhooks-tls")]
+use crate::update_listeners::webhooks::TlsConfig;
+
 /// Webhook implementation based on the [mod@axum] framework.
 ///
 /// This function does all the work necessary for webhook to work, it:
 /// - Calls [`set_webhook`], so telegram starts sending updates our way
-/// - Spawns [mod@axum] server listening for updates
+/// - Spawns [mod@axum] server listening for updates (over TLS, if
+///   [`Options::tls`] is set)
+/// - When the update listener is hinted with [allowed updates], calls
+///   [`set_webhook`] again with them
 /// - When the update listener is [`stop`]ped, calls [`delete_webhook`]
 ///
 /// [`set_webhook`]: crate::payloads::SetWebhook
 /// [`delete_webhook`]: crate::payloads::DeleteWebhook
 /// [`stop`]: crate::stop::StopToken::stop
+/// [allowed updates]: crate::update_listeners::UpdateListener::hint_allowed_updates
 ///
 /// ## Panics
 ///
@@ -47,6 +59,8 @@ where
     <R as Requester>::DeleteWebhook: Send,
 {
     let Options { address, .. } = options;
+    #[cfg(feature = "webhooks-tls")]
+    let tls = options.tls.clone();
 
     let (mut update_listener, stop_flag, app) = axum_to_router(bot, options).await?;
     let stop_token = update_listener.stop_token();
@@ -56,6 +70,14 @@ where
             .await
             .inspect_err(|_| stop_token.stop())
             .expect("Couldn't bind to the address");
+
+        #[cfg(feature = "webhooks-tls")]
+        if let Some(tls) = tls {
+            use crate::update_listeners::webhooks::hyper::serve_tls;
+
+            return serve_tls(tcp_listener, app, tls, stop_flag).await;
+        }
+
         axum::serve(tcp_listener, app)
             .with_graceful_shutdown(stop_flag)
             .await
@@ -71,6 +93,8 @@ where
 ///
 /// This function does most of the work necessary for webhook to work, it:
 /// - Calls [`set_webhook`], so telegram starts sending updates our way
+/// - When the update listener is hinted with [allowed updates], calls
+///   [`set_webhook`] again with them
 /// - When the update listener is [`stop`]ped, calls [`delete_webhook`]
 ///
 /// The only missing part is running [mod@axum] server with a returned
@@ -89,6 +113,7 @@ where
 /// [`set_webhook`]: crate::payloads::SetWebhook
 /// [`delete_webhook`]: crate::payloads::DeleteWebhook
 /// [`stop`]: crate::stop::StopToken::stop
+/// [allowed updates]: crate::update_listeners::UpdateListener::hint_allowed_updates
 /// [`options.address`]: Options::address
 /// [`with_graceful_shutdown`]: axum::serve::Serve::with_graceful_shutdown
 ///
@@ -97,7 +122,8 @@ where
 /// A update listener, stop-future, axum router triplet on success.
 ///
 /// The "stop-future" is resolved after [`stop`] is called on the stop token of
-/// the returned update listener.
+/// the returned update listener and the webhook is deleted. It also applies
+/// allowed updates hints, so it should be polled while the server is running.
 ///
 /// ## Fails
 ///
@@ -118,29 +144,20 @@ where
     R: Requester + Send,
     <R as Requester>::DeleteWebhook: Send,
 {
-    use crate::{requests::Request, update_listeners::webhooks::setup_webhook};
-    use futures::FutureExt;
-
-    setup_webhook(&bot, &mut options).await?;
-
-    let (listener, stop_flag, router) = axum_no_setup(options);
+    use crate::update_listeners::webhooks::setup_webhook;
 
-    let stop_flag = stop_flag.then(move |()| async move {
-        // This assignment is needed to not require `R: Sync` since without it `&bot`
-        // temporary lives across `.await` points.
-        let req = bot.delete_webhook().send();
-        let res = req.await;
-        if let Err(err) = res {
-            log::error!("Couldn't delete webhook: {}", err);
-        }
-    });
+    let (webhook, hints) = setup_webhook(bot, &mut options).await?;
+    let (listener, stop_flag, router) = router_with_hints(options, Some(hints));
 
-    Ok((listener, stop_flag, router))
+    Ok((listener, webhook.run(stop_flag), router))
 }
 
 /// Webhook implementation based on the [mod@axum] framework that doesn't
 /// perform any setup work.
 ///
+/// Since this function doesn't call `set_webhook`, allowed updates hints of the
+/// returned update listener are ignored.
+///
 /// ## Note about the stop-future
 ///
 /// This function returns a future that is resolved when `.stop()` is called on
@@ -155,146 +172,432 @@ where
 pub fn axum_no_setup(
     options: Options,
 ) -> (impl UpdateListener<Err = Infallible>, impl Future<Output = ()>, axum::Router) {
-    use crate::{
-        stop::{mk_stop_token, StopToken},
-        update_listeners::{webhooks::tuple_first_mut, StatefulListener},
-    };
-    use axum::{response::IntoResponse, routing::post};
-    use tokio_stream::wrappers::UnboundedReceiverStream;
+    router_with_hints(options, None)
+}
+
+fn router_with_hints(
+    options: Options,
+    hints: Option<AllowedUpdatesSender>,
+) -> (impl UpdateListener<Err = Infallible>, StopFlag, axum::Router) {
+    use axum::routing::post;
     use tower_http::trace::TraceLayer;
 
-    let (tx, rx): (UpdateSender, _) = mpsc::unbounded_channel();
+    let path = options.path.clone();
+    let (listener, stop_flag, handler) = handler_with_hints(options, hints);
 
-    async fn telegram_request(
-        State(WebhookState { secret, flag, mut tx }): State<WebhookState>,
-        secret_header: XTelegramBotApiSecretToken,
-        input: String,
-    ) -> impl IntoResponse {
-        // FIXME: use constant time comparison here
-        if secret_header.0.as_deref() != secret.as_deref().map(str::as_bytes) {
-            return StatusCode::UNAUTHORIZED;
-        }
+    let app = axum::Router::new()
+        .route(&path, post(telegram_request))
+        .layer(TraceLayer::new_for_http())
+        .with_state(handler);
 
-        let tx = match tx.get() {
-            None => return StatusCode::SERVICE_UNAVAILABLE,
-            // Do not process updates after `.stop()` is called even if the server is still
-            // running (useful for when you need to stop the bot but can't stop the server).
-            _ if flag.is_stopped() => {
-                tx.close();
-                return StatusCode::SERVICE_UNAVAILABLE;
-            }
-            Some(tx) => tx,
-        };
+    (listener, stop_flag, app)
+}
 
-        match serde_json::from_str::<Update>(&input) {
-            Ok(mut update) => {
-                // See HACK comment in
-                // `teloxide_core::net::request::process_response::{closure#0}`
-                if let UpdateKind::Error(value) = &mut update.kind {
-                    *value = serde_json::from_str(&input).unwrap_or_default();
-                }
+/// Webhook implementation based on the [mod@axum] framework that serves
+/// multiple bots on a single address.
+///
+/// This function does all the work necessary for webhooks to work, it:
+/// - Calls [`set_webhook`] for every bot, so telegram starts sending updates
+///   our way
+/// - Spawns [mod@axum] server listening for updates of all bots on `address`
+/// - When the update listener of a bot is hinted with [allowed updates], calls
+///   [`set_webhook`] again for this bot
+/// - When the update listener of a bot is [`stop`]ped, calls [`delete_webhook`]
+///   for this bot
+/// - Shuts the server down once update listeners of all bots are stopped
+///
+/// Updates are routed to bots by [`Options::path`] and, if several bots share
+/// the same path, by [`Options::secret_token`]. [`Options::address`] and
+/// `Options::tls` of individual bots are ignored, use `axum_multi_tls` to
+/// serve updates over TLS.
+///
+/// Update listeners are returned in the same order as bots.
+///
+/// [`set_webhook`]: crate::payloads::SetWebhook
+/// [`delete_webhook`]: crate::payloads::DeleteWebhook
+/// [`stop`]: crate::stop::StopToken::stop
+/// [allowed updates]: crate::update_listeners::UpdateListener::hint_allowed_updates
+///
+/// ## Panics
+///
+/// If binding to the `address` fails or if two bots have the same path and
+/// secret token.
+///
+/// ## Fails
+///
+/// If `set_webhook()` fails for any of the bots. In this case webhooks of the
+/// bots that were already set up are deleted.
+///
+/// ## See also
+///
+/// [`axum_multi_to_router`] and [`axum_multi_no_setup`] for lower-level
+/// versions of this function.
+pub async fn axum_multi<R, I>(
+    address: SocketAddr,
+    bots: I,
+) -> Result<Vec<impl UpdateListener<Err = Infallible>>, R::Err>
+where
+    R: Requester + Send + 'static,
+    <R as Requester>::DeleteWebhook: Send,
+    I: IntoIterator<Item = (R, Options)>,
+{
+    // Collect into a `Vec`, so that the stop-future doesn't capture `I`, which
+    // isn't necessarily `'static`
+    let bots: Vec<_> = bots.into_iter().collect();
 
-                tx.send(Ok(update)).expect("Cannot send an incoming update from the webhook")
-            }
-            Err(error) => {
-                log::error!(
-                    "Cannot parse an update.\nError: {:?}\nValue: {}\n\
-                     This is a bug in teloxide-core, please open an issue here: \
-                     https://github.com/teloxide/teloxide/issues.",
-                    error,
-                    input
-                );
+    #[cfg(feature = "webhooks-tls")]
+    if bots.iter().any(|(_, options)| options.tls.is_some()) {
+        log::warn!("`Options::tls` is ignored by `axum_multi`, use `axum_multi_tls` instead");
+    }
+
+    let (mut update_listeners, stop_flag, app) = axum_multi_to_router(bots).await?;
+    let stop_tokens: Vec<_> = update_listeners.iter_mut().map(|l| l.stop_token()).collect();
+    let stop_all = move || stop_tokens.iter().for_each(StopToken::stop);
+
+    tokio::spawn(async move {
+        let tcp_listener = tokio::net::TcpListener::bind(address)
+            .await
+            .inspect_err(|_| stop_all())
+            .expect("Couldn't bind to the address");
+        axum::serve(tcp_listener, app)
+            .with_graceful_shutdown(stop_flag)
+            .await
+            .inspect_err(|_| stop_all())
+            .expect("Axum server error");
+    });
+
+    Ok(update_listeners)
+}
+
+/// Like [`axum_multi`], but the server terminates TLS with `tls`.
+///
+/// [`Options::tls`] of individual bots is ignored. If the certificate is
+/// self-signed, it must be uploaded with [`Options::certificate`] of every bot.
+///
+/// ## Panics
+///
+/// If binding to the `address` fails or if two bots have the same path and
+/// secret token.
+///
+/// ## Fails
+///
+/// If `set_webhook()` fails for any of the bots. In this case webhooks of the
+/// bots that were already set up are deleted.
+#[cfg(feature = "webhooks-tls")]
+pub async fn axum_multi_tls<R, I>(
+    address: SocketAddr,
+    tls: TlsConfig,
+    bots: I,
+) -> Result<Vec<impl UpdateListener<Err = Infallible>>, R::Err>
+where
+    R: Requester + Send + 'static,
+    <R as Requester>::DeleteWebhook: Send,
+    I: IntoIterator<Item = (R, Options)>,
+{
+    use crate::update_listeners::webhooks::hyper::serve_tls;
+
+    // See `axum_multi`
+    let bots: Vec<_> = bots.into_iter().collect();
+    let (mut update_listeners, stop_flag, app) = axum_multi_to_router(bots).await?;
+    let stop_tokens: Vec<_> = update_listeners.iter_mut().map(|l| l.stop_token()).collect();
+
+    tokio::spawn(async move {
+        let tcp_listener = tokio::net::TcpListener::bind(address)
+            .await
+            .inspect_err(|_| stop_tokens.iter().for_each(StopToken::stop))
+            .expect("Couldn't bind to the address");
+        serve_tls(tcp_listener, app, tls, stop_flag).await;
+    });
+
+    Ok(update_listeners)
+}
+
+/// Webhook implementation based on the [mod@axum] framework that serves
+/// multiple bots and can reuse existing [mod@axum] server.
+///
+/// This function does most of the work necessary for webhooks to work, it:
+/// - Calls [`set_webhook`] for every bot, so telegram starts sending updates
+///   our way
+/// - When the update listener of a bot is hinted with [allowed updates], calls
+///   [`set_webhook`] again for this bot
+/// - When the update listener of a bot is [`stop`]ped, calls [`delete_webhook`]
+///   for this bot
+///
+/// The only missing part is running [mod@axum] server with a returned
+/// [`axum::Router`].
+///
+/// See [`axum_multi`] for how updates are routed to bots.
+///
+/// [`set_webhook`]: crate::payloads::SetWebhook
+/// [`delete_webhook`]: crate::payloads::DeleteWebhook
+/// [`stop`]: crate::stop::StopToken::stop
+/// [allowed updates]: crate::update_listeners::UpdateListener::hint_allowed_updates
+///
+/// ## Returns
+///
+/// Update listeners (in the same order as bots), stop-future, axum router
+/// triplet on success.
+///
+/// The "stop-future" is resolved after [`stop`] is called on the stop tokens
+/// of all returned update listeners and all webhooks are deleted. It also
+/// applies allowed updates hints, so it should be polled while the server is
+/// running.
+///
+/// ## Panics
+///
+/// If two bots have the same path and secret token.
+///
+/// ## Fails
+///
+/// If `set_webhook()` fails for any of the bots. In this case webhooks of the
+/// bots that were already set up are deleted.
+///
+/// ## See also
+///
+/// [`axum_multi`] for higher-level and [`axum_multi_no_setup`] for
+/// lower-level versions of this function.
+pub async fn axum_multi_to_router<R, I>(
+    bots: I,
+) -> Result<
+    (Vec<impl UpdateListener<Err = Infallible>>, impl Future<Output = ()> + Send, axum::Router),
+    R::Err,
+>
+where
+    R: Requester + Send,
+    <R as Requester>::DeleteWebhook: Send,
+    I: IntoIterator<Item = (R, Options)>,
+{
+    use crate::{requests::Request, update_listeners::webhooks::setup_webhook};
+    use futures::{future::join_all, FutureExt};
+
+    let mut webhooks = Vec::new();
+    let mut options_with_hints = Vec::new();
+
+    for (bot, mut options) in bots {
+        match setup_webhook(bot, &mut options).await {
+            Ok((webhook, hints)) => {
+                webhooks.push(webhook);
+                options_with_hints.push((options, Some(hints)));
             }
-        };
+            Err(err) => {
+                for webhook in &webhooks {
+                    if let Err(err) = webhook.bot.delete_webhook().send().await {
+                        log::error!("Couldn't delete webhook: {}", err);
+                    }
+                }
 
-        StatusCode::OK
+                return Err(err);
+            }
+        }
     }
 
-    let (stop_token, stop_flag) = mk_stop_token();
-
-    let app = axum::Router::new()
-        .route(&options.path, post(telegram_request))
-        .layer(TraceLayer::new_for_http())
-        .with_state(WebhookState {
-            tx: ClosableSender::new(tx),
-            flag: stop_flag.clone(),
-            secret: options.secret_token,
-        });
+    let (listeners, router) = multi_router_with_hints(options_with_hints);
 
-    let stream = UnboundedReceiverStream::new(rx);
+    let (listeners, stop_flags): (Vec<_>, Vec<_>) = listeners.into_iter().unzip();
+    let stop_flags =
+        webhooks.into_iter().zip(stop_flags).map(|(webhook, stop_flag)| webhook.run(stop_flag));
+    let stop_flag = join_all(stop_flags).map(drop);
 
-    // FIXME: this should support `hint_allowed_updates()`
-    let listener = StatefulListener::new(
-        (stream, stop_token),
-        tuple_first_mut,
-        |state: &mut (_, StopToken)| state.1.clone(),
-    );
+    Ok((listeners, stop_flag, router))
+}
 
-    (listener, stop_flag, app)
+/// Webhook implementation based on the [mod@axum] framework that serves
+/// multiple bots and doesn't perform any setup work.
+///
+/// Returns an update listener and a stop-future for every [`Options`] (in the
+/// same order), and a router that serves all of them. See [`axum_multi`] for
+/// how updates are routed to bots and [`axum_no_setup`] for the description
+/// of the stop-future.
+///
+/// ## Panics
+///
+/// If two options have the same path and secret token.
+///
+/// ## See also
+///
+/// [`axum_multi`] and [`axum_multi_to_router`] for higher-level versions of
+/// this function.
+pub fn axum_multi_no_setup<I>(
+    options: I,
+) -> (Vec<(impl UpdateListener<Err = Infallible>, impl Future<Output = ()>)>, axum::Router)
+where
+    I: IntoIterator<Item = Options>,
+{
+    multi_router_with_hints(options.into_iter().map(|options| (options, None)))
 }
 
-type UpdateSender = mpsc::UnboundedSender<Result<Update, std::convert::Infallible>>;
-type UpdateCSender = ClosableSender<Result<Update, std::convert::Infallible>>;
+fn multi_router_with_hints<I>(
+    options: I,
+) -> (Vec<(impl UpdateListener<Err = Infallible>, StopFlag)>, axum::Router)
+where
+    I: IntoIterator<Item = (Options, Option<AllowedUpdatesSender>)>,
+{
+    use std::collections::BTreeMap;
+
+    use axum::routing::post;
+
+    use crate::update_listeners::webhooks::handler::same_secret;
+    use tower_http::trace::TraceLayer;
+
+    let mut listeners = Vec::new();
+    let mut routes = BTreeMap::<String, Vec<WebhookHandler>>::new();
+
+    for (options, hints) in options {
+        let path = options.path.clone();
+        let (listener, stop_flag, handler) = handler_with_hints(options, hints);
+
+        let handlers = routes.entry(path).or_default();
+        assert!(
+            !handlers.iter().any(|h| same_secret(h, &handler)),
+            "Multiple bots have the same webhook path and secret token"
+        );
+        handlers.push(handler);
 
-#[derive(Clone)]
-struct WebhookState {
-    tx: UpdateCSender,
-    flag: StopFlag,
-    secret: Option<String>,
+        listeners.push((listener, stop_flag));
+    }
+
+    let app = routes
+        .into_iter()
+        .fold(axum::Router::new(), |app, (path, handlers)| {
+            app.route(&path, post(multi_telegram_request).with_state(Arc::<[_]>::from(handlers)))
+        })
+        .layer(TraceLayer::new_for_http());
+
+    (listeners, app)
 }
 
-/// A terrible workaround to drop axum extension
-struct ClosableSender<T> {
-    origin: std::sync::Arc<std::sync::RwLock<Option<mpsc::UnboundedSender<T>>>>,
+async fn telegram_request(
+    State(handler): State<WebhookHandler>,
+    headers: HeaderMap,
+    input: Bytes,
+) -> StatusCode {
+    let secret_token = headers.get(SECRET_TOKEN_HEADER).map(|header| header.as_bytes());
+    handler.handle(secret_token, &input).await
 }
 
-impl<T> Clone for ClosableSender<T> {
-    fn clone(&self) -> Self {
-        Self { origin: self.origin.clone() }
+async fn multi_telegram_request(
+    State(handlers): State<Arc<[WebhookHandler]>>,
+    headers: HeaderMap,
+    input: Bytes,
+) -> StatusCode {
+    use crate::update_listeners::webhooks::handler::find_handler;
+
+    let secret_token = headers.get(SECRET_TOKEN_HEADER).map(|header| header.as_bytes());
+    match find_handler(&handlers, secret_token) {
+        Ok(handler) => handler.handle_update(&input).await,
+        Err(status) => status,
     }
 }
 
-impl<T> ClosableSender<T> {
-    fn new(sender: mpsc::UnboundedSender<T>) -> Self {
-        Self { origin: std::sync::Arc::new(std::sync::RwLock::new(Some(sender))) }
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request};
+    use futures::StreamExt;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::update_listeners::{
+        webhooks::{test_options, QueueFullPolicy},
+        AsUpdateStream,
+    };
+
+    fn options(policy: QueueFullPolicy) -> Options {
+        test_options("https://example.com/webhook").queue_capacity(1).queue_full_policy(policy)
     }
 
-    fn get(&self) -> Option<mpsc::UnboundedSender<T>> {
-        self.origin.read().unwrap().clone()
+    async fn deliver(router: &axum::Router, update_id: u32) -> StatusCode {
+        deliver_to(router, "/webhook", "secret", update_id).await
     }
 
-    fn close(&mut self) {
-        self.origin.write().unwrap().take();
+    async fn deliver_to(
+        router: &axum::Router,
+        path: &str,
+        secret: &str,
+        update_id: u32,
+    ) -> StatusCode {
+        let request = Request::post(path)
+            .header("x-telegram-bot-api-secret-token", secret)
+            .body(Body::from(format!(r#"{{"update_id":{update_id},"unknown":{{}}}}"#)))
+            .unwrap();
+
+        router.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn rejects_when_full() {
+        for (policy, status) in [
+            (QueueFullPolicy::TooManyRequests, StatusCode::TOO_MANY_REQUESTS),
+            (QueueFullPolicy::ServiceUnavailable, StatusCode::SERVICE_UNAVAILABLE),
+        ] {
+            let options = options(policy);
+            let depth = options.queue_depth.clone();
+            let (mut listener, _stop_flag, router) = axum_no_setup(options);
+
+            assert_eq!(deliver(&router, 1).await, StatusCode::OK);
+            assert_eq!(deliver(&router, 2).await, status);
+            assert_eq!(depth.get(), 1);
+
+            let update = std::pin::pin!(listener.as_stream()).next().await.unwrap().unwrap();
+            assert_eq!(update.id.0, 1);
+            assert_eq!(depth.get(), 0);
+
+            assert_eq!(deliver(&router, 3).await, StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn waits_when_full() {
+        let options = options(QueueFullPolicy::Wait);
+        let depth = options.queue_depth.clone();
+        let (mut listener, _stop_flag, router) = axum_no_setup(options);
+
+        assert_eq!(deliver(&router, 1).await, StatusCode::OK);
+
+        let mut waiting = tokio::spawn({
+            let router = router.clone();
+            async move { deliver(&router, 2).await }
+        });
+        // The request is driven until it waits for the room in the queue
+        let res = tokio::time::timeout(std::time::Duration::from_millis(100), &mut waiting).await;
+        assert!(res.is_err());
+        assert_eq!(depth.get(), 1);
+
+        let mut stream = std::pin::pin!(listener.as_stream());
+        assert_eq!(stream.next().await.unwrap().unwrap().id.0, 1);
+        assert_eq!(waiting.await.unwrap(), StatusCode::OK);
+        assert_eq!(stream.next().await.unwrap().unwrap().id.0, 2);
+        assert_eq!(depth.get(), 0);
+    }
+
+    #[tokio::test]
+    async fn multi_routes_by_path_and_secret() {
+        let options = |path: &str, secret: &str| {
+            test_options(&format!("https://example.com{path}")).secret_token(secret.to_owned())
+        };
+
+        let (listeners, router) = axum_multi_no_setup([
+            options("/a", "first"),
+            options("/a", "second"),
+            options("/b", "first"),
+        ]);
+
+        assert_eq!(deliver_to(&router, "/a", "second", 1).await, StatusCode::OK);
+        assert_eq!(deliver_to(&router, "/b", "first", 2).await, StatusCode::OK);
+        assert_eq!(deliver_to(&router, "/a", "first", 3).await, StatusCode::OK);
+        assert_eq!(deliver_to(&router, "/b", "second", 4).await, StatusCode::UNAUTHORIZED);
+
+        let mut ids = Vec::new();
+        for (mut listener, _stop_flag) in listeners {
+            let update = std::pin::pin!(listener.as_stream()).next().await.unwrap().unwrap();
+            ids.push(update.id.0);
+        }
+        assert_eq!(ids, [3, 1, 2]);
     }
-}
 
-struct XTelegramBotApiSecretToken(Option<Vec<u8>>);
-
-impl<S> FromRequestParts<S> for XTelegramBotApiSecretToken {
-    type Rejection = StatusCode;
-
-    fn from_request_parts<'l0, 'l1, 'at>(
-        req: &'l0 mut Parts,
-        _state: &'l1 S,
-    ) -> Pin<Box<dyn Future<Output = Result<Self, Self::Rejection>> + Send + 'at>>
-    where
-        'l0: 'at,
-        'l1: 'at,
-        Self: 'at,
-    {
-        use crate::update_listeners::webhooks::check_secret;
-
-        let res = req
-            .headers
-            .remove("x-telegram-bot-api-secret-token")
-            .map(|header| {
-                check_secret(header.as_bytes())
-                    .map(<_>::to_owned)
-                    .map_err(|_| StatusCode::BAD_REQUEST)
-            })
-            .transpose()
-            .map(Self);
-
-        Box::pin(async { res }) as _
+    #[test]
+    #[should_panic(expected = "Multiple bots have the same webhook path and secret token")]
+    fn multi_same_path_and_secret() {
+        let _ =
+            axum_multi_no_setup([options(QueueFullPolicy::Wait), options(QueueFullPolicy::Wait)]);
     }
 }
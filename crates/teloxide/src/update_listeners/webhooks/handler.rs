@@ -0,0 +1,404 @@
+use std::{convert::Infallible, future::Future};
+
+use http::StatusCode;
+use tokio::sync::mpsc;
+
+use crate::{
+    requests::Requester,
+    stop::{StopFlag, StopToken},
+    types::{AllowedUpdate, Update, UpdateKind},
+    update_listeners::{
+        webhooks::{check_secret, AllowedUpdatesSender, Options, QueueDepth, QueueFullPolicy},
+        UpdateListener,
+    },
+};
+
+/// Name of the header which Telegram uses to send [`Options::secret_token`].
+pub const SECRET_TOKEN_HEADER: &str = "x-telegram-bot-api-secret-token";
+
+/// Framework-agnostic webhook request handler.
+///
+/// This handler does the work that is common for all webhook implementations:
+/// it checks the secret token (in constant time), parses an update from the
+/// request body and feeds it to the associated update listener. This can be
+/// used to integrate webhooks with web frameworks that are not supported by
+/// teloxide out of the box.
+///
+/// Handler is cheap to clone, all clones feed the same update listener.
+///
+/// ## See also
+///
+/// [`handler`] and [`handler_no_setup`] for functions that create a handler.
+#[derive(Clone)]
+pub struct WebhookHandler {
+    tx: UpdateCSender,
+    flag: StopFlag,
+    secret: Option<String>,
+    queue_full_policy: QueueFullPolicy,
+    queue_depth: QueueDepth,
+}
+
+/// Creates a [`WebhookHandler`] with setup.
+///
+/// This function:
+/// - Calls [`set_webhook`], so telegram starts sending updates our way
+/// - When the update listener is hinted with [allowed updates], calls
+///   [`set_webhook`] again with them
+/// - When the update listener is [`stop`]ped, calls [`delete_webhook`]
+///
+/// The only missing part is passing requests received by your server on
+/// [`options.address`] and [`options.path`] to the returned handler.
+///
+/// [`set_webhook`]: crate::payloads::SetWebhook
+/// [`delete_webhook`]: crate::payloads::DeleteWebhook
+/// [`stop`]: crate::stop::StopToken::stop
+/// [allowed updates]: crate::update_listeners::UpdateListener::hint_allowed_updates
+/// [`options.address`]: Options::address
+/// [`options.path`]: Options::path
+///
+/// ## Returns
+///
+/// A update listener, stop-future, webhook handler triplet on success.
+///
+/// The "stop-future" is resolved after [`stop`] is called on the stop token of
+/// the returned update listener and the webhook is deleted. It also applies
+/// allowed updates hints, so it should be polled while the server is running.
+///
+/// ## Fails
+///
+/// If `set_webhook()` fails.
+///
+/// ## See also
+///
+/// [`handler_no_setup`] for lower-level version of this function.
+pub async fn handler<R>(
+    bot: R,
+    mut options: Options,
+) -> Result<
+    (impl UpdateListener<Err = Infallible>, impl Future<Output = ()> + Send, WebhookHandler),
+    R::Err,
+>
+where
+    R: Requester + Send,
+    <R as Requester>::DeleteWebhook: Send,
+{
+    use crate::update_listeners::webhooks::setup_webhook;
+
+    let (webhook, hints) = setup_webhook(bot, &mut options).await?;
+    let (listener, stop_flag, handler) = handler_with_hints(options, Some(hints));
+
+    Ok((listener, webhook.run(stop_flag), handler))
+}
+
+/// Creates a [`WebhookHandler`] without performing any setup work.
+///
+/// Only [`Options::secret_token`] and the queue options are used, routing
+/// requests by [`Options::path`] is left to the caller. Allowed updates hints
+/// are ignored, since this function doesn't call `set_webhook`.
+///
+/// ## Note about the stop-future
+///
+/// This function returns a future that is resolved when `.stop()` is called on
+/// a stop token of the update listener. Note that even if the future is not
+/// used, after `.stop()` is called, update listener will not produce new
+/// updates.
+///
+/// ## See also
+///
+/// [`handler`] for higher-level version of this function.
+pub fn handler_no_setup(
+    options: Options,
+) -> (impl UpdateListener<Err = Infallible>, impl Future<Output = ()>, WebhookHandler) {
+    handler_with_hints(options, None)
+}
+
+/// Creates a [`WebhookHandler`] and an update listener that passes allowed
+/// updates hints to `hints`.
+pub(super) fn handler_with_hints(
+    options: Options,
+    hints: Option<AllowedUpdatesSender>,
+) -> (impl UpdateListener<Err = Infallible>, StopFlag, WebhookHandler) {
+    use crate::{
+        stop::mk_stop_token,
+        update_listeners::{
+            webhooks::{send_hint, tuple_first_mut},
+            StatefulListener,
+        },
+    };
+    use futures::StreamExt;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    let (tx, rx): (UpdateSender, _) = mpsc::channel(options.queue_capacity);
+    let (stop_token, stop_flag) = mk_stop_token();
+
+    let handler = WebhookHandler {
+        tx: ClosableSender::new(tx),
+        flag: stop_flag.clone(),
+        secret: options.secret_token,
+        queue_full_policy: options.queue_full_policy,
+        queue_depth: options.queue_depth.clone(),
+    };
+
+    let queue_depth = options.queue_depth;
+    let stream = ReceiverStream::new(rx).inspect(move |_| queue_depth.dec());
+
+    let listener = StatefulListener::new_with_hints(
+        (stream, (stop_token, hints)),
+        tuple_first_mut,
+        |state: &mut (_, (StopToken, _))| state.1 .0.clone(),
+        Some(|state: &mut (_, (_, _)), hint: &mut dyn Iterator<Item = AllowedUpdate>| {
+            send_hint(&state.1 .1, hint)
+        }),
+    );
+
+    (listener, stop_flag, handler)
+}
+
+impl WebhookHandler {
+    /// Handles a webhook request.
+    ///
+    /// `secret_token` is the value of the [`SECRET_TOKEN_HEADER`] header and
+    /// `body` is the request body.
+    ///
+    /// Returns the status code that should be sent back to Telegram:
+    /// - `200 OK` if the update was accepted
+    /// - `400 Bad Request` if the secret token is malformed or the body is not
+    ///   valid UTF-8
+    /// - `401 Unauthorized` if the secret token doesn't match
+    /// - `429 Too Many Requests` or `503 Service Unavailable` if the update
+    ///   can't be accepted right now, see [`Options::queue_full_policy`]
+    pub async fn handle(&self, secret_token: Option<&[u8]>, body: &[u8]) -> StatusCode {
+        match find_handler(std::slice::from_ref(self), secret_token) {
+            Ok(handler) => handler.handle_update(body).await,
+            Err(status) => status,
+        }
+    }
+
+    /// Returns `true` if `secret_token` matches the secret token of this
+    /// handler. The comparison is done in constant time.
+    fn accepts(&self, secret_token: Option<&[u8]>) -> bool {
+        use subtle::ConstantTimeEq;
+
+        match (self.secret.as_deref(), secret_token) {
+            (None, None) => true,
+            (Some(secret), Some(token)) => secret.as_bytes().ct_eq(token).into(),
+            _ => false,
+        }
+    }
+
+    /// Handles a request with an already checked secret token.
+    pub(super) async fn handle_update(&self, body: &[u8]) -> StatusCode {
+        let Self { flag, tx, queue_full_policy, queue_depth, .. } = self;
+
+        let tx = match tx.get() {
+            None => return StatusCode::SERVICE_UNAVAILABLE,
+            // Do not process updates after `.stop()` is called even if the server is still
+            // running (useful for when you need to stop the bot but can't stop the server).
+            _ if flag.is_stopped() => {
+                tx.close();
+                return StatusCode::SERVICE_UNAVAILABLE;
+            }
+            Some(tx) => tx,
+        };
+
+        let Ok(input) = std::str::from_utf8(body) else {
+            return StatusCode::BAD_REQUEST;
+        };
+
+        match serde_json::from_str::<Update>(input) {
+            Ok(mut update) => {
+                // See HACK comment in
+                // `teloxide_core::net::request::process_response::{closure#0}`
+                if let UpdateKind::Error(value) = &mut update.kind {
+                    *value = serde_json::from_str(input).unwrap_or_default();
+                }
+
+                return enqueue(&tx, update, *queue_full_policy, queue_depth, flag.clone()).await;
+            }
+            Err(error) => {
+                log::error!(
+                    "Cannot parse an update.\nError: {:?}\nValue: {}\n\
+                     This is a bug in teloxide-core, please open an issue here: \
+                     https://github.com/teloxide/teloxide/issues.",
+                    error,
+                    input
+                );
+            }
+        };
+
+        StatusCode::OK
+    }
+}
+
+/// Finds a handler that accepts `secret_token`.
+///
+/// Returns the status code that should be sent to Telegram if there is no such
+/// handler.
+pub(super) fn find_handler<'a>(
+    handlers: &'a [WebhookHandler],
+    secret_token: Option<&[u8]>,
+) -> Result<&'a WebhookHandler, StatusCode> {
+    if let Some(token) = secret_token {
+        check_secret(token).map_err(|_| StatusCode::BAD_REQUEST)?;
+    }
+
+    handlers.iter().find(|handler| handler.accepts(secret_token)).ok_or(StatusCode::UNAUTHORIZED)
+}
+
+/// Returns `true` if `a` and `b` have the same secret token.
+#[cfg(feature = "webhooks-axum")]
+pub(super) fn same_secret(a: &WebhookHandler, b: &WebhookHandler) -> bool {
+    a.secret == b.secret
+}
+
+/// Puts an update into the queue, respecting the queue full policy.
+///
+/// Returns the status code that should be sent to Telegram.
+async fn enqueue(
+    tx: &UpdateSender,
+    update: Update,
+    policy: QueueFullPolicy,
+    depth: &QueueDepth,
+    flag: StopFlag,
+) -> StatusCode {
+    use futures::future::{select, Either};
+    use mpsc::error::TrySendError;
+
+    let permit = match policy {
+        QueueFullPolicy::Wait => {
+            let reserve = std::pin::pin!(tx.reserve());
+
+            // Stop waiting for the room in the queue if the listener is stopped
+            match select(reserve, flag).await {
+                Either::Left((res, _)) => res.map_err(|_| StatusCode::SERVICE_UNAVAILABLE),
+                Either::Right(_) => Err(StatusCode::SERVICE_UNAVAILABLE),
+            }
+        }
+        QueueFullPolicy::TooManyRequests | QueueFullPolicy::ServiceUnavailable => {
+            tx.try_reserve().map_err(|err| match err {
+                TrySendError::Full(()) => {
+                    log::warn!("Webhook queue is full, asking Telegram to redeliver the update");
+
+                    match policy {
+                        QueueFullPolicy::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+                        _ => StatusCode::SERVICE_UNAVAILABLE,
+                    }
+                }
+                TrySendError::Closed(()) => StatusCode::SERVICE_UNAVAILABLE,
+            })
+        }
+    };
+
+    match permit {
+        Ok(permit) => {
+            // Count the update only once there is room for it, but before sending it,
+            // so the listener can't take it (and decrement the depth) before it was
+            // counted
+            depth.inc();
+            permit.send(Ok(update));
+            StatusCode::OK
+        }
+        Err(status) => status,
+    }
+}
+
+type UpdateSender = mpsc::Sender<Result<Update, std::convert::Infallible>>;
+type UpdateCSender = ClosableSender<Result<Update, std::convert::Infallible>>;
+
+/// A terrible workaround to drop the sender stored in web framework state
+struct ClosableSender<T> {
+    origin: std::sync::Arc<std::sync::RwLock<Option<mpsc::Sender<T>>>>,
+}
+
+impl<T> Clone for ClosableSender<T> {
+    fn clone(&self) -> Self {
+        Self { origin: self.origin.clone() }
+    }
+}
+
+impl<T> ClosableSender<T> {
+    fn new(sender: mpsc::Sender<T>) -> Self {
+        Self { origin: std::sync::Arc::new(std::sync::RwLock::new(Some(sender))) }
+    }
+
+    fn get(&self) -> Option<mpsc::Sender<T>> {
+        self.origin.read().unwrap().clone()
+    }
+
+    fn close(&self) {
+        self.origin.write().unwrap().take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::update_listeners::{webhooks::test_options, AsUpdateStream};
+
+    const UPDATE: &[u8] = br#"{"update_id":1,"unknown":{}}"#;
+
+    fn options() -> Options {
+        test_options("https://example.com/webhook")
+    }
+
+    #[tokio::test]
+    async fn checks_secret_token() {
+        let (mut listener, _stop_flag, handler) = handler_no_setup(options());
+
+        assert_eq!(handler.handle(None, UPDATE).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(handler.handle(Some(b"secreT"), UPDATE).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(handler.handle(Some(b"secret!"), UPDATE).await, StatusCode::BAD_REQUEST);
+        assert_eq!(handler.handle(Some(b"secret"), b"\xff").await, StatusCode::BAD_REQUEST);
+        assert_eq!(handler.handle(Some(b"secret"), UPDATE).await, StatusCode::OK);
+
+        let update = std::pin::pin!(listener.as_stream()).next().await.unwrap().unwrap();
+        assert_eq!(update.id.0, 1);
+    }
+
+    #[tokio::test]
+    async fn without_secret_token() {
+        let options = Options { secret_token: None, ..options() };
+        let (_listener, _stop_flag, handler) = handler_no_setup(options);
+
+        assert_eq!(handler.handle(Some(b"secret"), UPDATE).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(handler.handle(None, UPDATE).await, StatusCode::OK);
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn applies_allowed_updates_hints() {
+        use crate::{
+            payloads::SetWebhook, testing::MockBot, types::AllowedUpdate,
+            update_listeners::UpdateListener,
+        };
+
+        let bot = MockBot::new();
+        let options = options().allowed_updates(vec![AllowedUpdate::Message]);
+        let (mut listener, stop_future, _handler) = handler(bot.clone(), options).await.unwrap();
+        let stop_token = listener.stop_token();
+        let stop_future = tokio::spawn(stop_future);
+
+        // Hinting the same updates doesn't call `set_webhook` again
+        listener.hint_allowed_updates(&mut [AllowedUpdate::Message].into_iter());
+        listener.hint_allowed_updates(
+            &mut [AllowedUpdate::Message, AllowedUpdate::CallbackQuery].into_iter(),
+        );
+        stop_token.stop();
+        stop_future.await.unwrap();
+
+        let allowed_updates: Vec<_> =
+            bot.requests_of::<SetWebhook>().into_iter().map(|req| req.allowed_updates).collect();
+        assert_eq!(
+            allowed_updates,
+            [
+                Some(vec![AllowedUpdate::Message]),
+                Some(vec![AllowedUpdate::Message, AllowedUpdate::CallbackQuery]),
+            ]
+        );
+
+        let methods: Vec<_> = bot.requests().iter().map(|req| req.method()).collect();
+        assert_eq!(methods, ["SetWebhook", "SetWebhook", "DeleteWebhook"]);
+    }
+}
@@ -0,0 +1,227 @@
+use std::{convert::Infallible, future::Future, pin::pin};
+
+use futures::future::{select, Either};
+use http::{Request, Response};
+use hyper::body::Incoming;
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo},
+    server::conn::auto,
+    service::TowerToHyperService,
+};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::{TcpListener, TcpStream},
+    sync::watch,
+};
+
+use crate::{
+    requests::Requester,
+    update_listeners::{
+        webhooks::{tower_service, Options},
+        UpdateListener,
+    },
+};
+
+#[cfg(feature = "webhooks-tls")]
+use crate::update_listeners::webhooks::TlsConfig;
+
+/// Webhook implementation based on the [hyper] server.
+///
+/// This function does all the work necessary for webhook to work, it:
+/// - Calls [`set_webhook`], so telegram starts sending updates our way
+/// - Spawns [hyper] server listening for updates (over TLS, if [`Options::tls`]
+///   is set)
+/// - When the update listener is hinted with [allowed updates], calls
+///   [`set_webhook`] again with them
+/// - When the update listener is [`stop`]ped, calls [`delete_webhook`]
+///
+/// If you already have a [hyper] server running, use [`tower_service`] and
+/// serve the returned service with [`TowerToHyperService`].
+///
+/// [hyper]: https://docs.rs/hyper
+/// [`set_webhook`]: crate::payloads::SetWebhook
+/// [`delete_webhook`]: crate::payloads::DeleteWebhook
+/// [`stop`]: crate::stop::StopToken::stop
+/// [allowed updates]: crate::update_listeners::UpdateListener::hint_allowed_updates
+/// [`TowerToHyperService`]: hyper_util::service::TowerToHyperService
+///
+/// ## Panics
+///
+/// If binding to the [address] fails.
+///
+/// [address]: Options::address
+///
+/// ## Fails
+///
+/// If `set_webhook()` fails.
+pub async fn hyper<R>(
+    bot: R,
+    options: Options,
+) -> Result<impl UpdateListener<Err = Infallible>, R::Err>
+where
+    R: Requester + Send + 'static,
+    <R as Requester>::DeleteWebhook: Send,
+{
+    let Options { address, .. } = options;
+    #[cfg(feature = "webhooks-tls")]
+    let tls = options.tls.clone();
+
+    let (mut update_listener, stop_flag, service) = tower_service(bot, options).await?;
+    let stop_token = update_listener.stop_token();
+
+    tokio::spawn(async move {
+        let tcp_listener = TcpListener::bind(address)
+            .await
+            .inspect_err(|_| stop_token.stop())
+            .expect("Couldn't bind to the address");
+
+        #[cfg(feature = "webhooks-tls")]
+        if let Some(tls) = tls {
+            return serve_tls(tcp_listener, service, tls, stop_flag).await;
+        }
+
+        serve(tcp_listener, service, stop_flag).await;
+    });
+
+    Ok(update_listener)
+}
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Serves `service` on `tcp_listener` until `stop_flag` is resolved, then
+/// gracefully shuts down the connections and waits for them to close.
+pub(super) async fn serve<S, B>(
+    tcp_listener: TcpListener,
+    service: S,
+    stop_flag: impl Future<Output = ()>,
+) where
+    S: tower::Service<Request<Incoming>, Response = Response<B>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<BoxError>,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    accept(tcp_listener, stop_flag, |stream, shutdown| {
+        serve_connection(stream, service.clone(), shutdown)
+    })
+    .await;
+}
+
+/// How long a client may take to complete the TLS handshake.
+#[cfg(feature = "webhooks-tls")]
+const TLS_HANDSHAKE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Serves `service` over TLS on `tcp_listener` until `stop_flag` is resolved,
+/// then gracefully shuts down the connections and waits for them to close.
+#[cfg(feature = "webhooks-tls")]
+pub(super) async fn serve_tls<S, B>(
+    tcp_listener: TcpListener,
+    service: S,
+    tls: TlsConfig,
+    stop_flag: impl Future<Output = ()>,
+) where
+    S: tower::Service<Request<Incoming>, Response = Response<B>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<BoxError>,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    let acceptor = tls.acceptor();
+
+    accept(tcp_listener, stop_flag, |stream, shutdown| {
+        let acceptor = acceptor.clone();
+        let service = service.clone();
+
+        async move {
+            let handshake = tokio::time::timeout(TLS_HANDSHAKE_TIMEOUT, acceptor.accept(stream));
+
+            // Don't wait for the handshake if the server is shutting down
+            match select(pin!(handshake), pin!(shutdown.clone().wait())).await {
+                Either::Left((Ok(Ok(stream)), _)) => {
+                    serve_connection(stream, service, shutdown).await
+                }
+                Either::Left((Ok(Err(err)), _)) => log::warn!("TLS handshake failed: {}", err),
+                Either::Left((Err(_), _)) => log::warn!("TLS handshake timed out"),
+                Either::Right(_) => {}
+            }
+        }
+    })
+    .await;
+}
+
+/// Accepts connections on `tcp_listener` until `stop_flag` is resolved,
+/// spawning `on_connection` for each of them.
+///
+/// Once `stop_flag` is resolved, the [`Shutdown`] passed to the connections is
+/// resolved too and this function waits until all of them are done.
+async fn accept<F>(
+    tcp_listener: TcpListener,
+    stop_flag: impl Future<Output = ()>,
+    mut on_connection: impl FnMut(TcpStream, Shutdown) -> F,
+) where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let (shutdown_tx, shutdown_rx) = watch::channel(());
+    let mut stop_flag = pin!(stop_flag);
+
+    loop {
+        let stream = match select(pin!(tcp_listener.accept()), stop_flag.as_mut()).await {
+            Either::Left((Ok((stream, _)), _)) => stream,
+            Either::Left((Err(err), _)) => {
+                log::error!("Couldn't accept a connection: {}", err);
+                continue;
+            }
+            Either::Right(_) => break,
+        };
+
+        tokio::spawn(on_connection(stream, Shutdown(shutdown_rx.clone())));
+    }
+
+    drop(tcp_listener);
+    drop(shutdown_rx);
+
+    // Every connection holds a receiver, so the channel is closed once all of them
+    // are done
+    shutdown_tx.send_replace(());
+    shutdown_tx.closed().await;
+}
+
+/// A signal that the server is shutting down, see [`accept`].
+#[derive(Clone)]
+struct Shutdown(watch::Receiver<()>);
+
+impl Shutdown {
+    /// Resolves once the server is shutting down.
+    async fn wait(mut self) {
+        // Error means that the server is gone, which is a shutdown too
+        let _ = self.0.changed().await;
+    }
+}
+
+async fn serve_connection<I, S, B>(io: I, service: S, shutdown: Shutdown)
+where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    S: tower::Service<Request<Incoming>, Response = Response<B>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<BoxError>,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    let builder = auto::Builder::new(TokioExecutor::new());
+    let mut conn =
+        pin!(builder.serve_connection(TokioIo::new(io), TowerToHyperService::new(service)));
+
+    let res = match select(conn.as_mut(), pin!(shutdown.wait())).await {
+        Either::Left((res, _)) => res,
+        Either::Right(_) => {
+            conn.as_mut().graceful_shutdown();
+            conn.await
+        }
+    };
+    if let Err(err) = res {
+        log::error!("Hyper connection error: {}", err);
+    }
+}
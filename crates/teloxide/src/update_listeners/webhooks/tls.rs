@@ -0,0 +1,297 @@
+use std::{io, path::Path, sync::Arc};
+
+use thiserror::Error;
+use tokio_rustls::rustls::{
+    self,
+    pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer},
+    ServerConfig,
+};
+
+/// TLS configuration of a webhook server, see [`Options::tls`].
+///
+/// [`Options::tls`]: super::Options::tls
+#[derive(Clone)]
+pub struct TlsConfig {
+    server_config: Arc<ServerConfig>,
+    self_signed_certificate: Option<String>,
+}
+
+/// An error returned from [`TlsConfig`] constructors.
+#[derive(Debug, Error)]
+pub enum TlsError {
+    /// Couldn't read a certificate or a private key.
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+
+    /// The certificate or the private key is invalid.
+    #[error("rustls error: {0}")]
+    Rustls(#[from] rustls::Error),
+
+    /// Couldn't generate a self-signed certificate.
+    #[error("certificate generation error: {0}")]
+    Rcgen(#[from] rcgen::Error),
+
+    /// The private key file doesn't contain a private key.
+    #[error("no private key found")]
+    NoPrivateKey,
+
+    /// The webhook url doesn't have a host to generate a certificate for.
+    #[error("webhook url has no host")]
+    NoHost,
+}
+
+impl TlsConfig {
+    /// Uses an existing rustls [`ServerConfig`].
+    ///
+    /// Note that to serve HTTP/2, `server_config` should have `h2` in its ALPN
+    /// protocols.
+    pub fn new(server_config: Arc<ServerConfig>) -> Self {
+        Self { server_config, self_signed_certificate: None }
+    }
+
+    /// Creates a configuration from a PEM-encoded certificate chain and a
+    /// PEM-encoded private key.
+    pub fn from_pem(cert_chain: &[u8], private_key: &[u8]) -> Result<Self, TlsError> {
+        let cert_chain = rustls_pemfile::certs(&mut &*cert_chain).collect::<Result<_, _>>()?;
+        let private_key =
+            rustls_pemfile::private_key(&mut &*private_key)?.ok_or(TlsError::NoPrivateKey)?;
+
+        Ok(Self::new(Arc::new(server_config(cert_chain, private_key)?)))
+    }
+
+    /// Creates a configuration from files with a PEM-encoded certificate chain
+    /// and a PEM-encoded private key.
+    pub fn from_pem_files(
+        cert_chain: impl AsRef<Path>,
+        private_key: impl AsRef<Path>,
+    ) -> Result<Self, TlsError> {
+        Self::from_pem(&std::fs::read(cert_chain)?, &std::fs::read(private_key)?)
+    }
+
+    /// Generates a self-signed certificate for `host` (a domain name or an IP
+    /// address).
+    ///
+    /// Telegram will only accept this certificate if it's uploaded with
+    /// `set_webhook`, see [`Options::self_signed_tls`].
+    ///
+    /// [`Options::self_signed_tls`]: super::Options::self_signed_tls
+    pub fn self_signed(host: &str) -> Result<Self, TlsError> {
+        use rcgen::{CertificateParams, DnType, KeyPair};
+
+        let mut params = CertificateParams::new([host.to_owned()])?;
+        params.distinguished_name.push(DnType::CommonName, host);
+
+        let key_pair = KeyPair::generate()?;
+        let certificate = params.self_signed(&key_pair)?;
+
+        let private_key = PrivatePkcs8KeyDer::from(key_pair.serialize_der());
+        let server_config = server_config(vec![certificate.der().clone()], private_key.into())?;
+
+        Ok(Self {
+            server_config: Arc::new(server_config),
+            self_signed_certificate: Some(certificate.pem()),
+        })
+    }
+
+    /// Returns the rustls server configuration.
+    ///
+    /// This can be used to serve webhooks over TLS with your own server.
+    #[must_use]
+    pub fn server_config(&self) -> Arc<ServerConfig> {
+        Arc::clone(&self.server_config)
+    }
+
+    /// Returns the PEM-encoded certificate if it was generated by
+    /// [`TlsConfig::self_signed`].
+    #[must_use]
+    pub fn self_signed_certificate(&self) -> Option<&str> {
+        self.self_signed_certificate.as_deref()
+    }
+
+    pub(super) fn acceptor(&self) -> tokio_rustls::TlsAcceptor {
+        tokio_rustls::TlsAcceptor::from(self.server_config())
+    }
+}
+
+fn server_config(
+    cert_chain: Vec<CertificateDer<'static>>,
+    private_key: PrivateKeyDer<'static>,
+) -> Result<ServerConfig, TlsError> {
+    // Explicitly choose the provider, since the process-wide default is ambiguous
+    // if other dependencies enable a different one
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+
+    let mut config = ServerConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .with_no_client_auth()
+        .with_single_cert(cert_chain, private_key)?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        net::SocketAddr,
+        task::{self, Poll},
+        time::Duration,
+    };
+
+    use bytes::Bytes;
+    use futures::{future::BoxFuture, StreamExt};
+    use http::{Request, Response};
+    use http_body_util::Empty;
+    use hyper::body::Incoming;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+        sync::{mpsc, oneshot, Notify},
+    };
+    use tokio_rustls::{
+        client::TlsStream,
+        rustls::{pki_types::ServerName, ClientConfig, RootCertStore},
+    };
+
+    use super::*;
+    use crate::update_listeners::{
+        webhooks::{hyper::serve_tls, test_options, tower_no_setup},
+        AsUpdateStream,
+    };
+
+    #[test]
+    fn self_signed_options() {
+        let options = test_options("https://example.com/webhook").self_signed_tls().unwrap();
+
+        let certificate = options.tls.as_ref().unwrap().self_signed_certificate().unwrap();
+        assert!(certificate.starts_with("-----BEGIN CERTIFICATE-----"));
+        assert!(options.certificate.is_some());
+    }
+
+    #[tokio::test]
+    async fn serves_over_tls() {
+        let options = test_options("https://127.0.0.1/webhook").self_signed_tls().unwrap();
+        let tls = options.tls.clone().unwrap();
+
+        let (mut listener, stop_flag, service) = tower_no_setup(options);
+        let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = tcp_listener.local_addr().unwrap();
+        tokio::spawn(serve_tls(tcp_listener, service, tls.clone(), stop_flag));
+
+        let mut stream = connect(&tls, address).await;
+        stream.write_all(request(BODY).as_bytes()).await.unwrap();
+
+        let mut status_line = [0; 12];
+        stream.read_exact(&mut status_line).await.unwrap();
+        assert_eq!(&status_line, b"HTTP/1.1 200");
+
+        let update = std::pin::pin!(listener.as_stream()).next().await.unwrap().unwrap();
+        assert_eq!(update.id.0, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn handshake_times_out() {
+        let options = test_options("https://127.0.0.1/webhook").self_signed_tls().unwrap();
+        let tls = options.tls.clone().unwrap();
+
+        let (_listener, stop_flag, service) = tower_no_setup(options);
+        let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = tcp_listener.local_addr().unwrap();
+        tokio::spawn(serve_tls(tcp_listener, service, tls, stop_flag));
+
+        // The client never starts the handshake, so the server closes the connection
+        let mut stream = TcpStream::connect(address).await.unwrap();
+        let read = tokio::time::timeout(Duration::from_secs(60), stream.read(&mut [0; 1])).await;
+        assert_eq!(read.unwrap().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn shutdown_waits_for_connections() {
+        let tls = TlsConfig::self_signed("127.0.0.1").unwrap();
+        let (called_tx, mut called_rx) = mpsc::unbounded_channel();
+        let release = Arc::new(Notify::new());
+        let service = SlowService { called: called_tx, release: Arc::clone(&release) };
+        let (stop_tx, stop_rx) = oneshot::channel::<()>();
+
+        let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = tcp_listener.local_addr().unwrap();
+        let stop_flag = async move { _ = stop_rx.await };
+        let mut server = tokio::spawn(serve_tls(tcp_listener, service, tls.clone(), stop_flag));
+
+        let mut stream = connect(&tls, address).await;
+        stream.write_all(request(BODY).as_bytes()).await.unwrap();
+        called_rx.recv().await.unwrap();
+
+        // The request is in flight, so the server waits for it
+        stop_tx.send(()).unwrap();
+        let res = tokio::time::timeout(Duration::from_millis(100), &mut server).await;
+        assert!(res.is_err());
+
+        release.notify_one();
+        let mut status_line = [0; 12];
+        stream.read_exact(&mut status_line).await.unwrap();
+        assert_eq!(&status_line, b"HTTP/1.1 200");
+
+        tokio::time::timeout(Duration::from_secs(5), server).await.unwrap().unwrap();
+    }
+
+    const BODY: &str = r#"{"update_id":1,"unknown":{}}"#;
+
+    fn request(body: &str) -> String {
+        format!(
+            "POST /webhook HTTP/1.1\r\nhost: 127.0.0.1\r\nx-telegram-bot-api-secret-token: \
+             secret\r\ncontent-length: {}\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    /// Connects to the server at `address` trusting its self-signed
+    /// certificate.
+    async fn connect(tls: &TlsConfig, address: SocketAddr) -> TlsStream<TcpStream> {
+        let mut roots = RootCertStore::empty();
+        let certificate = tls.self_signed_certificate().unwrap().as_bytes();
+        for certificate in rustls_pemfile::certs(&mut &*certificate) {
+            roots.add(certificate.unwrap()).unwrap();
+        }
+        let client_config =
+            ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+                .with_safe_default_protocol_versions()
+                .unwrap()
+                .with_root_certificates(roots)
+                .with_no_client_auth();
+
+        let stream = TcpStream::connect(address).await.unwrap();
+        tokio_rustls::TlsConnector::from(Arc::new(client_config))
+            .connect(ServerName::try_from("127.0.0.1").unwrap(), stream)
+            .await
+            .unwrap()
+    }
+
+    /// A service that responds only once it's released.
+    #[derive(Clone)]
+    struct SlowService {
+        called: mpsc::UnboundedSender<()>,
+        release: Arc<Notify>,
+    }
+
+    impl tower::Service<Request<Incoming>> for SlowService {
+        type Response = Response<Empty<Bytes>>;
+        type Error = Infallible;
+        type Future = BoxFuture<'static, Result<Self::Response, Infallible>>;
+
+        fn poll_ready(&mut self, _: &mut task::Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: Request<Incoming>) -> Self::Future {
+            let Self { called, release } = self.clone();
+
+            Box::pin(async move {
+                _ = called.send(());
+                release.notified().await;
+                Ok(Response::new(Empty::new()))
+            })
+        }
+    }
+}
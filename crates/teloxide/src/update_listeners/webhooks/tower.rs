@@ -0,0 +1,203 @@
+use std::{
+    convert::Infallible,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use http::{Method, Request, Response, StatusCode};
+use http_body_util::Empty;
+
+use crate::{
+    requests::Requester,
+    update_listeners::{
+        webhooks::{handler, handler_no_setup, Options, WebhookHandler, SECRET_TOKEN_HEADER},
+        UpdateListener,
+    },
+};
+
+/// Maximum size of a request body accepted by [`WebhookService`].
+const BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+/// Webhook [`tower::Service`] that can be used with any [`tower`]-compatible
+/// server (for example [hyper] or [axum]).
+///
+/// The service accepts `POST` requests to [`Options::path`] and passes them to
+/// a [`WebhookHandler`]. Requests to other paths are answered with
+/// `404 Not Found` and requests with other methods with
+/// `405 Method Not Allowed`.
+///
+/// [hyper]: https://docs.rs/hyper
+/// [axum]: https://docs.rs/axum
+#[derive(Clone)]
+pub struct WebhookService {
+    path: Arc<str>,
+    handler: WebhookHandler,
+}
+
+impl WebhookService {
+    /// Creates a service that passes requests to `path` to `handler`.
+    pub fn new(path: &str, handler: WebhookHandler) -> Self {
+        Self { path: path.into(), handler }
+    }
+}
+
+impl<B> tower::Service<Request<B>> for WebhookService
+where
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    type Response = Response<Empty<Bytes>>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let this = self.clone();
+
+        Box::pin(async move {
+            let status = this.handle(req).await;
+
+            let mut response = Response::new(Empty::new());
+            *response.status_mut() = status;
+            Ok(response)
+        })
+    }
+}
+
+impl WebhookService {
+    async fn handle<B>(&self, req: Request<B>) -> StatusCode
+    where
+        B: http_body::Body,
+        B::Error: std::error::Error + Send + Sync + 'static,
+    {
+        use http_body_util::{BodyExt, LengthLimitError, Limited};
+
+        if req.uri().path() != &*self.path {
+            return StatusCode::NOT_FOUND;
+        }
+
+        if req.method() != Method::POST {
+            return StatusCode::METHOD_NOT_ALLOWED;
+        }
+
+        let (parts, body) = req.into_parts();
+        let body = match Limited::new(body, BODY_LIMIT).collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(err) if err.is::<LengthLimitError>() => return StatusCode::PAYLOAD_TOO_LARGE,
+            Err(_) => return StatusCode::BAD_REQUEST,
+        };
+
+        let secret_token = parts.headers.get(SECRET_TOKEN_HEADER).map(|header| header.as_bytes());
+        self.handler.handle(secret_token, &body).await
+    }
+}
+
+/// Webhook implementation based on the [`tower::Service`] trait.
+///
+/// This function does most of the work necessary for webhook to work, it:
+/// - Calls [`set_webhook`], so telegram starts sending updates our way
+/// - When the update listener is hinted with [allowed updates], calls
+///   [`set_webhook`] again with them
+/// - When the update listener is [`stop`]ped, calls [`delete_webhook`]
+///
+/// The only missing part is running a server with a returned
+/// [`WebhookService`].
+///
+/// **Note**: in order for webhooks to work, you need to use returned
+/// [`WebhookService`] in a server that is bound to [`options.address`].
+///
+/// [`set_webhook`]: crate::payloads::SetWebhook
+/// [`delete_webhook`]: crate::payloads::DeleteWebhook
+/// [`stop`]: crate::stop::StopToken::stop
+/// [allowed updates]: crate::update_listeners::UpdateListener::hint_allowed_updates
+/// [`options.address`]: Options::address
+///
+/// ## Returns
+///
+/// A update listener, stop-future, service triplet on success.
+///
+/// The "stop-future" is resolved after [`stop`] is called on the stop token of
+/// the returned update listener and the webhook is deleted. It also applies
+/// allowed updates hints, so it should be polled while the server is running.
+///
+/// ## Fails
+///
+/// If `set_webhook()` fails.
+///
+/// ## See also
+///
+/// [`tower_no_setup`] for lower-level version of this function.
+pub async fn tower_service<R>(
+    bot: R,
+    options: Options,
+) -> Result<
+    (impl UpdateListener<Err = Infallible>, impl Future<Output = ()> + Send, WebhookService),
+    R::Err,
+>
+where
+    R: Requester + Send,
+    <R as Requester>::DeleteWebhook: Send,
+{
+    let path = options.path.clone();
+    let (listener, stop_flag, handler) = handler(bot, options).await?;
+
+    Ok((listener, stop_flag, WebhookService::new(&path, handler)))
+}
+
+/// Webhook implementation based on the [`tower::Service`] trait that doesn't
+/// perform any setup work.
+///
+/// See [`handler_no_setup`] for the description of the stop-future.
+///
+/// ## See also
+///
+/// [`tower_service`] for higher-level version of this function.
+pub fn tower_no_setup(
+    options: Options,
+) -> (impl UpdateListener<Err = Infallible>, impl Future<Output = ()>, WebhookService) {
+    let path = options.path.clone();
+    let (listener, stop_flag, handler) = handler_no_setup(options);
+
+    (listener, stop_flag, WebhookService::new(&path, handler))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use http_body_util::Full;
+    use tower::Service;
+
+    use super::*;
+    use crate::update_listeners::{webhooks::test_options, AsUpdateStream};
+
+    async fn call(service: &WebhookService, method: Method, path: &str) -> StatusCode {
+        let request = Request::builder()
+            .method(method)
+            .uri(path)
+            .header(SECRET_TOKEN_HEADER, "secret")
+            .body(Full::new(Bytes::from_static(br#"{"update_id":1,"unknown":{}}"#)))
+            .unwrap();
+
+        service.clone().call(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn routes_requests() {
+        let (mut listener, _stop_flag, service) =
+            tower_no_setup(test_options("https://example.com/webhook"));
+
+        assert_eq!(call(&service, Method::POST, "/other").await, StatusCode::NOT_FOUND);
+        assert_eq!(call(&service, Method::GET, "/webhook").await, StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(call(&service, Method::POST, "/webhook").await, StatusCode::OK);
+
+        let update = std::pin::pin!(listener.as_stream()).next().await.unwrap().unwrap();
+        assert_eq!(update.id.0, 1);
+    }
+}
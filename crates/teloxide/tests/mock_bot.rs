@@ -0,0 +1,114 @@
+use teloxide::{
+    dispatching::{Dispatcher, UpdateFilterExt},
+    error_handlers::LoggingErrorHandler,
+    payloads::{AnswerCallbackQuery, SendMessage},
+    prelude::*,
+    testing::{self, MockBot, UpdateBuilder},
+    types::{Message, Recipient, Update},
+    update_listeners, ApiError, RequestError,
+};
+
+fn message(chat_id: i64, text: &str) -> Message {
+    let user = testing::user(chat_id as u64, "Laster");
+    testing::text_message(testing::private_chat(&user), Some(user), text)
+}
+
+fn text_update(chat_id: i64, text: &str) -> Update {
+    let user = testing::user(chat_id as u64, "Laster");
+    UpdateBuilder::text_message(testing::private_chat(&user), user, text).build()
+}
+
+#[tokio::test]
+async fn records_requests_from_handlers() {
+    let bot = MockBot::new();
+    bot.respond::<SendMessage, _>(|payload| {
+        let Recipient::Id(ChatId(chat_id)) = payload.chat_id else { unreachable!() };
+        Ok(message(chat_id, &payload.text))
+    });
+
+    let handler = Update::filter_message().endpoint(|bot: MockBot, msg: Message| async move {
+        bot.send_message(msg.chat.id, format!("echo: {}", msg.text().unwrap())).await?;
+        respond(())
+    });
+
+    Dispatcher::builder(bot.clone(), handler)
+        .build()
+        .dispatch_with_listener(
+            update_listeners::from_updates([text_update(10, "hi"), text_update(20, "bye")]),
+            LoggingErrorHandler::new(),
+        )
+        .await;
+
+    let mut sent = bot.requests_of::<SendMessage>();
+    // Updates from different chats may be handled concurrently
+    sent.sort_by_key(|p| p.text.clone());
+
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0].chat_id, ChatId(20).into());
+    assert_eq!(sent[0].text, "echo: bye");
+    assert_eq!(sent[1].chat_id, ChatId(10).into());
+    assert_eq!(sent[1].text, "echo: hi");
+
+    // The dispatcher asks for `GetMe` before dispatching
+    assert_eq!(bot.requests()[0].method(), "GetMe");
+}
+
+#[tokio::test]
+async fn scripted_responses() {
+    let bot = MockBot::new();
+    bot.respond_once::<SendMessage>(Err(RequestError::Api(ApiError::BotBlocked)));
+    bot.respond_once::<SendMessage>(Ok(message(1, "second")));
+
+    let first = bot.send_message(ChatId(1), "first").await;
+    assert!(matches!(first, Err(RequestError::Api(ApiError::BotBlocked))));
+
+    let second = bot.send_message(ChatId(1), "second").await.unwrap();
+    assert_eq!(second.text(), Some("second"));
+
+    // Nothing is queued anymore and the method doesn't return `True`
+    assert!(bot.send_message(ChatId(1), "third").await.is_err());
+
+    // Methods returning `True` succeed by default
+    bot.answer_callback_query("id").await.unwrap();
+
+    let requests = bot.requests();
+    assert_eq!(requests.len(), 4);
+    assert_eq!(requests[2].json()["text"], "third");
+    assert!(requests[3].is::<AnswerCallbackQuery>());
+
+    bot.clear_requests();
+    assert!(bot.requests().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn concurrent_requests_use_responder() {
+    let bot = MockBot::new();
+    bot.respond::<SendMessage, _>({
+        let bot = bot.clone();
+        move |payload| {
+            // Answer the first request only after the second one was sent, so that they
+            // overlap
+            if payload.text == "0" {
+                for _ in 0..1000 {
+                    if bot.requests_of::<SendMessage>().len() == 2 {
+                        break;
+                    }
+                    std::thread::sleep(std::time::Duration::from_millis(1));
+                }
+            }
+            Ok(message(1, &payload.text))
+        }
+    });
+
+    let first = tokio::spawn({
+        let bot = bot.clone();
+        async move { bot.send_message(ChatId(1), "0").await }
+    });
+    while bot.requests_of::<SendMessage>().is_empty() {
+        tokio::task::yield_now().await;
+    }
+    let second = bot.send_message(ChatId(1), "1").await;
+
+    assert_eq!(first.await.unwrap().unwrap().text(), Some("0"));
+    assert_eq!(second.unwrap().text(), Some("1"));
+}
@@ -1,10 +1,15 @@
+use futures::TryStreamExt;
 use std::{
     fmt::{Debug, Display},
     sync::Arc,
+    time::Duration,
 };
 use teloxide::{
-    dispatching::dialogue::{RedisStorage, RedisStorageError, Serializer, Storage},
-    types::ChatId,
+    dispatching::dialogue::{
+        AtomicStorage, EnumerableStorage, ExpiringStorage, RedisStorage, RedisStorageError,
+        Serializer, Storage,
+    },
+    types::{ChatId, MessageId, ThreadId, UserId},
 };
 
 #[tokio::test]
@@ -43,6 +48,50 @@ async fn test_redis_cbor() {
     test_redis(storage).await;
 }
 
+#[tokio::test]
+#[cfg_attr(not(CI_REDIS), ignore)]
+async fn test_redis_expiry() {
+    let storage = RedisStorage::open_with_ttl(
+        "redis://127.0.0.1:7780",
+        teloxide::dispatching::dialogue::serializer::Json,
+        Duration::from_secs(60 * 60),
+    )
+    .await
+    .unwrap();
+
+    Arc::clone(&storage).update_dialogue(ChatId(1), "ABC".to_owned()).await.unwrap();
+    Arc::clone(&storage).update_dialogue(ChatId(2), "DEF".to_owned()).await.unwrap();
+
+    // Pretend that the first dialogue has expired: Redis deletes the key, while its
+    // deadline stays in the index
+    let mut conn = deadpool_redis::redis::Client::open("redis://127.0.0.1:7780")
+        .unwrap()
+        .get_multiplexed_async_connection()
+        .await
+        .unwrap();
+    () = deadpool_redis::redis::pipe()
+        .del("1")
+        .ignore()
+        .zadd("teloxide_dialogues_expiry:chat", "1", 0)
+        .ignore()
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+
+    assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(1)).await.unwrap(), None::<Dialogue>);
+    assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(2)).await.unwrap(), Some("DEF".to_owned()));
+    assert!(matches!(
+        Storage::<Dialogue>::remove_dialogue(Arc::clone(&storage), ChatId(1)).await.unwrap_err(),
+        RedisStorageError::DialogueNotFound
+    ));
+
+    let expired: Vec<ChatId> =
+        ExpiringStorage::<Dialogue>::remove_expired(Arc::clone(&storage)).await.unwrap();
+    assert_eq!(expired, [ChatId(1)]);
+
+    Storage::<Dialogue>::remove_dialogue(Arc::clone(&storage), ChatId(2)).await.unwrap();
+}
+
 type Dialogue = String;
 
 macro_rules! test_dialogues {
@@ -56,7 +105,7 @@ macro_rules! test_dialogues {
 async fn test_redis<S>(storage: Arc<RedisStorage<S>>)
 where
     S: Send + Sync + Serializer<Dialogue> + 'static,
-    <S as Serializer<Dialogue>>::Error: Debug + Display,
+    <S as Serializer<Dialogue>>::Error: Debug + Display + Send,
 {
     test_dialogues!(storage, None, None, None);
 
@@ -82,4 +131,71 @@ where
         Arc::clone(&storage).remove_dialogue(ChatId(1)).await.unwrap_err(),
         RedisStorageError::DialogueNotFound
     ));
+
+    // Dialogues of different users in the same chat are independent.
+    let (alice, bob) = ((ChatId(1), UserId(1)), (ChatId(1), UserId(2)));
+    Arc::clone(&storage).update_dialogue(alice, "ABC".to_owned()).await.unwrap();
+    Arc::clone(&storage).update_dialogue(bob, "DEF".to_owned()).await.unwrap();
+    assert_eq!(Arc::clone(&storage).get_dialogue(alice).await.unwrap(), Some("ABC".to_owned()));
+    assert_eq!(Arc::clone(&storage).get_dialogue(bob).await.unwrap(), Some("DEF".to_owned()));
+    test_dialogues!(storage, None, None, None);
+
+    Arc::clone(&storage).remove_dialogue(alice).await.unwrap();
+    assert_eq!(Arc::clone(&storage).get_dialogue(alice).await.unwrap(), None::<Dialogue>);
+    Arc::clone(&storage).remove_dialogue(bob).await.unwrap();
+
+    // Keys of different types never refer to the same dialogue.
+    let (zero, thread) = ((ChatId(1), UserId(0)), (ChatId(1), ThreadId(MessageId(1))));
+    Arc::clone(&storage).update_dialogue(zero, "ABC".to_owned()).await.unwrap();
+    Arc::clone(&storage).update_dialogue(alice, "DEF".to_owned()).await.unwrap();
+    test_dialogues!(storage, None, None, None);
+    assert_eq!(Arc::clone(&storage).get_dialogue(thread).await.unwrap(), None::<Dialogue>);
+    Arc::clone(&storage).remove_dialogue(zero).await.unwrap();
+    Arc::clone(&storage).remove_dialogue(alice).await.unwrap();
+
+    // Concurrent transitions are not lost.
+    let tasks: Vec<_> = (0..20)
+        .map(|_| {
+            let storage = Arc::clone(&storage);
+            tokio::spawn(async move {
+                let increment = |old: Option<Dialogue>| {
+                    (old.map_or(0, |d| d.parse::<u32>().unwrap()) + 1).to_string()
+                };
+                storage.update_dialogue_with(ChatId(1), Box::new(increment)).await.unwrap()
+            })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap();
+    }
+    test_dialogues!(storage, Some("20".to_owned()), None, None);
+
+    // All dialogues can be listed, counted and removed at once, while dialogues
+    // with keys of other types are left alone.
+    let user = (ChatId(1), UserId(1));
+    Arc::clone(&storage).update_dialogue(user, "user".to_owned()).await.unwrap();
+    for i in 0..250 {
+        Arc::clone(&storage).update_dialogue(ChatId(i), i.to_string()).await.unwrap();
+    }
+    let mut dialogues: Vec<(ChatId, Dialogue)> =
+        Arc::clone(&storage).list_dialogues().try_collect().await.unwrap();
+    dialogues.sort();
+    assert_eq!(dialogues, (0..250).map(|i| (ChatId(i), i.to_string())).collect::<Vec<_>>());
+    assert_eq!(
+        EnumerableStorage::<Dialogue, ChatId>::count(Arc::clone(&storage)).await.unwrap(),
+        250
+    );
+
+    EnumerableStorage::<Dialogue, ChatId>::clear(Arc::clone(&storage)).await.unwrap();
+    test_dialogues!(storage, None, None, None);
+    assert_eq!(
+        EnumerableStorage::<Dialogue, ChatId>::count(Arc::clone(&storage)).await.unwrap(),
+        0
+    );
+
+    let dialogues: Vec<((ChatId, UserId), Dialogue)> =
+        Arc::clone(&storage).list_dialogues().try_collect().await.unwrap();
+    assert_eq!(dialogues, [(user, "user".to_owned())]);
+    EnumerableStorage::<Dialogue, (ChatId, UserId)>::clear(Arc::clone(&storage)).await.unwrap();
+    assert_eq!(Arc::clone(&storage).get_dialogue(user).await.unwrap(), None::<Dialogue>);
 }
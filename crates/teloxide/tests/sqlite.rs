@@ -1,11 +1,16 @@
+use futures::TryStreamExt;
 use std::{
     fmt::{Debug, Display},
     fs,
     sync::Arc,
+    time::Duration,
 };
 use teloxide::{
-    dispatching::dialogue::{Serializer, SqliteStorage, SqliteStorageError, Storage},
-    types::ChatId,
+    dispatching::dialogue::{
+        AtomicStorage, EnumerableStorage, ExpiringStorage, Serializer, SqliteStorage,
+        SqliteStorageError, Storage,
+    },
+    types::{ChatId, MessageId, ThreadId, UserId},
 };
 
 #[tokio::test(flavor = "multi_thread")]
@@ -47,6 +52,65 @@ async fn test_sqlite_cbor() {
     fs::remove_dir_all("./test_db3").unwrap();
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sqlite_expiry() {
+    fs::create_dir("./test_db4").unwrap();
+    let storage = SqliteStorage::open_with_ttl(
+        "./test_db4/test_db4.sqlite",
+        teloxide::dispatching::dialogue::serializer::Json,
+        Duration::from_secs(60 * 60),
+    )
+    .await
+    .unwrap();
+
+    Arc::clone(&storage).update_dialogue(ChatId(1), "ABC".to_owned()).await.unwrap();
+    Arc::clone(&storage).update_dialogue(ChatId(2), "DEF".to_owned()).await.unwrap();
+
+    // Pretend that the first dialogue was last updated two hours ago
+    let pool = sqlx::SqlitePool::connect("sqlite:./test_db4/test_db4.sqlite").await.unwrap();
+    sqlx::query("UPDATE teloxide_dialogues SET updated_at = updated_at - ? WHERE chat_id = 1")
+        .bind(2 * 60 * 60 * 1000)
+        .execute(&pool)
+        .await
+        .unwrap();
+    pool.close().await;
+
+    assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(1)).await.unwrap(), None::<Dialogue>);
+    assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(2)).await.unwrap(), Some("DEF".to_owned()));
+    assert!(matches!(
+        Storage::<Dialogue>::remove_dialogue(Arc::clone(&storage), ChatId(1)).await.unwrap_err(),
+        SqliteStorageError::DialogueNotFound
+    ));
+
+    let expired: Vec<ChatId> =
+        ExpiringStorage::<Dialogue>::remove_expired(Arc::clone(&storage)).await.unwrap();
+    assert_eq!(expired, [ChatId(1)]);
+
+    Storage::<Dialogue>::remove_dialogue(Arc::clone(&storage), ChatId(2)).await.unwrap();
+    fs::remove_dir_all("./test_db4").unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sqlite_versioned() {
+    use teloxide::dispatching::dialogue::serializer::{Json, Versioned};
+
+    fs::create_dir("./test_db5").unwrap();
+    let path = "./test_db5/test_db5.sqlite";
+
+    // A dialogue written before versioning was introduced
+    let storage = SqliteStorage::open(path, Json).await.unwrap();
+    Arc::clone(&storage).update_dialogue(ChatId(1), 42).await.unwrap();
+
+    let serializer = Versioned::new(Json, 1).migration(0, |old: i32| old.to_string());
+    let storage = SqliteStorage::open(path, serializer).await.unwrap();
+    assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(1)).await.unwrap(), Some("42".to_owned()));
+
+    Arc::clone(&storage).update_dialogue(ChatId(1), "ABC".to_owned()).await.unwrap();
+    assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(1)).await.unwrap(), Some("ABC".to_owned()));
+
+    fs::remove_dir_all("./test_db5").unwrap();
+}
+
 type Dialogue = String;
 
 macro_rules! test_dialogues {
@@ -60,7 +124,7 @@ macro_rules! test_dialogues {
 async fn test_sqlite<S>(storage: Arc<SqliteStorage<S>>)
 where
     S: Send + Sync + Serializer<Dialogue> + 'static,
-    <S as Serializer<Dialogue>>::Error: Debug + Display,
+    <S as Serializer<Dialogue>>::Error: Debug + Display + Send,
 {
     test_dialogues!(storage, None, None, None);
 
@@ -86,4 +150,71 @@ where
         Arc::clone(&storage).remove_dialogue(ChatId(1)).await.unwrap_err(),
         SqliteStorageError::DialogueNotFound
     ));
+
+    // Dialogues of different users in the same chat are independent.
+    let (alice, bob) = ((ChatId(1), UserId(1)), (ChatId(1), UserId(2)));
+    Arc::clone(&storage).update_dialogue(alice, "ABC".to_owned()).await.unwrap();
+    Arc::clone(&storage).update_dialogue(bob, "DEF".to_owned()).await.unwrap();
+    assert_eq!(Arc::clone(&storage).get_dialogue(alice).await.unwrap(), Some("ABC".to_owned()));
+    assert_eq!(Arc::clone(&storage).get_dialogue(bob).await.unwrap(), Some("DEF".to_owned()));
+    test_dialogues!(storage, None, None, None);
+
+    Arc::clone(&storage).remove_dialogue(alice).await.unwrap();
+    assert_eq!(Arc::clone(&storage).get_dialogue(alice).await.unwrap(), None::<Dialogue>);
+    Arc::clone(&storage).remove_dialogue(bob).await.unwrap();
+
+    // Keys of different types never refer to the same dialogue.
+    let (zero, thread) = ((ChatId(1), UserId(0)), (ChatId(1), ThreadId(MessageId(1))));
+    Arc::clone(&storage).update_dialogue(zero, "ABC".to_owned()).await.unwrap();
+    Arc::clone(&storage).update_dialogue(alice, "DEF".to_owned()).await.unwrap();
+    test_dialogues!(storage, None, None, None);
+    assert_eq!(Arc::clone(&storage).get_dialogue(thread).await.unwrap(), None::<Dialogue>);
+    Arc::clone(&storage).remove_dialogue(zero).await.unwrap();
+    Arc::clone(&storage).remove_dialogue(alice).await.unwrap();
+
+    // Concurrent transitions are not lost.
+    let tasks: Vec<_> = (0..20)
+        .map(|_| {
+            let storage = Arc::clone(&storage);
+            tokio::spawn(async move {
+                let increment = |old: Option<Dialogue>| {
+                    (old.map_or(0, |d| d.parse::<u32>().unwrap()) + 1).to_string()
+                };
+                storage.update_dialogue_with(ChatId(1), Box::new(increment)).await.unwrap()
+            })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap();
+    }
+    test_dialogues!(storage, Some("20".to_owned()), None, None);
+
+    // All dialogues can be listed, counted and removed at once, while dialogues
+    // with keys of other types are left alone.
+    let user = (ChatId(1), UserId(1));
+    Arc::clone(&storage).update_dialogue(user, "user".to_owned()).await.unwrap();
+    for i in 0..250 {
+        Arc::clone(&storage).update_dialogue(ChatId(i), i.to_string()).await.unwrap();
+    }
+    let mut dialogues: Vec<(ChatId, Dialogue)> =
+        Arc::clone(&storage).list_dialogues().try_collect().await.unwrap();
+    dialogues.sort();
+    assert_eq!(dialogues, (0..250).map(|i| (ChatId(i), i.to_string())).collect::<Vec<_>>());
+    assert_eq!(
+        EnumerableStorage::<Dialogue, ChatId>::count(Arc::clone(&storage)).await.unwrap(),
+        250
+    );
+
+    EnumerableStorage::<Dialogue, ChatId>::clear(Arc::clone(&storage)).await.unwrap();
+    test_dialogues!(storage, None, None, None);
+    assert_eq!(
+        EnumerableStorage::<Dialogue, ChatId>::count(Arc::clone(&storage)).await.unwrap(),
+        0
+    );
+
+    let dialogues: Vec<((ChatId, UserId), Dialogue)> =
+        Arc::clone(&storage).list_dialogues().try_collect().await.unwrap();
+    assert_eq!(dialogues, [(user, "user".to_owned())]);
+    EnumerableStorage::<Dialogue, (ChatId, UserId)>::clear(Arc::clone(&storage)).await.unwrap();
+    assert_eq!(Arc::clone(&storage).get_dialogue(user).await.unwrap(), None::<Dialogue>);
 }